@@ -12,7 +12,7 @@ pub enum ErrorCode {
     NotGroupAdmin,
     #[msg("Only the creator can perform this action")]
     NotGroupCreator,
-    #[msg("Only the creator can post in a channel")]
+    #[msg("Only admins can post in a channel")]
     ChannelPostingRestricted,
     #[msg("This invite has expired")]
     InviteExpired,
@@ -82,4 +82,62 @@ pub enum ErrorCode {
     InvalidToken,
     #[msg("The NFT is not valid for this group.")]
     InvalidNFT,
+    #[msg("Arithmetic overflow.")]
+    Overflow,
+    #[msg("The escrow has not expired yet.")]
+    EscrowNotExpired,
+    #[msg("A token account required for this transfer is missing.")]
+    MissingTokenAccount,
+    #[msg("The escrow cannot move to that status.")]
+    InvalidEscrowTransition,
+    #[msg("This group does not allow escrow trades.")]
+    EscrowNotEnabled,
+    #[msg("Prize split must give every place a share and add up to 100%.")]
+    InvalidPrizeSplit,
+    #[msg("Submissions are closed for this challenge.")]
+    SubmissionsClosed,
+    #[msg("Voting is not open for this challenge.")]
+    VotingNotOpen,
+    #[msg("This challenge does not take votes this way.")]
+    InvalidVotingMode,
+    #[msg("Reveals are not open for this challenge.")]
+    RevealNotOpen,
+    #[msg("The revealed vote does not match the commitment.")]
+    InvalidReveal,
+    #[msg("This vote has already been revealed.")]
+    VoteAlreadyRevealed,
+    #[msg("The voting mode settings do not fit together.")]
+    InvalidVotingConfig,
+    #[msg("A user profile is required to vote in this challenge.")]
+    ProfileRequired,
+    #[msg("You hold no voting tokens.")]
+    NoVotingPower,
+    #[msg("Your vote is not on this submission.")]
+    NotVotedFor,
+    #[msg("Contributions are only refunded when the challenge cannot pay out.")]
+    RefundUnavailable,
+    #[msg("This challenge can no longer be cancelled.")]
+    ChallengeNotCancellable,
+    #[msg("This image has already been submitted to the challenge.")]
+    DuplicateSubmission,
+    #[msg("Only the admin and moderator roles can be granted or removed.")]
+    InvalidRole,
+    #[msg("You are banned from this group.")]
+    BannedFromGroup,
+    #[msg("Only the group's owner can perform this action.")]
+    NotGroupOwner,
+    #[msg("This wallet has not been offered ownership of the group.")]
+    NotPendingOwner,
+    #[msg("The challenge has not been cancelled.")]
+    ChallengeNotCancelled,
+    #[msg("An entry dropped off the leaderboard; every submission must be ranked again once results are in.")]
+    LeaderboardIncomplete,
+    #[msg("This wallet is already banned from the group.")]
+    AlreadyBanned,
+    #[msg("The escrow is under dispute; a moderator of its group must resolve it.")]
+    EscrowDisputed,
+    #[msg("The escrow is not under dispute.")]
+    EscrowNotDisputed,
+    #[msg("The escrow's creation time is too far from the current time.")]
+    InvalidCreatedAt,
 }
\ No newline at end of file
@@ -1,15 +1,42 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount, Transfer};
+use anchor_lang::system_program;
+use anchor_spl::token::{Mint, Token, TokenAccount, Transfer};
 
+use super::escrow_funds::token_leg_account;
 use crate::state::*;
 use crate::error::ErrorCode;
 
-pub fn handler(ctx: Context<AcceptEscrow>, created_at: i64) -> Result<()> {
+pub fn handler(ctx: Context<AcceptEscrow>, _created_at: i64) -> Result<()> {
+    let counterparty_token = ctx.accounts.counterparty_mint.as_ref().map(|mint| mint.key());
     let escrow = &mut ctx.accounts.escrow;
+    require!(counterparty_token == escrow.counterparty_token, ErrorCode::InvalidToken);
+
     let clock = Clock::get()?;
-    require!(!escrow.accepted, ErrorCode::EscrowAlreadyAccepted);
     require!(escrow.expires_at >= clock.unix_timestamp, ErrorCode::EscrowExpired);
-    escrow.accepted = true;
+    escrow.transition(EscrowStatus::Accepted, clock.unix_timestamp)?;
+    escrow.counterparty_rent_payer = ctx.accounts.fee_payer.key();
+    let counterparty_amount = escrow.counterparty_amount;
+
+    // Lock the counterparty's side next to the initiator's
+    if counterparty_token.is_some() {
+        let cpi_accounts = Transfer {
+            from: token_leg_account(&ctx.accounts.counterparty_token_account)?,
+            to: token_leg_account(&ctx.accounts.counterparty_vault)?,
+            authority: ctx.accounts.counterparty.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+        anchor_spl::token::transfer(cpi_context, counterparty_amount)?;
+    } else {
+        let cpi_accounts = system_program::Transfer {
+            from: ctx.accounts.counterparty.to_account_info(),
+            to: ctx.accounts.escrow.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.system_program.to_account_info();
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+        system_program::transfer(cpi_context, counterparty_amount)?;
+    }
+
     Ok(())
 }
 
@@ -24,11 +51,43 @@ pub struct AcceptEscrow<'info> {
         seeds = [b"escrow", escrow.initiator.as_ref(), &created_at.to_le_bytes()],
         bump = escrow.bump,
         constraint = escrow.counterparty == counterparty.key() @ ErrorCode::NotOwner,
+        constraint = escrow.status.can_transition_to(EscrowStatus::Accepted) @ ErrorCode::EscrowAlreadyAccepted,
     )]
     pub escrow: Account<'info, Escrow>,
 
     #[account(mut)]
     pub counterparty: Signer<'info>,
 
+    /// The counterparty must still belong to the escrow's group
+    #[account(
+        seeds = [b"member", escrow.group.as_ref(), counterparty.key().as_ref()],
+        bump = counterparty_member.bump,
+        constraint = counterparty_member.member == counterparty.key() @ ErrorCode::NotGroupMember,
+    )]
+    pub counterparty_member: Account<'info, GroupMember>,
+
+    /// Omitted when the counterparty pays native SOL
+    pub counterparty_mint: Option<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = Some(counterparty_token_account.mint) == escrow.counterparty_token @ ErrorCode::InvalidToken,
+        constraint = counterparty_token_account.owner == counterparty.key() @ ErrorCode::NotOwner,
+    )]
+    pub counterparty_token_account: Option<Account<'info, TokenAccount>>,
+
+    // Accounts are created before constraints run, so an existing vault is
+    // reused to let a repeat acceptance reach the status check on the escrow
+    #[account(
+        init_if_needed,
+        payer = fee_payer,
+        seeds = [b"counterparty_vault", escrow.key().as_ref()],
+        bump,
+        token::mint = counterparty_mint,
+        token::authority = escrow,
+    )]
+    pub counterparty_vault: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
-}
\ No newline at end of file
+}
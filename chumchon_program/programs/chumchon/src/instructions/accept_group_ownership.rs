@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ErrorCode;
+
+/// Completes a transfer; the group keeps its address, which is seeded by its creator
+pub fn handler(ctx: Context<AcceptGroupOwnership>) -> Result<()> {
+    let group = &mut ctx.accounts.group;
+    group.owner = ctx.accounts.new_owner.key();
+    group.pending_owner = None;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptGroupOwnership<'info> {
+    pub new_owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"group", group.name.as_bytes(), group.creator.as_ref()],
+        bump = group.bump,
+        constraint = group.pending_owner == Some(new_owner.key()) @ ErrorCode::NotPendingOwner,
+    )]
+    pub group: Account<'info, Group>,
+}
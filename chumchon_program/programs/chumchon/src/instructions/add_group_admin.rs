@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ErrorCode;
+
+/// Makes a member an admin or moderator. Only the owner appoints admins,
+/// while admins may also appoint moderators.
+pub fn handler(ctx: Context<AddGroupAdmin>, role: GroupRole) -> Result<()> {
+    require!(matches!(role, GroupRole::Admin | GroupRole::Moderator), ErrorCode::InvalidRole);
+    let group = &ctx.accounts.group;
+    let authority = ctx.accounts.authority.key();
+    let authority_member = ctx.accounts.authority_member.as_ref();
+    group.require_outranks(authority_member, authority, role)?;
+    group.require_outranks(authority_member, authority, group.member_role(&ctx.accounts.member_record))?;
+
+    ctx.accounts.member_record.role = role;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AddGroupAdmin<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"group", group.name.as_bytes(), group.creator.as_ref()],
+        bump = group.bump,
+    )]
+    pub group: Account<'info, Group>,
+
+    /// Required unless the authority owns the group
+    #[account(
+        seeds = [b"member", group.key().as_ref(), authority.key().as_ref()],
+        bump = authority_member.bump,
+    )]
+    pub authority_member: Option<Account<'info, GroupMember>>,
+
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), member_record.member.as_ref()],
+        bump = member_record.bump,
+    )]
+    pub member_record: Account<'info, GroupMember>,
+}
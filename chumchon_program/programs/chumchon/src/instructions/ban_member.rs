@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ErrorCode;
+
+/// Bans `member` from joining again, kicking them first if they are still in
+/// the group. Like a kick, the authority must outrank them. A ban that has run
+/// out is replaced.
+pub fn handler(ctx: Context<BanMember>, member: Pubkey, reason: u8, expires_at: Option<i64>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    if let Some(expires_at) = expires_at {
+        require!(expires_at > now, ErrorCode::InvalidExpiry);
+    }
+
+    // Someone who has already left is banned as a plain member
+    let membership = GroupMember::load(&ctx.accounts.member_record)?;
+    let group = &mut ctx.accounts.group;
+    let role = match membership.as_ref() {
+        Some(record) => group.member_role(record),
+        None => group.role_of(None, member).unwrap_or(GroupRole::Member),
+    };
+    let authority_member = ctx.accounts.authority_member.as_ref();
+    let authority = ctx.accounts.authority.key();
+    group.require_outranks(authority_member, authority, role)?;
+    let banned_by_role = group.role_of(authority_member, authority).ok_or(ErrorCode::NotGroupAdmin)?;
+
+    if let Some(record) = membership {
+        let rent_payer = ctx.accounts.rent_payer.as_ref().ok_or(ErrorCode::NotOwner)?;
+        require_keys_eq!(rent_payer.key(), record.rent_payer, ErrorCode::NotOwner);
+        GroupMember::close(&ctx.accounts.member_record, rent_payer)?;
+        group.remove_member()?;
+    }
+
+    // A fresh record is zeroed; an existing one keeps its rent payer
+    let ban_record = &mut ctx.accounts.ban_record;
+    if ban_record.group == Pubkey::default() {
+        ban_record.rent_payer = ctx.accounts.fee_payer.key();
+    } else {
+        require!(!ban_record.is_active(now), ErrorCode::AlreadyBanned);
+    }
+    ban_record.group = group.key();
+    ban_record.member = member;
+    ban_record.banned_by = authority;
+    ban_record.banned_by_role = banned_by_role;
+    ban_record.reason = reason;
+    ban_record.banned_at = now;
+    ban_record.expires_at = expires_at;
+    ban_record.bump = ctx.bumps.ban_record;
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(member: Pubkey)]
+pub struct BanMember<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"group", group.name.as_bytes(), group.creator.as_ref()],
+        bump = group.bump,
+    )]
+    pub group: Account<'info, Group>,
+
+    /// Required unless the authority owns the group
+    #[account(
+        seeds = [b"member", group.key().as_ref(), authority.key().as_ref()],
+        bump = authority_member.bump,
+    )]
+    pub authority_member: Option<Account<'info, GroupMember>>,
+
+    /// CHECK: The member's membership address, read in the handler as it is
+    /// empty once they have left
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), member.as_ref()],
+        bump,
+    )]
+    pub member_record: UncheckedAccount<'info>,
+
+    /// CHECK: Receives the membership record's rent, checked in the handler.
+    /// Omitted once the member has left.
+    #[account(mut)]
+    pub rent_payer: Option<AccountInfo<'info>>,
+
+    #[account(
+        init_if_needed,
+        payer = fee_payer,
+        space = GroupBan::LEN,
+        seeds = [b"ban", group.key().as_ref(), member.as_ref()],
+        bump,
+    )]
+    pub ban_record: Account<'info, GroupBan>,
+
+    pub system_program: Program<'info, System>,
+}
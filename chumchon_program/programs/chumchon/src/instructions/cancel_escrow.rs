@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use super::escrow_funds::{release_lamports, release_tokens, token_leg_account};
+use crate::state::*;
+use crate::error::ErrorCode;
+
+pub fn handler(ctx: Context<CancelEscrow>, _created_at: i64) -> Result<()> {
+    let clock = Clock::get()?;
+    ctx.accounts.escrow.transition(EscrowStatus::Cancelled, clock.unix_timestamp)?;
+
+    // Hand the initiator's deposit back before anyone has matched it
+    let escrow = &ctx.accounts.escrow;
+    match escrow.initiator_token {
+        Some(_) => release_tokens(
+            escrow,
+            token_leg_account(&ctx.accounts.initiator_vault)?,
+            token_leg_account(&ctx.accounts.initiator_refund_account)?,
+            Some(token_leg_account(&ctx.accounts.initiator_refund_account)?),
+            ctx.accounts.initiator_rent_payer.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            escrow.initiator_amount,
+        )?,
+        None => release_lamports(
+            escrow,
+            &ctx.accounts.initiator.to_account_info(),
+            escrow.initiator_amount,
+        )?,
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(created_at: i64)]
+pub struct CancelEscrow<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow.initiator.as_ref(), &created_at.to_le_bytes()],
+        bump = escrow.bump,
+        has_one = initiator @ ErrorCode::NotOwner,
+        constraint = escrow.status != EscrowStatus::Disputed @ ErrorCode::EscrowDisputed,
+        constraint = escrow.status.can_transition_to(EscrowStatus::Cancelled) @ ErrorCode::EscrowAlreadyAccepted,
+        close = initiator_rent_payer,
+    )]
+    pub escrow: Account<'info, Escrow>,
+
+    #[account(mut)]
+    pub initiator: Signer<'info>,
+
+    /// CHECK: Receives the escrow and initiator vault rent
+    #[account(mut, address = escrow.initiator_rent_payer @ ErrorCode::NotOwner)]
+    pub initiator_rent_payer: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"initiator_vault", escrow.key().as_ref()],
+        bump,
+    )]
+    pub initiator_vault: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = Some(initiator_refund_account.mint) == escrow.initiator_token @ ErrorCode::InvalidToken,
+        constraint = initiator_refund_account.owner == escrow.initiator @ ErrorCode::NotOwner,
+    )]
+    pub initiator_refund_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
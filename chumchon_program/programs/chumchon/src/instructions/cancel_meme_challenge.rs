@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ErrorCode;
+
+/// Calls the challenge off. Contributors then claim their refunds with
+/// `refund_contribution` and entrants theirs with `refund_entry_fee`, while the
+/// creator takes back the rest of the pool with `close_meme_challenge`.
+pub fn handler(ctx: Context<CancelMemeChallenge>) -> Result<()> {
+    let challenge = &mut ctx.accounts.challenge;
+    challenge.require_active()?;
+    require!(challenge.can_cancel(Clock::get()?.unix_timestamp), ErrorCode::ChallengeNotCancellable);
+    challenge.cancelled = true;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelMemeChallenge<'info> {
+    #[account(
+        mut,
+        seeds = [b"challenge", challenge.creator.as_ref(), &challenge.start_time.to_le_bytes()],
+        bump = challenge.bump,
+        constraint = challenge.creator == creator.key() @ ErrorCode::NotGroupCreator,
+    )]
+    pub challenge: Account<'info, MemeChallenge>,
+
+    pub creator: Signer<'info>,
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use super::vote_weight::release_vote_tokens;
+use crate::state::*;
+use crate::error::ErrorCode;
+
+/// Moves an open vote to `new_submission` with the weight it was cast with,
+/// or retracts it when `new_submission` is omitted, handing back any tokens it locked
+pub fn handler(ctx: Context<ChangeMemeVote>) -> Result<()> {
+    let challenge = &mut ctx.accounts.challenge;
+    let voter = ctx.accounts.voter.key();
+    let clock = Clock::get()?;
+    require!(challenge.submission_end <= clock.unix_timestamp && clock.unix_timestamp < challenge.voting_end, ErrorCode::VotingNotOpen);
+    require!(challenge.reveal_end.is_none(), ErrorCode::InvalidVotingMode);
+
+    if let Some(new_submission) = ctx.accounts.new_submission.as_ref() {
+        require_keys_neq!(new_submission.key(), ctx.accounts.submission.key(), ErrorCode::AlreadyVoted);
+        challenge.require_member(ctx.accounts.member_record.as_ref(), voter)?;
+        require_neq!(new_submission.submitter, voter, ErrorCode::CannotVoteOwnSubmission);
+    }
+
+    let weight = ctx.accounts.voter_record.weight;
+    challenge.withdraw_vote(&mut ctx.accounts.submission, weight)?;
+
+    match ctx.accounts.new_submission.as_mut() {
+        Some(new_submission) => {
+            challenge.count_vote(new_submission, weight)?;
+
+            let voter_record = &mut ctx.accounts.voter_record;
+            voter_record.submission = new_submission.key();
+            voter_record.voted_at = clock.unix_timestamp;
+        }
+        // Closing the record lets the voter cast a fresh vote later
+        None => {
+            if challenge.voting_mode == VotingMode::TokenWeighted {
+                release_vote_tokens(
+                    challenge,
+                    &ctx.accounts.vote_vault,
+                    &ctx.accounts.voter_token_account,
+                    &ctx.accounts.rent_payer,
+                    &ctx.accounts.token_program,
+                )?;
+            }
+            ctx.accounts.voter_record.close(ctx.accounts.rent_payer.to_account_info())?;
+        }
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ChangeMemeVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"challenge", challenge.creator.as_ref(), &challenge.start_time.to_le_bytes()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, MemeChallenge>,
+
+    #[account(
+        mut,
+        seeds = [b"voter", challenge.key().as_ref(), voter.key().as_ref()],
+        bump = voter_record.bump,
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+
+    /// CHECK: Receives the voter record's rent when the vote is retracted
+    #[account(mut, address = voter_record.rent_payer @ ErrorCode::NotOwner)]
+    pub rent_payer: AccountInfo<'info>,
+
+    /// The submission currently holding the vote
+    #[account(
+        mut,
+        seeds = [b"submission", challenge.key().as_ref(), submission.submitter.as_ref()],
+        bump = submission.bump,
+        constraint = submission.key() == voter_record.submission @ ErrorCode::NotVotedFor,
+    )]
+    pub submission: Account<'info, MemeSubmission>,
+
+    /// Omitted to retract the vote
+    #[account(
+        mut,
+        seeds = [b"submission", challenge.key().as_ref(), new_submission.submitter.as_ref()],
+        bump = new_submission.bump,
+    )]
+    pub new_submission: Option<Account<'info, MemeSubmission>>,
+
+    /// Required when moving the vote in a group challenge
+    pub member_record: Option<Account<'info, GroupMember>>,
+
+    /// Tokens locked by a `TokenWeighted` vote, handed back when it is retracted
+    #[account(
+        mut,
+        seeds = [b"vote_vault", challenge.key().as_ref(), voter.key().as_ref()],
+        bump,
+    )]
+    pub vote_vault: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = voter_token_account.owner == voter.key() @ ErrorCode::NotOwner,
+    )]
+    pub voter_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ErrorCode;
+
+/// Closes a contribution record once its challenge has paid out, so whoever
+/// paid its rent gets it back. Contributions that are owed back close with
+/// `refund_contribution` instead.
+pub fn handler(ctx: Context<CloseChallengeContribution>) -> Result<()> {
+    require!(ctx.accounts.challenge.completed, ErrorCode::ChallengeNotEnded);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseChallengeContribution<'info> {
+    pub contributor: Signer<'info>,
+
+    #[account(
+        seeds = [b"challenge", challenge.creator.as_ref(), &challenge.start_time.to_le_bytes()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, MemeChallenge>,
+
+    #[account(
+        mut,
+        seeds = [b"contribution", challenge.key().as_ref(), &contribution.contribution_id.to_le_bytes()],
+        bump = contribution.bump,
+        constraint = contribution.contributor == contributor.key() @ ErrorCode::NotOwner,
+        close = rent_payer,
+    )]
+    pub contribution: Account<'info, ChallengeContribution>,
+
+    /// CHECK: Receives the contribution record's rent
+    #[account(mut, address = contribution.rent_payer @ ErrorCode::NotOwner)]
+    pub rent_payer: AccountInfo<'info>,
+}
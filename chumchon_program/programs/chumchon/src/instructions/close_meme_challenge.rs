@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use super::prize_pool::{close_vault, pay_lamports, pay_tokens};
+use crate::state::*;
+use crate::error::ErrorCode;
+
+/// Hands the creator their own share of a cancelled challenge's pool, leaving
+/// what contributors and entrants are owed in place. Once every contribution
+/// is refunded, every submission closed and every voter's locked tokens withdrawn
+/// it also closes the challenge, so the creator calls it again after the last refund.
+pub fn handler(ctx: Context<CloseMemeChallenge>) -> Result<()> {
+    let challenge = &ctx.accounts.challenge;
+    require!(challenge.cancelled, ErrorCode::ChallengeNotCancelled);
+    let share = challenge.creator_share()?;
+    let closing = !challenge.owes_refunds();
+
+    match ctx.accounts.reward_vault.as_ref() {
+        Some(vault) => {
+            let creator_token_account = ctx.accounts.creator_token_account.as_ref()
+                .ok_or(ErrorCode::MissingTokenAccount)?;
+            let token_program = ctx.accounts.token_program.to_account_info();
+            // Anything sent to the vault beyond the pool goes out with the last withdrawal
+            pay_tokens(
+                challenge,
+                vault.to_account_info(),
+                creator_token_account.to_account_info(),
+                token_program.clone(),
+                if closing { vault.amount } else { share },
+            )?;
+            if closing {
+                close_vault(
+                    challenge,
+                    vault.to_account_info(),
+                    ctx.accounts.rent_payer.to_account_info(),
+                    token_program,
+                )?;
+            }
+        }
+        None => pay_lamports(challenge, &ctx.accounts.creator.to_account_info(), share)?,
+    }
+
+    let challenge = &mut ctx.accounts.challenge;
+    challenge.reward_amount -= share;
+    // The challenge's rent goes to the rent payer when it closes
+    if closing {
+        challenge.close(ctx.accounts.rent_payer.to_account_info())?;
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseMemeChallenge<'info> {
+    #[account(
+        mut,
+        seeds = [b"challenge", challenge.creator.as_ref(), &challenge.start_time.to_le_bytes()],
+        bump = challenge.bump,
+        constraint = challenge.creator == creator.key() @ ErrorCode::NotGroupCreator,
+    )]
+    pub challenge: Account<'info, MemeChallenge>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// CHECK: Receives the rent of the challenge and its vault
+    #[account(mut, address = challenge.rent_payer @ ErrorCode::NotOwner)]
+    pub rent_payer: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"challenge_vault", challenge.key().as_ref()],
+        bump,
+    )]
+    pub reward_vault: Option<Account<'info, TokenAccount>>,
+
+    /// Receives the creator's share of a token pool
+    #[account(
+        mut,
+        constraint = creator_token_account.owner == creator.key() @ ErrorCode::NotOwner,
+    )]
+    pub creator_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ErrorCode;
+
+/// Closes a submission and its content guard once the challenge has paid out,
+/// so whoever paid their rent gets it back. Submissions to a cancelled challenge close with
+/// `refund_entry_fee` instead.
+pub fn handler(ctx: Context<CloseMemeSubmission>) -> Result<()> {
+    require!(ctx.accounts.challenge.completed, ErrorCode::ChallengeNotEnded);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseMemeSubmission<'info> {
+    pub submitter: Signer<'info>,
+
+    #[account(
+        seeds = [b"challenge", challenge.creator.as_ref(), &challenge.start_time.to_le_bytes()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, MemeChallenge>,
+
+    #[account(
+        mut,
+        seeds = [b"submission", challenge.key().as_ref(), submitter.key().as_ref()],
+        bump = submission.bump,
+        close = rent_payer,
+    )]
+    pub submission: Account<'info, MemeSubmission>,
+
+    /// Frees the submission's image hash, and its rent goes to the rent payer too
+    #[account(
+        mut,
+        seeds = [b"content", challenge.key().as_ref(), submission.content_hash.as_ref()],
+        bump = content_guard.bump,
+        close = rent_payer,
+    )]
+    pub content_guard: Account<'info, ContentGuard>,
+
+    /// CHECK: Receives the rent of the submission and its content guard
+    #[account(mut, address = submission.rent_payer @ ErrorCode::NotOwner)]
+    pub rent_payer: AccountInfo<'info>,
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ErrorCode;
+
+/// Closes a vote's record once results are final, so whoever paid its rent gets
+/// it back. Records of `TokenWeighted` votes close with `withdraw_vote_tokens`
+/// instead, which also hands back the tokens they locked.
+pub fn handler(ctx: Context<CloseVoterRecord>) -> Result<()> {
+    let challenge = &ctx.accounts.challenge;
+    require!(Clock::get()?.unix_timestamp >= challenge.results_at(), ErrorCode::ChallengeNotEnded);
+    require!(challenge.voting_mode != VotingMode::TokenWeighted, ErrorCode::InvalidVotingMode);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseVoterRecord<'info> {
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [b"challenge", challenge.creator.as_ref(), &challenge.start_time.to_le_bytes()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, MemeChallenge>,
+
+    #[account(
+        mut,
+        seeds = [b"voter", challenge.key().as_ref(), voter.key().as_ref()],
+        bump = voter_record.bump,
+        close = rent_payer,
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+
+    /// CHECK: Receives the voter record's rent
+    #[account(mut, address = voter_record.rent_payer @ ErrorCode::NotOwner)]
+    pub rent_payer: AccountInfo<'info>,
+}
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use super::vote_weight::cast_vote_weight;
+use crate::state::*;
+use crate::error::ErrorCode;
+
+/// The vote's weight is settled, and any quadratic votes paid for, when it is committed
+pub fn handler(ctx: Context<CommitMemeVote>, commitment: [u8; 32], votes: u32) -> Result<()> {
+    let challenge = &ctx.accounts.challenge;
+    let voter = ctx.accounts.voter.key();
+    let clock = Clock::get()?;
+    challenge.require_active()?;
+    require!(challenge.submission_end <= clock.unix_timestamp && clock.unix_timestamp < challenge.voting_end, ErrorCode::VotingNotOpen);
+    require!(challenge.reveal_end.is_some(), ErrorCode::InvalidVotingMode);
+    // Without a submission the commitment could never be revealed, only lock up the vote
+    require!(challenge.submission_count > 0, ErrorCode::NoSubmissions);
+    challenge.require_member(ctx.accounts.member_record.as_ref(), voter)?;
+
+    let weight = cast_vote_weight(
+        &mut ctx.accounts.challenge,
+        &ctx.accounts.voter,
+        votes,
+        &ctx.accounts.voter_profile,
+        &ctx.accounts.voter_token_account,
+        &ctx.accounts.vote_vault,
+        &ctx.accounts.reward_vault,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+    )?;
+
+    // Nothing is counted until the vote is revealed
+    let voter_record = &mut ctx.accounts.voter_record;
+    voter_record.challenge = ctx.accounts.challenge.key();
+    voter_record.submission = Pubkey::default();
+    voter_record.voter = voter;
+    voter_record.commitment = Some(commitment);
+    voter_record.revealed = false;
+    voter_record.weight = weight;
+    voter_record.voted_at = clock.unix_timestamp;
+    voter_record.rent_payer = ctx.accounts.fee_payer.key();
+    voter_record.bump = ctx.bumps.voter_record;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CommitMemeVote<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"challenge", challenge.creator.as_ref(), &challenge.start_time.to_le_bytes()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, MemeChallenge>,
+
+    /// Required when the challenge belongs to a group
+    pub member_record: Option<Account<'info, GroupMember>>,
+
+    /// Keyed by challenge, which also keeps the choice hidden
+    #[account(
+        init,
+        payer = fee_payer,
+        space = VoterRecord::LEN,
+        seeds = [b"voter", challenge.key().as_ref(), voter.key().as_ref()],
+        bump,
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+
+    /// Required in `OnePerProfile` challenges
+    #[account(
+        seeds = [b"user", voter.key().as_ref()],
+        bump = voter_profile.bump,
+    )]
+    pub voter_profile: Option<Account<'info, UserProfile>>,
+
+    /// Voting tokens of a `TokenWeighted` challenge, or what pays for quadratic votes on a token pool
+    #[account(
+        mut,
+        constraint = voter_token_account.owner == voter.key() @ ErrorCode::NotOwner,
+    )]
+    pub voter_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Mint of a `TokenWeighted` challenge's voting tokens
+    #[account(constraint = Some(voting_mint.key()) == challenge.voting_mint @ ErrorCode::InvalidToken)]
+    pub voting_mint: Option<Account<'info, Mint>>,
+
+    /// Holds a `TokenWeighted` vote's tokens until it is retracted or results are final
+    #[account(
+        init,
+        payer = fee_payer,
+        seeds = [b"vote_vault", challenge.key().as_ref(), voter.key().as_ref()],
+        bump,
+        token::mint = voting_mint,
+        token::authority = challenge,
+    )]
+    pub vote_vault: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"challenge_vault", challenge.key().as_ref()],
+        bump,
+    )]
+    pub reward_vault: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+}
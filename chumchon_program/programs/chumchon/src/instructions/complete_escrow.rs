@@ -1,13 +1,45 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount, Transfer};
+use anchor_spl::token::{Token, TokenAccount};
 
+use super::escrow_funds::{release_lamports, release_tokens, token_leg_account};
 use crate::state::*;
 use crate::error::ErrorCode;
 
-pub fn handler(ctx: Context<CompleteEscrow>, created_at: i64) -> Result<()> {
-    require!(ctx.accounts.escrow.accepted, ErrorCode::EscrowNotAccepted);
-    let escrow = &mut ctx.accounts.escrow;
-    escrow.completed = true;
+pub fn handler(ctx: Context<CompleteEscrow>, _created_at: i64) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(ctx.accounts.escrow.expires_at >= clock.unix_timestamp, ErrorCode::EscrowExpired);
+    ctx.accounts.escrow.transition(EscrowStatus::Settled, clock.unix_timestamp)?;
+
+    let escrow = &ctx.accounts.escrow;
+    let token_program = ctx.accounts.token_program.to_account_info();
+
+    // Swap both legs: initiator's side to the counterparty and vice versa.
+    // Each token vault's rent goes back to whoever paid for it.
+    match escrow.initiator_token {
+        Some(_) => release_tokens(
+            escrow,
+            token_leg_account(&ctx.accounts.initiator_vault)?,
+            token_leg_account(&ctx.accounts.counterparty_receive_account)?,
+            ctx.accounts.initiator_refund_account.as_ref().map(|account| account.to_account_info()),
+            ctx.accounts.initiator_rent_payer.to_account_info(),
+            token_program.clone(),
+            escrow.initiator_amount,
+        )?,
+        None => release_lamports(escrow, &ctx.accounts.counterparty, escrow.initiator_amount)?,
+    }
+    match escrow.counterparty_token {
+        Some(_) => release_tokens(
+            escrow,
+            token_leg_account(&ctx.accounts.counterparty_vault)?,
+            token_leg_account(&ctx.accounts.initiator_receive_account)?,
+            ctx.accounts.counterparty_refund_account.as_ref().map(|account| account.to_account_info()),
+            ctx.accounts.counterparty_rent_payer.to_account_info(),
+            token_program,
+            escrow.counterparty_amount,
+        )?,
+        None => release_lamports(escrow, &ctx.accounts.initiator, escrow.counterparty_amount)?,
+    }
+
     Ok(())
 }
 
@@ -21,11 +53,80 @@ pub struct CompleteEscrow<'info> {
         mut,
         seeds = [b"escrow", escrow.initiator.as_ref(), &created_at.to_le_bytes()],
         bump = escrow.bump,
+        constraint = escrow.status != EscrowStatus::Disputed @ ErrorCode::EscrowDisputed,
+        constraint = escrow.status.can_transition_to(EscrowStatus::Settled) @ ErrorCode::EscrowNotAccepted,
+        close = initiator_rent_payer,
     )]
     pub escrow: Account<'info, Escrow>,
 
-    #[account(mut)]
-    pub initiator: Signer<'info>,
+    /// Either party may settle once both sides are locked
+    #[account(
+        constraint = authority.key() == escrow.initiator
+            || authority.key() == escrow.counterparty @ ErrorCode::Unauthorized,
+    )]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Receives the counterparty's leg when it is native SOL
+    #[account(mut, address = escrow.initiator @ ErrorCode::NotOwner)]
+    pub initiator: AccountInfo<'info>,
+
+    /// CHECK: Receives the initiator's leg when it is native SOL
+    #[account(mut, address = escrow.counterparty @ ErrorCode::NotOwner)]
+    pub counterparty: AccountInfo<'info>,
+
+    /// CHECK: Receives the escrow and initiator vault rent
+    #[account(mut, address = escrow.initiator_rent_payer @ ErrorCode::NotOwner)]
+    pub initiator_rent_payer: AccountInfo<'info>,
+
+    /// CHECK: Receives the counterparty vault rent
+    #[account(mut, address = escrow.counterparty_rent_payer @ ErrorCode::NotOwner)]
+    pub counterparty_rent_payer: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"initiator_vault", escrow.key().as_ref()],
+        bump,
+    )]
+    pub initiator_vault: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: Counterparty vault PDA, only created once the escrow is accepted
+    #[account(
+        mut,
+        seeds = [b"counterparty_vault", escrow.key().as_ref()],
+        bump,
+    )]
+    pub counterparty_vault: Option<AccountInfo<'info>>,
 
+    #[account(
+        mut,
+        constraint = Some(initiator_receive_account.mint) == escrow.counterparty_token @ ErrorCode::InvalidToken,
+        constraint = initiator_receive_account.owner == escrow.initiator @ ErrorCode::NotOwner,
+    )]
+    pub initiator_receive_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = Some(counterparty_receive_account.mint) == escrow.initiator_token @ ErrorCode::InvalidToken,
+        constraint = counterparty_receive_account.owner == escrow.counterparty @ ErrorCode::NotOwner,
+    )]
+    pub counterparty_receive_account: Option<Account<'info, TokenAccount>>,
+
+    /// Takes back tokens sent to the initiator vault beyond the initiator's leg
+    #[account(
+        mut,
+        constraint = Some(initiator_refund_account.mint) == escrow.initiator_token @ ErrorCode::InvalidToken,
+        constraint = initiator_refund_account.owner == escrow.initiator @ ErrorCode::NotOwner,
+    )]
+    pub initiator_refund_account: Option<Account<'info, TokenAccount>>,
+
+    /// Takes back tokens sent to the counterparty vault beyond the counterparty's leg
+    #[account(
+        mut,
+        constraint = Some(counterparty_refund_account.mint) == escrow.counterparty_token @ ErrorCode::InvalidToken,
+        constraint = counterparty_refund_account.owner == escrow.counterparty @ ErrorCode::NotOwner,
+    )]
+    pub counterparty_refund_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
-}
\ No newline at end of file
+}
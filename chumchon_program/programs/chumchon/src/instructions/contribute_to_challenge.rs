@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{Token, TokenAccount, Transfer};
+
+use crate::state::*;
+use crate::error::ErrorCode;
+
+/// Adds to the prize pool in the challenge's reward currency until results are in
+pub fn handler(ctx: Context<ContributeToChallenge>, amount: u64) -> Result<()> {
+    require_gt!(amount, 0, ErrorCode::InvalidAmount);
+    let challenge = &ctx.accounts.challenge;
+    let clock = Clock::get()?;
+    challenge.require_active()?;
+    require!(!challenge.completed && clock.unix_timestamp < challenge.results_at(), ErrorCode::ChallengeEnded);
+
+    if challenge.reward_mint.is_some() {
+        let token_account = ctx.accounts.contributor_token_account.as_ref()
+            .ok_or(ErrorCode::MissingTokenAccount)?;
+        require!(Some(token_account.mint) == challenge.reward_mint, ErrorCode::InvalidToken);
+        let cpi_accounts = Transfer {
+            from: token_account.to_account_info(),
+            to: ctx.accounts.reward_vault.as_ref()
+                .ok_or(ErrorCode::MissingTokenAccount)?.to_account_info(),
+            authority: ctx.accounts.contributor.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+        anchor_spl::token::transfer(cpi_context, amount)?;
+    } else {
+        let cpi_accounts = system_program::Transfer {
+            from: ctx.accounts.contributor.to_account_info(),
+            to: ctx.accounts.challenge.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.system_program.to_account_info();
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+        system_program::transfer(cpi_context, amount)?;
+    }
+
+    let challenge = &mut ctx.accounts.challenge;
+    let contribution = &mut ctx.accounts.contribution;
+    contribution.challenge = challenge.key();
+    contribution.contributor = ctx.accounts.contributor.key();
+    contribution.contribution_id = challenge.contribution_count;
+    contribution.amount = amount;
+    contribution.contributed_at = clock.unix_timestamp;
+    contribution.rent_payer = ctx.accounts.fee_payer.key();
+    contribution.bump = ctx.bumps.contribution;
+
+    challenge.reward_amount = challenge.reward_amount.checked_add(amount).ok_or(ErrorCode::Overflow)?;
+    challenge.contributed_amount = challenge.contributed_amount.checked_add(amount).ok_or(ErrorCode::Overflow)?;
+    challenge.contribution_count = challenge.contribution_count.checked_add(1).ok_or(ErrorCode::Overflow)?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ContributeToChallenge<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"challenge", challenge.creator.as_ref(), &challenge.start_time.to_le_bytes()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, MemeChallenge>,
+
+    #[account(
+        init,
+        payer = fee_payer,
+        space = ChallengeContribution::LEN,
+        seeds = [b"contribution", challenge.key().as_ref(), &challenge.contribution_count.to_le_bytes()],
+        bump,
+    )]
+    pub contribution: Account<'info, ChallengeContribution>,
+
+    /// Pays the contribution to a token pool
+    #[account(
+        mut,
+        constraint = contributor_token_account.owner == contributor.key() @ ErrorCode::NotOwner,
+    )]
+    pub contributor_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"challenge_vault", challenge.key().as_ref()],
+        bump,
+    )]
+    pub reward_vault: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
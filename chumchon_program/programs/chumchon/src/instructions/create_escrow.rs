@@ -1,15 +1,17 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount, Transfer};
+use anchor_lang::system_program;
+use anchor_spl::token::{Mint, Token, TokenAccount, Transfer};
 
+use super::escrow_funds::token_leg_account;
 use crate::state::*;
 use crate::error::ErrorCode;
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<CreateEscrow>,
     counterparty: Pubkey,
-    group: Pubkey,
     initiator_amount: u64,
-    counterparty_token: Pubkey,
+    counterparty_token: Option<Pubkey>,
     counterparty_amount: u64,
     expires_at: i64,
     created_at: i64,
@@ -17,36 +19,59 @@ pub fn handler(
     require_gt!(initiator_amount, 0, ErrorCode::InvalidAmount);
     require_gt!(counterparty_amount, 0, ErrorCode::InvalidAmount);
     require!(expires_at > created_at, ErrorCode::InvalidExpiry);
+    let now = Clock::get()?.unix_timestamp;
+    require!(expires_at > now, ErrorCode::InvalidExpiry);
+    require!(created_at.abs_diff(now) <= MAX_CREATED_AT_DRIFT, ErrorCode::InvalidCreatedAt);
 
+    let initiator_token = ctx.accounts.initiator_mint.as_ref().map(|mint| mint.key());
     let escrow = &mut ctx.accounts.escrow;
 
     escrow.initiator = ctx.accounts.initiator.key();
     escrow.counterparty = counterparty;
-    escrow.group = group;
-    escrow.initiator_token = ctx.accounts.initiator_token_account.mint;
+    escrow.group = ctx.accounts.group.key();
+    escrow.initiator_token = initiator_token;
     escrow.initiator_amount = initiator_amount;
     escrow.counterparty_token = counterparty_token;
     escrow.counterparty_amount = counterparty_amount;
+    escrow.status = EscrowStatus::Open;
     escrow.created_at = created_at;
     escrow.expires_at = expires_at;
-    escrow.accepted = false;
-    escrow.completed = false;
+    escrow.initiator_rent_payer = ctx.accounts.fee_payer.key();
     escrow.bump = ctx.bumps.escrow;
+    escrow.log_status(now);
 
-    let cpi_accounts = Transfer {
-        from: ctx.accounts.initiator_token_account.to_account_info(),
-        to: ctx.accounts.escrow_token_account_initiator.to_account_info(),
-        authority: ctx.accounts.initiator.to_account_info(),
-    };
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
-    anchor_spl::token::transfer(cpi_context, initiator_amount)?;
+    // Lock the initiator's side in the program-owned vault, or in the escrow itself for SOL
+    if initiator_token.is_some() {
+        let cpi_accounts = Transfer {
+            from: token_leg_account(&ctx.accounts.initiator_token_account)?,
+            to: token_leg_account(&ctx.accounts.initiator_vault)?,
+            authority: ctx.accounts.initiator.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+        anchor_spl::token::transfer(cpi_context, initiator_amount)?;
+    } else {
+        let cpi_accounts = system_program::Transfer {
+            from: ctx.accounts.initiator.to_account_info(),
+            to: ctx.accounts.escrow.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.system_program.to_account_info();
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+        system_program::transfer(cpi_context, initiator_amount)?;
+    }
 
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(created_at: i64)]
+#[instruction(
+    counterparty: Pubkey,
+    initiator_amount: u64,
+    counterparty_token: Option<Pubkey>,
+    counterparty_amount: u64,
+    expires_at: i64,
+    created_at: i64,
+)]
 pub struct CreateEscrow<'info> {
     #[account(mut)]
     pub fee_payer: Signer<'info>,
@@ -63,12 +88,48 @@ pub struct CreateEscrow<'info> {
     )]
     pub escrow: Account<'info, Escrow>,
 
-    #[account(mut)]
-    pub initiator_token_account: Account<'info, TokenAccount>,
+    /// Escrow is a whale-group feature unless the group opts in
+    #[account(
+        seeds = [b"group", group.name.as_bytes(), group.creator.as_ref()],
+        bump = group.bump,
+        constraint = group.is_whale_group || group.escrow_enabled @ ErrorCode::EscrowNotEnabled,
+    )]
+    pub group: Account<'info, Group>,
 
-    #[account(mut)]
-    pub escrow_token_account_initiator: Account<'info, TokenAccount>,
+    #[account(
+        seeds = [b"member", group.key().as_ref(), initiator.key().as_ref()],
+        bump = initiator_member.bump,
+        constraint = initiator_member.member == initiator.key() @ ErrorCode::NotGroupMember,
+    )]
+    pub initiator_member: Account<'info, GroupMember>,
+
+    #[account(
+        seeds = [b"member", group.key().as_ref(), counterparty.as_ref()],
+        bump = counterparty_member.bump,
+        constraint = counterparty_member.member == counterparty @ ErrorCode::NotGroupMember,
+    )]
+    pub counterparty_member: Account<'info, GroupMember>,
+
+    /// Omitted when the initiator offers native SOL
+    pub initiator_mint: Option<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = Some(initiator_token_account.mint) == initiator_mint.as_ref().map(|mint| mint.key()) @ ErrorCode::InvalidToken,
+        constraint = initiator_token_account.owner == initiator.key() @ ErrorCode::NotOwner,
+    )]
+    pub initiator_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = fee_payer,
+        seeds = [b"initiator_vault", escrow.key().as_ref()],
+        bump,
+        token::mint = initiator_mint,
+        token::authority = escrow,
+    )]
+    pub initiator_vault: Option<Account<'info, TokenAccount>>,
 
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
-}
\ No newline at end of file
+}
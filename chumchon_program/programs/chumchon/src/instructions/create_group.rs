@@ -3,6 +3,7 @@ use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::error::ErrorCode;
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<CreateGroup>,
     name: String,
@@ -13,6 +14,7 @@ pub fn handler(
     required_amount: u64,
     required_nft_collection: Option<Pubkey>,
     required_sol_balance: u64,
+    escrow_enabled: bool,
 ) -> Result<()> {
     require!(name.len() <= 32, ErrorCode::NameTooLong);
     require!(description.len() <= 256, ErrorCode::DescriptionTooLong);
@@ -20,10 +22,14 @@ pub fn handler(
     let group = &mut ctx.accounts.group;
     let clock = Clock::get()?.unix_timestamp;
     group.creator = ctx.accounts.creator.key();
+    group.owner = ctx.accounts.creator.key();
+    group.pending_owner = None;
+    group.display_name = name.clone();
     group.name = name;
     group.description = description;
     group.is_channel = is_channel;
     group.is_whale_group = is_whale_group;
+    group.escrow_enabled = escrow_enabled;
     group.required_token = required_token;
     group.required_amount = required_amount;
     group.required_nft_collection = required_nft_collection;
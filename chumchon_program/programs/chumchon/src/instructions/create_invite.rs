@@ -8,9 +8,12 @@ pub fn handler(
     max_uses: u32,
     expires_at: i64,
 ) -> Result<()> {
-    require!(code.len() <= 32, ErrorCode::CodeTooLong);
     require!(max_uses > 0, ErrorCode::InvalidMaxUses);
     require!(expires_at > Clock::get()?.unix_timestamp, ErrorCode::InvalidExpiry);
+    require!(
+        ctx.accounts.group.has_role(ctx.accounts.creator_member.as_ref(), ctx.accounts.creator.key(), GroupRole::Admin),
+        ErrorCode::NotGroupAdmin
+    );
     let invite = &mut ctx.accounts.invite;
     invite.creator = ctx.accounts.creator.key();
     invite.group = ctx.accounts.group.key();
@@ -23,6 +26,7 @@ pub fn handler(
 }
 
 #[derive(Accounts)]
+#[instruction(code: String)]
 pub struct CreateInvite<'info> {
     #[account(mut)]
     pub fee_payer: Signer<'info>,
@@ -31,7 +35,7 @@ pub struct CreateInvite<'info> {
         init,
         payer = fee_payer,
         space = Invite::LEN,
-        seeds = [b"invite", group.key().as_ref(), code.as_bytes()],
+        seeds = [b"invite", group.key().as_ref(), Invite::code_seed(&code)?],
         bump,
     )]
     pub invite: Account<'info, Invite>,
@@ -39,12 +43,19 @@ pub struct CreateInvite<'info> {
     #[account(
         seeds = [b"group", group.name.as_bytes(), group.creator.as_ref()],
         bump = group.bump,
-        constraint = group.creator == creator.key() @ ErrorCode::NotGroupCreator,
     )]
     pub group: Account<'info, Group>,
 
+    /// Group admin issuing the invite
     #[account(mut)]
     pub creator: Signer<'info>,
 
+    /// Required unless the invite comes from the group's owner
+    #[account(
+        seeds = [b"member", group.key().as_ref(), creator.key().as_ref()],
+        bump = creator_member.bump,
+    )]
+    pub creator_member: Option<Account<'info, GroupMember>>,
+
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
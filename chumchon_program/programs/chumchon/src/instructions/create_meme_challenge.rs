@@ -1,8 +1,11 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{Mint, Token, TokenAccount, Transfer};
 
 use crate::state::*;
 use crate::error::ErrorCode;
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<CreateMemeChallenge>,
     title: String,
@@ -10,32 +13,109 @@ pub fn handler(
     prompt: String,
     reward_amount: u64,
     start_time: i64,
-    end_time: i64,
+    submission_end: i64,
+    voting_end: i64,
+    reveal_end: Option<i64>,
+    prize_split_bps: Vec<u16>,
+    voting_mode: VotingMode,
+    voting_mint: Option<Pubkey>,
+    vote_price: u64,
+    entry_fee: u64,
 ) -> Result<()> {
     require!(title.len() <= 64, ErrorCode::TitleTooLong);
     require!(description.len() <= 256, ErrorCode::DescriptionTooLong);
     require!(prompt.len() <= 128, ErrorCode::PromptTooLong);
     require!(reward_amount > 0, ErrorCode::InvalidAmount);
-    require!(end_time > start_time, ErrorCode::InvalidExpiry);
+    require!(submission_end > start_time, ErrorCode::InvalidExpiry);
+    require!(voting_end > submission_end, ErrorCode::InvalidExpiry);
+    if let Some(reveal_end) = reveal_end {
+        require!(reveal_end > voting_end, ErrorCode::InvalidExpiry);
+    }
     require!(start_time > Clock::get()?.unix_timestamp, ErrorCode::InvalidExpiry);
+    require!(
+        !prize_split_bps.is_empty() && prize_split_bps.len() <= MAX_PRIZE_PLACES,
+        ErrorCode::InvalidPrizeSplit
+    );
+    require!(prize_split_bps.iter().all(|bps| *bps > 0), ErrorCode::InvalidPrizeSplit);
+    let split_total = prize_split_bps.iter().try_fold(0u16, |total, bps| total.checked_add(*bps));
+    require!(split_total == Some(PRIZE_SPLIT_TOTAL_BPS), ErrorCode::InvalidPrizeSplit);
+    require!(
+        voting_mint.is_some() == (voting_mode == VotingMode::TokenWeighted),
+        ErrorCode::InvalidVotingConfig
+    );
+    require!((vote_price > 0) == (voting_mode == VotingMode::Quadratic), ErrorCode::InvalidVotingConfig);
+    let reward_mint = ctx.accounts.reward_mint.as_ref().map(|mint| mint.key());
+    if let Some(group) = ctx.accounts.group.as_ref() {
+        require!(
+            group.has_role(ctx.accounts.creator_member.as_ref(), ctx.accounts.creator.key(), GroupRole::Admin),
+            ErrorCode::NotGroupAdmin
+        );
+    }
+    let group = ctx.accounts.group.as_ref().map(|group| group.key());
     let challenge = &mut ctx.accounts.challenge;
     challenge.creator = ctx.accounts.creator.key();
+    challenge.group = group;
     challenge.title = title;
     challenge.description = description;
     challenge.prompt = prompt;
     challenge.reward_amount = reward_amount;
+    challenge.reward_mint = reward_mint;
+    challenge.prize_split_bps = prize_split_bps;
     challenge.start_time = start_time;
-    challenge.end_time = end_time;
+    challenge.submission_end = submission_end;
+    challenge.voting_end = voting_end;
+    challenge.reveal_end = reveal_end;
+    challenge.voting_mode = voting_mode;
+    challenge.voting_mint = voting_mint;
+    challenge.vote_price = vote_price;
+    challenge.entry_fee = entry_fee;
     challenge.submission_count = 0;
+    challenge.contribution_count = 0;
+    challenge.contributed_amount = 0;
     challenge.total_votes = 0;
-    challenge.winner = None;
+    challenge.open_vote_vaults = 0;
+    challenge.leaderboard = Vec::new();
+    challenge.leaderboard_stale = false;
+    challenge.ranked_count = 0;
+    challenge.winners = Vec::new();
     challenge.completed = false;
+    challenge.cancelled = false;
+    challenge.rent_payer = ctx.accounts.fee_payer.key();
     challenge.bump = ctx.bumps.challenge;
+
+    // Lock the prize pool up front, in the challenge vault or in the challenge itself for SOL
+    if reward_mint.is_some() {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.creator_token_account.as_ref()
+                .ok_or(ErrorCode::MissingTokenAccount)?.to_account_info(),
+            to: ctx.accounts.reward_vault.as_ref()
+                .ok_or(ErrorCode::MissingTokenAccount)?.to_account_info(),
+            authority: ctx.accounts.creator.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+        anchor_spl::token::transfer(cpi_context, reward_amount)?;
+    } else {
+        let cpi_accounts = system_program::Transfer {
+            from: ctx.accounts.creator.to_account_info(),
+            to: ctx.accounts.challenge.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.system_program.to_account_info();
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+        system_program::transfer(cpi_context, reward_amount)?;
+    }
+
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(start_time: i64)]
+#[instruction(
+    title: String,
+    description: String,
+    prompt: String,
+    reward_amount: u64,
+    start_time: i64,
+)]
 pub struct CreateMemeChallenge<'info> {
     #[account(mut)]
     pub fee_payer: Signer<'info>,
@@ -52,5 +132,41 @@ pub struct CreateMemeChallenge<'info> {
     )]
     pub challenge: Account<'info, MemeChallenge>,
 
+    /// Restricts the challenge to this group's members; only group admins may attach it
+    #[account(
+        seeds = [b"group", group.name.as_bytes(), group.creator.as_ref()],
+        bump = group.bump,
+    )]
+    pub group: Option<Account<'info, Group>>,
+
+    /// The creator's membership of `group`, needed unless they own the group
+    #[account(
+        seeds = [b"member", creator_member.group.as_ref(), creator.key().as_ref()],
+        bump = creator_member.bump,
+        constraint = Some(creator_member.group) == group.as_ref().map(|group| group.key()) @ ErrorCode::NotGroupAdmin,
+    )]
+    pub creator_member: Option<Account<'info, GroupMember>>,
+
+    /// Omitted when the prize is paid in native SOL
+    pub reward_mint: Option<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = Some(creator_token_account.mint) == reward_mint.as_ref().map(|mint| mint.key()) @ ErrorCode::InvalidToken,
+        constraint = creator_token_account.owner == creator.key() @ ErrorCode::NotOwner,
+    )]
+    pub creator_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = fee_payer,
+        seeds = [b"challenge_vault", challenge.key().as_ref()],
+        bump,
+        token::mint = reward_mint,
+        token::authority = challenge,
+    )]
+    pub reward_vault: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
-}
\ No newline at end of file
+}
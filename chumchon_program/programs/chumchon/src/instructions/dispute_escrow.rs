@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ErrorCode;
+
+/// Either party may dispute an accepted trade before it expires. Neither can
+/// settle it after that; a moderator of the escrow's group decides it with
+/// `resolve_escrow_dispute`, or both sides are refunded once it expires.
+pub fn handler(ctx: Context<DisputeEscrow>, _created_at: i64) -> Result<()> {
+    let clock = Clock::get()?;
+    let escrow = &mut ctx.accounts.escrow;
+    require!(escrow.expires_at >= clock.unix_timestamp, ErrorCode::EscrowExpired);
+    escrow.transition(EscrowStatus::Disputed, clock.unix_timestamp)
+}
+
+#[derive(Accounts)]
+#[instruction(created_at: i64)]
+pub struct DisputeEscrow<'info> {
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow.initiator.as_ref(), &created_at.to_le_bytes()],
+        bump = escrow.bump,
+        constraint = escrow.status != EscrowStatus::Disputed @ ErrorCode::EscrowDisputed,
+        constraint = escrow.status.can_transition_to(EscrowStatus::Disputed) @ ErrorCode::EscrowNotAccepted,
+    )]
+    pub escrow: Account<'info, Escrow>,
+
+    #[account(
+        constraint = authority.key() == escrow.initiator
+            || authority.key() == escrow.counterparty @ ErrorCode::Unauthorized,
+    )]
+    pub authority: Signer<'info>,
+}
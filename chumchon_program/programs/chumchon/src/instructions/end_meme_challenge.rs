@@ -1,13 +1,72 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use super::prize_pool::{close_vault, pay_lamports, pay_tokens};
 use crate::state::*;
 use crate::error::ErrorCode;
 
-pub fn handler(ctx: Context<EndMemeChallenge>) -> Result<()> {
-    let challenge = &mut ctx.accounts.challenge;
+/// Pays each leaderboard place its share of the pool once results are in; anyone
+/// may call it. The remaining accounts are the recipients in place order:
+/// submitter wallets for a SOL pool, or their token accounts for a token pool.
+/// Rounding dust, the shares of places nobody reached and tokens sent to the
+/// vault beyond the pool go to first place, so the vault can close empty.
+/// Once an entry has dropped off the leaderboard, every submission must first
+/// be ranked again with `rank_meme_submission`.
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, EndMemeChallenge<'info>>) -> Result<()> {
+    let challenge = &ctx.accounts.challenge;
     let clock = Clock::get()?;
-    require!(clock.unix_timestamp >= challenge.end_time, ErrorCode::ChallengeNotEnded);
+    require!(clock.unix_timestamp >= challenge.results_at(), ErrorCode::ChallengeNotEnded);
     require!(!challenge.completed, ErrorCode::ChallengeAlreadyCompleted);
+    challenge.require_active()?;
     require!(challenge.submission_count > 0, ErrorCode::NoSubmissions);
+    require!(!challenge.leaderboard_stale, ErrorCode::LeaderboardIncomplete);
+
+    let recipients = ctx.remaining_accounts;
+    require!(recipients.len() == challenge.leaderboard.len(), ErrorCode::InvalidRecipient);
+
+    let mut shares = challenge.leaderboard.iter()
+        .zip(&challenge.prize_split_bps)
+        .map(|(_, bps)| {
+            let share = (challenge.reward_amount as u128) * (*bps as u128)
+                / (PRIZE_SPLIT_TOTAL_BPS as u128);
+            u64::try_from(share).map_err(|_| error!(ErrorCode::Overflow))
+        })
+        .collect::<Result<Vec<u64>>>()?;
+    let paid: u64 = shares.iter().sum();
+    shares[0] += challenge.reward_amount - paid;
+    if let Some(vault) = ctx.accounts.reward_vault.as_ref() {
+        shares[0] += vault.amount.checked_sub(challenge.reward_amount).ok_or(ErrorCode::Overflow)?;
+    }
+
+    let token_program = ctx.accounts.token_program.to_account_info();
+    for ((entry, recipient), share) in challenge.leaderboard.iter().zip(recipients).zip(shares) {
+        match challenge.reward_mint {
+            Some(reward_mint) => {
+                let token_account = Account::<TokenAccount>::try_from(recipient)?;
+                require_keys_eq!(token_account.mint, reward_mint, ErrorCode::InvalidToken);
+                require_keys_eq!(token_account.owner, entry.submitter, ErrorCode::InvalidRecipient);
+                let vault = ctx.accounts.reward_vault.as_ref()
+                    .ok_or(ErrorCode::MissingTokenAccount)?.to_account_info();
+                pay_tokens(challenge, vault, recipient.clone(), token_program.clone(), share)?;
+            }
+            None => {
+                require_keys_eq!(recipient.key(), entry.submitter, ErrorCode::InvalidRecipient);
+                pay_lamports(challenge, recipient, share)?;
+            }
+        }
+    }
+
+    if let Some(vault) = ctx.accounts.reward_vault.as_ref() {
+        close_vault(
+            challenge,
+            vault.to_account_info(),
+            ctx.accounts.rent_payer.to_account_info(),
+            token_program,
+        )?;
+    }
+
+    let challenge = &mut ctx.accounts.challenge;
+    challenge.winners = challenge.leaderboard.iter().map(|entry| entry.submitter).collect();
     challenge.completed = true;
     Ok(())
 }
@@ -21,12 +80,20 @@ pub struct EndMemeChallenge<'info> {
         mut,
         seeds = [b"challenge", challenge.creator.as_ref(), &challenge.start_time.to_le_bytes()],
         bump = challenge.bump,
-        constraint = challenge.creator == creator.key() @ ErrorCode::NotGroupCreator,
     )]
     pub challenge: Account<'info, MemeChallenge>,
 
-    #[account(mut)]
-    pub creator: Signer<'info>,
+    /// CHECK: Receives the vault's rent
+    #[account(mut, address = challenge.rent_payer @ ErrorCode::NotOwner)]
+    pub rent_payer: AccountInfo<'info>,
 
+    #[account(
+        mut,
+        seeds = [b"challenge_vault", challenge.key().as_ref()],
+        bump,
+    )]
+    pub reward_vault: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
-}
\ No newline at end of file
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{CloseAccount, TokenAccount, Transfer};
+
+use crate::state::*;
+use crate::error::ErrorCode;
+
+/// Unwraps an optional account that an SPL token leg needs
+pub(crate) fn token_leg_account<'info, T: ToAccountInfo<'info>>(
+    account: &Option<T>,
+) -> Result<AccountInfo<'info>> {
+    account
+        .as_ref()
+        .map(|account| account.to_account_info())
+        .ok_or_else(|| error!(ErrorCode::MissingTokenAccount))
+}
+
+/// Pays a token leg out of its vault and returns the vault's rent. The vault
+/// cannot close while it holds anything, so tokens anyone sent in on top of the
+/// leg go back to the depositor's `refund_account`.
+pub(crate) fn release_tokens<'info>(
+    escrow: &Account<'info, Escrow>,
+    vault: AccountInfo<'info>,
+    destination: AccountInfo<'info>,
+    refund_account: Option<AccountInfo<'info>>,
+    rent_destination: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let created_at = escrow.created_at.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"escrow",
+        escrow.initiator.as_ref(),
+        &created_at,
+        &[escrow.bump],
+    ]];
+    let balance = TokenAccount::try_deserialize(&mut &vault.try_borrow_data()?[..])?.amount;
+    let surplus = balance.checked_sub(amount).ok_or(ErrorCode::Overflow)?;
+
+    let mut payouts = vec![(destination, amount)];
+    if surplus > 0 {
+        payouts.push((refund_account.ok_or(ErrorCode::MissingTokenAccount)?, surplus));
+    }
+    for (to, amount) in payouts {
+        anchor_spl::token::transfer(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                Transfer {
+                    from: vault.clone(),
+                    to,
+                    authority: escrow.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+    }
+    anchor_spl::token::close_account(CpiContext::new_with_signer(
+        token_program,
+        CloseAccount {
+            account: vault,
+            destination: rent_destination,
+            authority: escrow.to_account_info(),
+        },
+        signer_seeds,
+    ))
+}
+
+/// Pays a native SOL leg straight out of the escrow PDA
+pub(crate) fn release_lamports<'info>(
+    escrow: &Account<'info, Escrow>,
+    destination: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    escrow.sub_lamports(amount)?;
+    destination.add_lamports(amount)?;
+    Ok(())
+}
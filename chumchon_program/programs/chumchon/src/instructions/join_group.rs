@@ -35,14 +35,17 @@ pub fn handler(ctx: Context<JoinGroup>) -> Result<()> {
     }
 
     let clock = Clock::get()?.unix_timestamp;
+    GroupBan::require_not_banned(&ctx.accounts.ban_record, clock)?;
     member_record.group = group.key();
     member_record.member = member.key();
     member_record.joined_at = clock;
+    member_record.role = GroupRole::Member;
+    member_record.rent_payer = ctx.accounts.fee_payer.key();
     member_record.bump = ctx.bumps.member_record;
 
     // Update group member count
     let group_account = &mut ctx.accounts.group;
-    group_account.member_count += 1;
+    group_account.member_count = group_account.member_count.checked_add(1).ok_or(ErrorCode::Overflow)?;
 
     Ok(())
 }
@@ -67,6 +70,13 @@ pub struct JoinGroup<'info> {
     )]
     pub member_record: Account<'info, GroupMember>,
 
+    /// CHECK: The member's ban address, which only matters once it holds a ban
+    #[account(
+        seeds = [b"ban", group.key().as_ref(), member.key().as_ref()],
+        bump,
+    )]
+    pub ban_record: AccountInfo<'info>,
+
     /// CHECK: Optional token account for groups requiring tokens
     pub member_token_account: Option<AccountInfo<'info>>,
 
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ErrorCode;
+
+/// Removes a member outranked by the authority, who must be a moderator or
+/// above. The member may join again; ban them to keep them out.
+pub fn handler(ctx: Context<KickMember>) -> Result<()> {
+    let group = &mut ctx.accounts.group;
+    let role = group.member_role(&ctx.accounts.member_record);
+    group.require_outranks(ctx.accounts.authority_member.as_ref(), ctx.accounts.authority.key(), role)?;
+    group.remove_member()
+}
+
+#[derive(Accounts)]
+pub struct KickMember<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"group", group.name.as_bytes(), group.creator.as_ref()],
+        bump = group.bump,
+    )]
+    pub group: Account<'info, Group>,
+
+    /// Required unless the authority owns the group
+    #[account(
+        seeds = [b"member", group.key().as_ref(), authority.key().as_ref()],
+        bump = authority_member.bump,
+    )]
+    pub authority_member: Option<Account<'info, GroupMember>>,
+
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), member_record.member.as_ref()],
+        bump = member_record.bump,
+        close = rent_payer,
+    )]
+    pub member_record: Account<'info, GroupMember>,
+
+    /// CHECK: Receives the membership record's rent
+    #[account(mut, address = member_record.rent_payer @ ErrorCode::NotOwner)]
+    pub rent_payer: AccountInfo<'info>,
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ErrorCode;
+
+/// Closes the caller's membership, returning its rent to whoever paid for it
+pub fn handler(ctx: Context<LeaveGroup>) -> Result<()> {
+    ctx.accounts.group.remove_member()
+}
+
+#[derive(Accounts)]
+pub struct LeaveGroup<'info> {
+    pub member: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"group", group.name.as_bytes(), group.creator.as_ref()],
+        bump = group.bump,
+    )]
+    pub group: Account<'info, Group>,
+
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), member.key().as_ref()],
+        bump = member_record.bump,
+        close = rent_payer,
+    )]
+    pub member_record: Account<'info, GroupMember>,
+
+    /// CHECK: Receives the membership record's rent
+    #[account(mut, address = member_record.rent_payer @ ErrorCode::NotOwner)]
+    pub rent_payer: AccountInfo<'info>,
+}
@@ -1,17 +1,92 @@
+#![allow(ambiguous_glob_reexports)]
+
 pub mod accept_escrow;
+pub mod accept_group_ownership;
+pub mod add_group_admin;
+pub mod ban_member;
+pub mod cancel_escrow;
+pub mod cancel_meme_challenge;
+pub mod change_meme_vote;
+pub mod close_challenge_contribution;
+pub mod close_meme_challenge;
+pub mod close_meme_submission;
+pub mod close_voter_record;
+pub mod commit_meme_vote;
 pub mod complete_escrow;
 pub mod complete_tutorial;
+pub mod contribute_to_challenge;
 pub mod create_escrow;
 pub mod create_group;
 pub mod create_invite;
 pub mod create_meme_challenge;
 pub mod create_user_profile;
+pub mod dispute_escrow;
 pub mod end_meme_challenge;
+mod escrow_funds;
 pub mod join_group;
+pub mod kick_member;
+pub mod leave_group;
+mod prize_pool;
+pub mod rank_meme_submission;
+pub mod reclaim_expired_escrow;
+pub mod refund_contribution;
+pub mod refund_entry_fee;
+pub mod remove_group_admin;
+pub mod resolve_escrow_dispute;
+pub mod reveal_meme_vote;
 pub mod send_message;
 pub mod set_profile_nft;
 pub mod submit_meme;
 pub mod tip_message;
+pub mod transfer_group_ownership;
+pub mod unban_member;
+pub mod update_group;
 pub mod update_user_profile;
 pub mod use_invite;
-pub mod vote_for_meme;
\ No newline at end of file
+pub mod vote_for_meme;
+mod vote_weight;
+pub mod withdraw_vote_tokens;
+
+pub use accept_escrow::*;
+pub use accept_group_ownership::*;
+pub use add_group_admin::*;
+pub use ban_member::*;
+pub use cancel_escrow::*;
+pub use cancel_meme_challenge::*;
+pub use change_meme_vote::*;
+pub use close_challenge_contribution::*;
+pub use close_meme_challenge::*;
+pub use close_meme_submission::*;
+pub use close_voter_record::*;
+pub use commit_meme_vote::*;
+pub use complete_escrow::*;
+pub use complete_tutorial::*;
+pub use contribute_to_challenge::*;
+pub use create_escrow::*;
+pub use create_group::*;
+pub use create_invite::*;
+pub use create_meme_challenge::*;
+pub use create_user_profile::*;
+pub use dispute_escrow::*;
+pub use end_meme_challenge::*;
+pub use join_group::*;
+pub use kick_member::*;
+pub use leave_group::*;
+pub use rank_meme_submission::*;
+pub use reclaim_expired_escrow::*;
+pub use refund_contribution::*;
+pub use refund_entry_fee::*;
+pub use remove_group_admin::*;
+pub use resolve_escrow_dispute::*;
+pub use reveal_meme_vote::*;
+pub use send_message::*;
+pub use set_profile_nft::*;
+pub use submit_meme::*;
+pub use tip_message::*;
+pub use transfer_group_ownership::*;
+pub use unban_member::*;
+pub use update_group::*;
+pub use update_user_profile::*;
+pub use use_invite::*;
+pub use vote_for_meme::*;
+pub use withdraw_vote_tokens::*;
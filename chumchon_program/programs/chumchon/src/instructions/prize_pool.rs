@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{CloseAccount, Transfer};
+
+use crate::state::*;
+
+/// Pays tokens out of a vault held by the challenge, such as its prize pool
+pub(crate) fn pay_tokens<'info>(
+    challenge: &Account<'info, MemeChallenge>,
+    vault: AccountInfo<'info>,
+    destination: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let start_time = challenge.start_time.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"challenge",
+        challenge.creator.as_ref(),
+        &start_time,
+        &[challenge.bump],
+    ]];
+
+    anchor_spl::token::transfer(
+        CpiContext::new_with_signer(
+            token_program,
+            Transfer {
+                from: vault,
+                to: destination,
+                authority: challenge.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+/// Closes an emptied vault held by the challenge
+pub(crate) fn close_vault<'info>(
+    challenge: &Account<'info, MemeChallenge>,
+    vault: AccountInfo<'info>,
+    rent_destination: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+) -> Result<()> {
+    let start_time = challenge.start_time.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"challenge",
+        challenge.creator.as_ref(),
+        &start_time,
+        &[challenge.bump],
+    ]];
+
+    anchor_spl::token::close_account(CpiContext::new_with_signer(
+        token_program,
+        CloseAccount {
+            account: vault,
+            destination: rent_destination,
+            authority: challenge.to_account_info(),
+        },
+        signer_seeds,
+    ))
+}
+
+/// Pays part of a native SOL prize pool straight out of the challenge PDA
+pub(crate) fn pay_lamports<'info>(
+    challenge: &Account<'info, MemeChallenge>,
+    destination: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    challenge.sub_lamports(amount)?;
+    destination.add_lamports(amount)?;
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ErrorCode;
+
+/// Anyone may re-rank a submission, so places that entries lost by losing
+/// votes show the next best again. Once results are in, ranking each
+/// submission of a stale board once rebuilds it, one transaction at a time,
+/// so the challenge can end.
+pub fn handler(ctx: Context<RankMemeSubmission>) -> Result<()> {
+    let challenge = &mut ctx.accounts.challenge;
+    let submission = &mut ctx.accounts.submission;
+    require!(!challenge.completed, ErrorCode::ChallengeAlreadyCompleted);
+
+    challenge.record_votes(LeaderboardEntry {
+        submission: submission.key(),
+        submitter: submission.submitter,
+        votes: submission.votes,
+        submitted_at: submission.submitted_at,
+    });
+
+    // Votes can still move before results are in, so only later ranks count
+    let votes_final = Clock::get()?.unix_timestamp >= challenge.results_at();
+    if challenge.leaderboard_stale && votes_final && !submission.ranked {
+        submission.ranked = true;
+        challenge.count_ranked()?;
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RankMemeSubmission<'info> {
+    #[account(
+        mut,
+        seeds = [b"challenge", challenge.creator.as_ref(), &challenge.start_time.to_le_bytes()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, MemeChallenge>,
+
+    #[account(
+        mut,
+        seeds = [b"submission", challenge.key().as_ref(), submission.submitter.as_ref()],
+        bump = submission.bump,
+    )]
+    pub submission: Account<'info, MemeSubmission>,
+}
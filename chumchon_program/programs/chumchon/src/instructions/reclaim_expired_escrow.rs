@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use super::escrow_funds::{release_lamports, release_tokens, token_leg_account};
+use crate::state::*;
+use crate::error::ErrorCode;
+
+pub fn handler(ctx: Context<ReclaimExpiredEscrow>, _created_at: i64) -> Result<()> {
+    // Anyone may trigger the refund; funds only ever go back to the parties
+    let clock = Clock::get()?;
+    require!(clock.unix_timestamp > ctx.accounts.escrow.expires_at, ErrorCode::EscrowNotExpired);
+    let counterparty_deposited = matches!(
+        ctx.accounts.escrow.status,
+        EscrowStatus::Accepted | EscrowStatus::Disputed
+    );
+    ctx.accounts.escrow.transition(EscrowStatus::Expired, clock.unix_timestamp)?;
+
+    let escrow = &ctx.accounts.escrow;
+    let token_program = ctx.accounts.token_program.to_account_info();
+
+    // Refund the initiator's deposit
+    match escrow.initiator_token {
+        Some(_) => release_tokens(
+            escrow,
+            token_leg_account(&ctx.accounts.initiator_vault)?,
+            token_leg_account(&ctx.accounts.initiator_refund_account)?,
+            Some(token_leg_account(&ctx.accounts.initiator_refund_account)?),
+            ctx.accounts.initiator_rent_payer.to_account_info(),
+            token_program.clone(),
+            escrow.initiator_amount,
+        )?,
+        None => release_lamports(escrow, &ctx.accounts.initiator, escrow.initiator_amount)?,
+    }
+
+    // Refund the counterparty's deposit if the escrow was accepted but never settled
+    if counterparty_deposited {
+        match escrow.counterparty_token {
+            Some(_) => release_tokens(
+                escrow,
+                token_leg_account(&ctx.accounts.counterparty_vault)?,
+                token_leg_account(&ctx.accounts.counterparty_refund_account)?,
+                Some(token_leg_account(&ctx.accounts.counterparty_refund_account)?),
+                token_leg_account(&ctx.accounts.counterparty_rent_payer)?,
+                token_program,
+                escrow.counterparty_amount,
+            )?,
+            None => release_lamports(escrow, &ctx.accounts.counterparty, escrow.counterparty_amount)?,
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(created_at: i64)]
+pub struct ReclaimExpiredEscrow<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow.initiator.as_ref(), &created_at.to_le_bytes()],
+        bump = escrow.bump,
+        close = initiator_rent_payer,
+    )]
+    pub escrow: Account<'info, Escrow>,
+
+    /// CHECK: Receives the initiator's refund when it is native SOL
+    #[account(mut, address = escrow.initiator @ ErrorCode::NotOwner)]
+    pub initiator: AccountInfo<'info>,
+
+    /// CHECK: Receives the counterparty's refund when it is native SOL
+    #[account(mut, address = escrow.counterparty @ ErrorCode::NotOwner)]
+    pub counterparty: AccountInfo<'info>,
+
+    /// CHECK: Receives the escrow and initiator vault rent
+    #[account(mut, address = escrow.initiator_rent_payer @ ErrorCode::NotOwner)]
+    pub initiator_rent_payer: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"initiator_vault", escrow.key().as_ref()],
+        bump,
+    )]
+    pub initiator_vault: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = Some(initiator_refund_account.mint) == escrow.initiator_token @ ErrorCode::InvalidToken,
+        constraint = initiator_refund_account.owner == escrow.initiator @ ErrorCode::NotOwner,
+    )]
+    pub initiator_refund_account: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: Receives the counterparty vault rent, only needed for an accepted token leg
+    #[account(mut, address = escrow.counterparty_rent_payer @ ErrorCode::NotOwner)]
+    pub counterparty_rent_payer: Option<AccountInfo<'info>>,
+
+    #[account(
+        mut,
+        seeds = [b"counterparty_vault", escrow.key().as_ref()],
+        bump,
+    )]
+    pub counterparty_vault: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = Some(counterparty_refund_account.mint) == escrow.counterparty_token @ ErrorCode::InvalidToken,
+        constraint = counterparty_refund_account.owner == escrow.counterparty @ ErrorCode::NotOwner,
+    )]
+    pub counterparty_refund_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
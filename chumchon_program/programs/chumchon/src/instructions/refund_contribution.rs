@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use super::prize_pool::{pay_lamports, pay_tokens};
+use crate::state::*;
+use crate::error::ErrorCode;
+
+/// Returns a contribution to its contributor once the challenge is cancelled or
+/// can no longer pay out
+pub fn handler(ctx: Context<RefundContribution>) -> Result<()> {
+    let challenge = &ctx.accounts.challenge;
+    let amount = ctx.accounts.contribution.amount;
+    require!(challenge.refunds_contributions(Clock::get()?.unix_timestamp), ErrorCode::RefundUnavailable);
+
+    match challenge.reward_mint {
+        Some(reward_mint) => {
+            let token_account = ctx.accounts.contributor_token_account.as_ref()
+                .ok_or(ErrorCode::MissingTokenAccount)?;
+            require_keys_eq!(token_account.mint, reward_mint, ErrorCode::InvalidToken);
+            let vault = ctx.accounts.reward_vault.as_ref()
+                .ok_or(ErrorCode::MissingTokenAccount)?.to_account_info();
+            pay_tokens(
+                challenge,
+                vault,
+                token_account.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+                amount,
+            )?;
+        }
+        None => pay_lamports(challenge, &ctx.accounts.contributor.to_account_info(), amount)?,
+    }
+
+    let challenge = &mut ctx.accounts.challenge;
+    challenge.reward_amount = challenge.reward_amount.checked_sub(amount).ok_or(ErrorCode::Overflow)?;
+    challenge.contributed_amount = challenge.contributed_amount.checked_sub(amount).ok_or(ErrorCode::Overflow)?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RefundContribution<'info> {
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"challenge", challenge.creator.as_ref(), &challenge.start_time.to_le_bytes()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, MemeChallenge>,
+
+    #[account(
+        mut,
+        seeds = [b"contribution", challenge.key().as_ref(), &contribution.contribution_id.to_le_bytes()],
+        bump = contribution.bump,
+        constraint = contribution.contributor == contributor.key() @ ErrorCode::NotOwner,
+        close = rent_payer,
+    )]
+    pub contribution: Account<'info, ChallengeContribution>,
+
+    /// CHECK: Receives the contribution record's rent
+    #[account(mut, address = contribution.rent_payer @ ErrorCode::NotOwner)]
+    pub rent_payer: AccountInfo<'info>,
+
+    /// Receives the refund of a token pool
+    #[account(
+        mut,
+        constraint = contributor_token_account.owner == contributor.key() @ ErrorCode::NotOwner,
+    )]
+    pub contributor_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"challenge_vault", challenge.key().as_ref()],
+        bump,
+    )]
+    pub reward_vault: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
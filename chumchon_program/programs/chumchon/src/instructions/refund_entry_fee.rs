@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use super::prize_pool::{pay_lamports, pay_tokens};
+use crate::state::*;
+use crate::error::ErrorCode;
+
+/// Returns a submission's entry fee once the challenge is cancelled and closes
+/// the submission and its content guard, whose rent goes back to whoever paid it. Free entries close
+/// this way too, which the challenge waits for before it can close.
+pub fn handler(ctx: Context<RefundEntryFee>) -> Result<()> {
+    let challenge = &ctx.accounts.challenge;
+    let entry_fee = challenge.entry_fee;
+    require!(challenge.cancelled, ErrorCode::ChallengeNotCancelled);
+
+    if entry_fee > 0 {
+        match challenge.reward_mint {
+            Some(reward_mint) => {
+                let token_account = ctx.accounts.submitter_token_account.as_ref()
+                    .ok_or(ErrorCode::MissingTokenAccount)?;
+                require_keys_eq!(token_account.mint, reward_mint, ErrorCode::InvalidToken);
+                let vault = ctx.accounts.reward_vault.as_ref()
+                    .ok_or(ErrorCode::MissingTokenAccount)?.to_account_info();
+                pay_tokens(
+                    challenge,
+                    vault,
+                    token_account.to_account_info(),
+                    ctx.accounts.token_program.to_account_info(),
+                    entry_fee,
+                )?;
+            }
+            None => pay_lamports(challenge, &ctx.accounts.submitter.to_account_info(), entry_fee)?,
+        }
+    }
+
+    let challenge = &mut ctx.accounts.challenge;
+    challenge.reward_amount = challenge.reward_amount.checked_sub(entry_fee).ok_or(ErrorCode::Overflow)?;
+    challenge.submission_count = challenge.submission_count.checked_sub(1).ok_or(ErrorCode::Overflow)?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RefundEntryFee<'info> {
+    #[account(mut)]
+    pub submitter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"challenge", challenge.creator.as_ref(), &challenge.start_time.to_le_bytes()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, MemeChallenge>,
+
+    #[account(
+        mut,
+        seeds = [b"submission", challenge.key().as_ref(), submitter.key().as_ref()],
+        bump = submission.bump,
+        close = rent_payer,
+    )]
+    pub submission: Account<'info, MemeSubmission>,
+
+    /// Frees the submission's image hash, and its rent goes to the rent payer too
+    #[account(
+        mut,
+        seeds = [b"content", challenge.key().as_ref(), submission.content_hash.as_ref()],
+        bump = content_guard.bump,
+        close = rent_payer,
+    )]
+    pub content_guard: Account<'info, ContentGuard>,
+
+    /// CHECK: Receives the rent of the submission and its content guard
+    #[account(mut, address = submission.rent_payer @ ErrorCode::NotOwner)]
+    pub rent_payer: AccountInfo<'info>,
+
+    /// Receives the refund of a token pool
+    #[account(
+        mut,
+        constraint = submitter_token_account.owner == submitter.key() @ ErrorCode::NotOwner,
+    )]
+    pub submitter_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"challenge_vault", challenge.key().as_ref()],
+        bump,
+    )]
+    pub reward_vault: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
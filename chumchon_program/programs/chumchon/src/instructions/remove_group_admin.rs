@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ErrorCode;
+
+/// Returns an admin or moderator to a plain member. Admins may only be
+/// removed by the owner, moderators by admins as well.
+pub fn handler(ctx: Context<RemoveGroupAdmin>) -> Result<()> {
+    let role = ctx.accounts.group.member_role(&ctx.accounts.member_record);
+    require!(matches!(role, GroupRole::Admin | GroupRole::Moderator), ErrorCode::InvalidRole);
+    ctx.accounts.group.require_outranks(
+        ctx.accounts.authority_member.as_ref(),
+        ctx.accounts.authority.key(),
+        role,
+    )?;
+
+    ctx.accounts.member_record.role = GroupRole::Member;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveGroupAdmin<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"group", group.name.as_bytes(), group.creator.as_ref()],
+        bump = group.bump,
+    )]
+    pub group: Account<'info, Group>,
+
+    /// Required unless the authority owns the group
+    #[account(
+        seeds = [b"member", group.key().as_ref(), authority.key().as_ref()],
+        bump = authority_member.bump,
+    )]
+    pub authority_member: Option<Account<'info, GroupMember>>,
+
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), member_record.member.as_ref()],
+        bump = member_record.bump,
+    )]
+    pub member_record: Account<'info, GroupMember>,
+}
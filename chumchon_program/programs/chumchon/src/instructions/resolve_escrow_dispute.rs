@@ -0,0 +1,153 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use super::escrow_funds::{release_lamports, release_tokens, token_leg_account};
+use crate::state::*;
+use crate::error::ErrorCode;
+
+/// A moderator of the escrow's group settles a disputed trade as agreed when
+/// `settle` is set, or otherwise cancels it and refunds both sides
+pub fn handler(ctx: Context<ResolveEscrowDispute>, _created_at: i64, settle: bool) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    require!(
+        ctx.accounts.group.has_role(ctx.accounts.authority_member.as_ref(), authority, GroupRole::Moderator),
+        ErrorCode::NotGroupAdmin
+    );
+    let next = if settle { EscrowStatus::Settled } else { EscrowStatus::Cancelled };
+    ctx.accounts.escrow.transition(next, Clock::get()?.unix_timestamp)?;
+
+    let escrow = &ctx.accounts.escrow;
+    let token_program = ctx.accounts.token_program.to_account_info();
+
+    // Settling swaps the legs; cancelling hands each back to whoever deposited it
+    let (initiator_leg_to, initiator_sol_to) = match settle {
+        true => (&ctx.accounts.counterparty_receive_account, &ctx.accounts.counterparty),
+        false => (&ctx.accounts.initiator_refund_account, &ctx.accounts.initiator),
+    };
+    let (counterparty_leg_to, counterparty_sol_to) = match settle {
+        true => (&ctx.accounts.initiator_receive_account, &ctx.accounts.initiator),
+        false => (&ctx.accounts.counterparty_refund_account, &ctx.accounts.counterparty),
+    };
+
+    match escrow.initiator_token {
+        Some(_) => release_tokens(
+            escrow,
+            token_leg_account(&ctx.accounts.initiator_vault)?,
+            token_leg_account(initiator_leg_to)?,
+            ctx.accounts.initiator_refund_account.as_ref().map(|account| account.to_account_info()),
+            ctx.accounts.initiator_rent_payer.to_account_info(),
+            token_program.clone(),
+            escrow.initiator_amount,
+        )?,
+        None => release_lamports(escrow, initiator_sol_to, escrow.initiator_amount)?,
+    }
+    match escrow.counterparty_token {
+        Some(_) => release_tokens(
+            escrow,
+            token_leg_account(&ctx.accounts.counterparty_vault)?,
+            token_leg_account(counterparty_leg_to)?,
+            ctx.accounts.counterparty_refund_account.as_ref().map(|account| account.to_account_info()),
+            ctx.accounts.counterparty_rent_payer.to_account_info(),
+            token_program,
+            escrow.counterparty_amount,
+        )?,
+        None => release_lamports(escrow, counterparty_sol_to, escrow.counterparty_amount)?,
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(created_at: i64)]
+pub struct ResolveEscrowDispute<'info> {
+    #[account(
+        mut,
+        seeds = [b"escrow", escrow.initiator.as_ref(), &created_at.to_le_bytes()],
+        bump = escrow.bump,
+        constraint = escrow.status == EscrowStatus::Disputed @ ErrorCode::EscrowNotDisputed,
+        close = initiator_rent_payer,
+    )]
+    pub escrow: Account<'info, Escrow>,
+
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"group", group.name.as_bytes(), group.creator.as_ref()],
+        bump = group.bump,
+        address = escrow.group @ ErrorCode::NotGroupMember,
+    )]
+    pub group: Account<'info, Group>,
+
+    /// Required unless the authority owns the group
+    #[account(
+        seeds = [b"member", group.key().as_ref(), authority.key().as_ref()],
+        bump = authority_member.bump,
+    )]
+    pub authority_member: Option<Account<'info, GroupMember>>,
+
+    /// CHECK: Receives a native SOL leg owed to the initiator
+    #[account(mut, address = escrow.initiator @ ErrorCode::NotOwner)]
+    pub initiator: AccountInfo<'info>,
+
+    /// CHECK: Receives a native SOL leg owed to the counterparty
+    #[account(mut, address = escrow.counterparty @ ErrorCode::NotOwner)]
+    pub counterparty: AccountInfo<'info>,
+
+    /// CHECK: Receives the escrow and initiator vault rent
+    #[account(mut, address = escrow.initiator_rent_payer @ ErrorCode::NotOwner)]
+    pub initiator_rent_payer: AccountInfo<'info>,
+
+    /// CHECK: Receives the counterparty vault rent
+    #[account(mut, address = escrow.counterparty_rent_payer @ ErrorCode::NotOwner)]
+    pub counterparty_rent_payer: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"initiator_vault", escrow.key().as_ref()],
+        bump,
+    )]
+    pub initiator_vault: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"counterparty_vault", escrow.key().as_ref()],
+        bump,
+    )]
+    pub counterparty_vault: Option<Account<'info, TokenAccount>>,
+
+    /// Receives the counterparty's token leg when the trade is settled
+    #[account(
+        mut,
+        constraint = Some(initiator_receive_account.mint) == escrow.counterparty_token @ ErrorCode::InvalidToken,
+        constraint = initiator_receive_account.owner == escrow.initiator @ ErrorCode::NotOwner,
+    )]
+    pub initiator_receive_account: Option<Account<'info, TokenAccount>>,
+
+    /// Receives the initiator's token leg when the trade is settled
+    #[account(
+        mut,
+        constraint = Some(counterparty_receive_account.mint) == escrow.initiator_token @ ErrorCode::InvalidToken,
+        constraint = counterparty_receive_account.owner == escrow.counterparty @ ErrorCode::NotOwner,
+    )]
+    pub counterparty_receive_account: Option<Account<'info, TokenAccount>>,
+
+    /// Takes back the initiator's token leg when the trade is cancelled, and
+    /// anything sent to its vault beyond the leg
+    #[account(
+        mut,
+        constraint = Some(initiator_refund_account.mint) == escrow.initiator_token @ ErrorCode::InvalidToken,
+        constraint = initiator_refund_account.owner == escrow.initiator @ ErrorCode::NotOwner,
+    )]
+    pub initiator_refund_account: Option<Account<'info, TokenAccount>>,
+
+    /// Takes back the counterparty's token leg when the trade is cancelled, and
+    /// anything sent to its vault beyond the leg
+    #[account(
+        mut,
+        constraint = Some(counterparty_refund_account.mint) == escrow.counterparty_token @ ErrorCode::InvalidToken,
+        constraint = counterparty_refund_account.owner == escrow.counterparty @ ErrorCode::NotOwner,
+    )]
+    pub counterparty_refund_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
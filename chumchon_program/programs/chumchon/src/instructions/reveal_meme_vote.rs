@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ErrorCode;
+
+pub fn handler(ctx: Context<RevealMemeVote>, salt: [u8; 32]) -> Result<()> {
+    let challenge = &mut ctx.accounts.challenge;
+    let submission = &mut ctx.accounts.submission;
+    let voter_record = &mut ctx.accounts.voter_record;
+    let voter = ctx.accounts.voter.key();
+    let clock = Clock::get()?;
+    let reveal_end = challenge.reveal_end.ok_or(ErrorCode::InvalidVotingMode)?;
+    require!(challenge.voting_end <= clock.unix_timestamp && clock.unix_timestamp < reveal_end, ErrorCode::RevealNotOpen);
+    require!(!voter_record.revealed, ErrorCode::VoteAlreadyRevealed);
+    require!(
+        voter_record.commitment == Some(VoterRecord::commitment_for(&submission.key(), &voter, &salt)),
+        ErrorCode::InvalidReveal
+    );
+    require_neq!(submission.submitter, voter, ErrorCode::CannotVoteOwnSubmission);
+
+    challenge.count_vote(submission, voter_record.weight)?;
+    voter_record.submission = submission.key();
+    voter_record.revealed = true;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevealMemeVote<'info> {
+    pub voter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"challenge", challenge.creator.as_ref(), &challenge.start_time.to_le_bytes()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, MemeChallenge>,
+
+    #[account(
+        mut,
+        seeds = [b"submission", challenge.key().as_ref(), submission.submitter.as_ref()],
+        bump = submission.bump,
+    )]
+    pub submission: Account<'info, MemeSubmission>,
+
+    #[account(
+        mut,
+        seeds = [b"voter", challenge.key().as_ref(), voter.key().as_ref()],
+        bump = voter_record.bump,
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+}
@@ -18,18 +18,19 @@ pub struct SendMessage<'info> {
     )]
     pub group: Account<'info, Group>,
 
+    /// Required unless the message comes from the group's owner
     #[account(
         seeds = [b"member", group.key().as_ref(), sender.key().as_ref()],
         bump = member_record.bump,
         constraint = member_record.member == sender.key() @ ErrorCode::NotGroupMember,
     )]
-    pub member_record: Account<'info, GroupMember>,
+    pub member_record: Option<Account<'info, GroupMember>>,
 
     #[account(
         init,
         payer = fee_payer,
         space = Message::LEN,
-        seeds = [b"message", group.key().as_ref(), sender.key().as_ref()],
+        seeds = [b"message", group.key().as_ref(), &group.message_count.to_le_bytes()],
         bump,
     )]
     pub message: Account<'info, Message>,
@@ -38,29 +39,39 @@ pub struct SendMessage<'info> {
 }
 
 pub fn handler(ctx: Context<SendMessage>, content: String) -> Result<()> {
-    require!(content.len() <= 1000, ErrorCode::ContentTooLong);
+    require!(content.len() <= 500, ErrorCode::ContentTooLong);
     
-    let group = &ctx.accounts.group;
+    let group = &mut ctx.accounts.group;
     let sender = &ctx.accounts.sender;
-    let member_record = &ctx.accounts.member_record;
+    let member_record = ctx.accounts.member_record.as_ref();
 
-    // Check if user is a member of the group
-    require_keys_eq!(member_record.group, group.key(), ErrorCode::NotGroupMember);
-    require_keys_eq!(member_record.member, sender.key(), ErrorCode::NotGroupMember);
+    // The owner posts whether or not they have joined; anyone else must be a member
+    if group.owner != sender.key() {
+        let member_record = member_record.ok_or(ErrorCode::NotGroupMember)?;
+        require_keys_eq!(member_record.group, group.key(), ErrorCode::NotGroupMember);
+        require_keys_eq!(member_record.member, sender.key(), ErrorCode::NotGroupMember);
+    }
 
-    // Check if it's a channel and if the sender is the creator
+    // Only admins post in channels
     if group.is_channel {
-        require_keys_eq!(group.creator, sender.key(), ErrorCode::ChannelPostingRestricted);
+        require!(
+            group.has_role(member_record, sender.key(), GroupRole::Admin),
+            ErrorCode::ChannelPostingRestricted
+        );
     }
 
     let message = &mut ctx.accounts.message;
     let clock = Clock::get()?.unix_timestamp;
     message.group = group.key();
     message.sender = sender.key();
+    message.message_id = group.message_count;
     message.content = content;
     message.timestamp = clock;
     message.tips_received = 0;
     message.bump = ctx.bumps.message;
 
+    group.message_count = group.message_count.checked_add(1).ok_or(ErrorCode::Overflow)?;
+    group.last_message_at = clock;
+
     Ok(())
 }
\ No newline at end of file
@@ -1,12 +1,11 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::TokenAccount;
+use anchor_spl::token::{Mint, Token, TokenAccount};
 
 use crate::state::*;
 use crate::error::ErrorCode;
 
 
 #[derive(Accounts)]
-#[instruction(nft_mint: Pubkey)]
 pub struct SetProfileNft<'info> {
     #[account(mut)]
     pub fee_payer: Signer<'info>,
@@ -25,7 +24,7 @@ pub struct SetProfileNft<'info> {
     #[account(
         constraint = nft_token_account.owner == owner.key() @ ErrorCode::NotOwner,
         constraint = nft_token_account.amount == 1 @ ErrorCode::NoNFT,
-        constraint = nft_token_account.mint == nft_mint @ ErrorCode::InvalidMint,
+        constraint = nft_token_account.mint == nft_mint.key() @ ErrorCode::InvalidMint,
     )]
     pub nft_token_account: Account<'info, TokenAccount>,
 
@@ -38,6 +37,7 @@ pub fn handler(
     ctx: Context<SetProfileNft>,
     nft_mint: Pubkey,
 ) -> Result<()> {
+    require_keys_eq!(ctx.accounts.nft_mint.key(), nft_mint, ErrorCode::InvalidMint);
     let profile = &mut ctx.accounts.profile;
     profile.nft_profile_picture = Some(nft_mint);
     Ok(())
@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{Token, TokenAccount, Transfer};
 
 use crate::state::*;
 use crate::error::ErrorCode;
@@ -8,15 +10,29 @@ pub fn handler(
     image_url: String,
     title: String,
     description: String,
+    content_hash: [u8; 32],
 ) -> Result<()> {
     let challenge = &mut ctx.accounts.challenge;
     let clock = Clock::get()?;
     
+    challenge.require_active()?;
     require!(clock.unix_timestamp > challenge.start_time, ErrorCode::ChallengeNotStarted);
-    require!(clock.unix_timestamp < challenge.end_time, ErrorCode::ChallengeEnded);
-    require!(image_url.len() <= 256, ErrorCode::UrlTooLong);
+    require!(clock.unix_timestamp < challenge.submission_end, ErrorCode::SubmissionsClosed);
+    challenge.require_member(ctx.accounts.member_record.as_ref(), ctx.accounts.submitter.key())?;
+    require!(image_url.len() <= 200, ErrorCode::UrlTooLong);
     require!(title.len() <= 64, ErrorCode::TitleTooLong);
     require!(description.len() <= 256, ErrorCode::DescriptionTooLong);
+    claim_content_hash(
+        &ctx.accounts.content_guard,
+        &ctx.accounts.fee_payer,
+        &ctx.accounts.system_program,
+        ContentGuard {
+            challenge: challenge.key(),
+            content_hash,
+            submission: ctx.accounts.submission.key(),
+            bump: ctx.bumps.content_guard,
+        },
+    )?;
     
     let submission = &mut ctx.accounts.submission;
     submission.challenge = challenge.key();
@@ -24,16 +40,109 @@ pub fn handler(
     submission.image_url = image_url;
     submission.title = title;
     submission.description = description;
+    submission.content_hash = content_hash;
     submission.votes = 0;
     submission.submitted_at = clock.unix_timestamp;
+    submission.ranked = false;
+    submission.rent_payer = ctx.accounts.fee_payer.key();
     submission.bump = ctx.bumps.submission;
     
     challenge.submission_count += 1;
+
+    // Entry fees grow the pool they compete for
+    let entry_fee = challenge.entry_fee;
+    if entry_fee > 0 {
+        if challenge.reward_mint.is_some() {
+            let token_account = ctx.accounts.submitter_token_account.as_ref()
+                .ok_or(ErrorCode::MissingTokenAccount)?;
+            require!(Some(token_account.mint) == challenge.reward_mint, ErrorCode::InvalidToken);
+            let cpi_accounts = Transfer {
+                from: token_account.to_account_info(),
+                to: ctx.accounts.reward_vault.as_ref()
+                    .ok_or(ErrorCode::MissingTokenAccount)?.to_account_info(),
+                authority: ctx.accounts.submitter.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+            anchor_spl::token::transfer(cpi_context, entry_fee)?;
+        } else {
+            let cpi_accounts = system_program::Transfer {
+                from: ctx.accounts.submitter.to_account_info(),
+                to: challenge.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.system_program.to_account_info();
+            let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+            system_program::transfer(cpi_context, entry_fee)?;
+        }
+        challenge.reward_amount = challenge.reward_amount.checked_add(entry_fee).ok_or(ErrorCode::Overflow)?;
+    }
+
+    // Early entries hold the open places until others out-vote them
+    challenge.record_votes(LeaderboardEntry {
+        submission: submission.key(),
+        submitter: submission.submitter,
+        votes: 0,
+        submitted_at: submission.submitted_at,
+    });
     
     Ok(())
 }
 
+/// Creates the challenge's guard for an image hash, failing with a dedicated
+/// error rather than a system one when the hash was already entered
+fn claim_content_hash<'info>(
+    content_guard: &AccountInfo<'info>,
+    fee_payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    guard: ContentGuard,
+) -> Result<()> {
+    require_keys_neq!(*content_guard.owner, crate::ID, ErrorCode::DuplicateSubmission);
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"content",
+        guard.challenge.as_ref(),
+        &guard.content_hash,
+        &[guard.bump],
+    ]];
+    let rent = Rent::get()?.minimum_balance(ContentGuard::LEN);
+    let system_program = system_program.to_account_info();
+
+    // Someone may have sent lamports to the address ahead of time, which rules out create_account
+    if content_guard.lamports() == 0 {
+        let cpi_accounts = system_program::CreateAccount {
+            from: fee_payer.to_account_info(),
+            to: content_guard.clone(),
+        };
+        let cpi_context = CpiContext::new_with_signer(system_program, cpi_accounts, signer_seeds);
+        system_program::create_account(cpi_context, rent, ContentGuard::LEN as u64, &crate::ID)?;
+    } else {
+        let top_up = rent.saturating_sub(content_guard.lamports());
+        if top_up > 0 {
+            let cpi_accounts = system_program::Transfer {
+                from: fee_payer.to_account_info(),
+                to: content_guard.clone(),
+            };
+            let cpi_context = CpiContext::new(system_program.clone(), cpi_accounts);
+            system_program::transfer(cpi_context, top_up)?;
+        }
+        let cpi_accounts = system_program::Allocate {
+            account_to_allocate: content_guard.clone(),
+        };
+        let cpi_context = CpiContext::new_with_signer(system_program.clone(), cpi_accounts, signer_seeds);
+        system_program::allocate(cpi_context, ContentGuard::LEN as u64)?;
+        let cpi_accounts = system_program::Assign {
+            account_to_assign: content_guard.clone(),
+        };
+        let cpi_context = CpiContext::new_with_signer(system_program, cpi_accounts, signer_seeds);
+        system_program::assign(cpi_context, &crate::ID)?;
+    }
+
+    let mut data = content_guard.try_borrow_mut_data()?;
+    guard.try_serialize(&mut &mut data[..])
+}
+
 #[derive(Accounts)]
+#[instruction(image_url: String, title: String, description: String, content_hash: [u8; 32])]
 pub struct SubmitMeme<'info> {
     #[account(mut)]
     pub fee_payer: Signer<'info>,
@@ -48,6 +157,9 @@ pub struct SubmitMeme<'info> {
     )]
     pub challenge: Account<'info, MemeChallenge>,
 
+    /// Required when the challenge belongs to a group
+    pub member_record: Option<Account<'info, GroupMember>>,
+
     #[account(
         init,
         payer = fee_payer,
@@ -57,5 +169,28 @@ pub struct SubmitMeme<'info> {
     )]
     pub submission: Account<'info, MemeSubmission>,
 
+    /// CHECK: `ContentGuard` PDA, created by the handler so a reused hash gets its own error
+    #[account(
+        mut,
+        seeds = [b"content", challenge.key().as_ref(), content_hash.as_ref()],
+        bump,
+    )]
+    pub content_guard: AccountInfo<'info>,
+
+    /// Pays the entry fee of a token pool
+    #[account(
+        mut,
+        constraint = submitter_token_account.owner == submitter.key() @ ErrorCode::NotOwner,
+    )]
+    pub submitter_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"challenge_vault", challenge.key().as_ref()],
+        bump,
+    )]
+    pub reward_vault: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
@@ -3,10 +3,10 @@ use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::error::ErrorCode;
 
-pub fn handler(ctx: Context<TipMessage>, message_id: u64, amount: u64) -> Result<()> {
+pub fn handler(ctx: Context<TipMessage>, _message_id: u64, amount: u64) -> Result<()> {
     require_gt!(amount, 0, ErrorCode::InvalidAmount);
     let message = &mut ctx.accounts.message;
-    message.tips_received += amount;
+    message.tips_received = message.tips_received.checked_add(amount).ok_or(ErrorCode::Overflow)?;
 
     let cpi_context = CpiContext::new(
         ctx.accounts.system_program.to_account_info(),
@@ -40,7 +40,10 @@ pub struct TipMessage<'info> {
     pub group: Account<'info, Group>,
 
     /// CHECK: This is the recipient of the tip (message sender)
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = recipient.key() == message.sender @ ErrorCode::InvalidRecipient,
+    )]
     pub recipient: AccountInfo<'info>,
 
     pub system_program: Program<'info, System>,
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ErrorCode;
+
+/// Offers the group to `new_owner`, who takes over once they accept.
+/// Passing None withdraws an open offer.
+pub fn handler(ctx: Context<TransferGroupOwnership>, new_owner: Option<Pubkey>) -> Result<()> {
+    ctx.accounts.group.pending_owner = new_owner;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct TransferGroupOwnership<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"group", group.name.as_bytes(), group.creator.as_ref()],
+        bump = group.bump,
+        has_one = owner @ ErrorCode::NotGroupOwner,
+    )]
+    pub group: Account<'info, Group>,
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ErrorCode;
+
+/// Lifts a ban, whether or not it has run out, so the wallet may join again.
+/// Only the moderator who banned the wallet, someone above them or the owner,
+/// whom nobody outranks, may do so.
+pub fn handler(ctx: Context<UnbanMember>) -> Result<()> {
+    let group = &ctx.accounts.group;
+    let authority_member = ctx.accounts.authority_member.as_ref();
+    let authority = ctx.accounts.authority.key();
+    require!(
+        group.has_role(authority_member, authority, GroupRole::Moderator),
+        ErrorCode::NotGroupAdmin
+    );
+    let ban_record = &ctx.accounts.ban_record;
+    let is_owner = group.role_of(authority_member, authority) == Some(GroupRole::Owner);
+    if authority != ban_record.banned_by && !is_owner {
+        group.require_outranks(authority_member, authority, ban_record.banned_by_role)?;
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UnbanMember<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"group", group.name.as_bytes(), group.creator.as_ref()],
+        bump = group.bump,
+    )]
+    pub group: Account<'info, Group>,
+
+    /// Required unless the authority owns the group
+    #[account(
+        seeds = [b"member", group.key().as_ref(), authority.key().as_ref()],
+        bump = authority_member.bump,
+    )]
+    pub authority_member: Option<Account<'info, GroupMember>>,
+
+    #[account(
+        mut,
+        seeds = [b"ban", group.key().as_ref(), ban_record.member.as_ref()],
+        bump = ban_record.bump,
+        close = rent_payer,
+    )]
+    pub ban_record: Account<'info, GroupBan>,
+
+    /// CHECK: Receives the ban record's rent
+    #[account(mut, address = ban_record.rent_payer @ ErrorCode::NotOwner)]
+    pub rent_payer: AccountInfo<'info>,
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::ErrorCode;
+
+/// Replaces everything about the group except the name in its address.
+/// Gating changes only apply to members who join afterwards.
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<UpdateGroup>,
+    display_name: String,
+    description: String,
+    is_channel: bool,
+    is_whale_group: bool,
+    required_token: Option<Pubkey>,
+    required_amount: u64,
+    required_nft_collection: Option<Pubkey>,
+    required_sol_balance: u64,
+    escrow_enabled: bool,
+) -> Result<()> {
+    require!(display_name.len() <= 32, ErrorCode::NameTooLong);
+    require!(description.len() <= 256, ErrorCode::DescriptionTooLong);
+
+    let group = &mut ctx.accounts.group;
+    require!(
+        group.has_role(ctx.accounts.authority_member.as_ref(), ctx.accounts.authority.key(), GroupRole::Admin),
+        ErrorCode::NotGroupAdmin
+    );
+    group.display_name = display_name;
+    group.description = description;
+    group.is_channel = is_channel;
+    group.is_whale_group = is_whale_group;
+    group.escrow_enabled = escrow_enabled;
+    group.required_token = required_token;
+    group.required_amount = required_amount;
+    group.required_nft_collection = required_nft_collection;
+    group.required_sol_balance = required_sol_balance;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateGroup<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"group", group.name.as_bytes(), group.creator.as_ref()],
+        bump = group.bump,
+    )]
+    pub group: Account<'info, Group>,
+
+    /// Required unless the authority owns the group
+    #[account(
+        seeds = [b"member", group.key().as_ref(), authority.key().as_ref()],
+        bump = authority_member.bump,
+    )]
+    pub authority_member: Option<Account<'info, GroupMember>>,
+}
@@ -3,20 +3,23 @@ use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::error::ErrorCode;
 
-pub fn handler(ctx: Context<UseInvite>, invite_code: String) -> Result<()> {
+pub fn handler(ctx: Context<UseInvite>, _invite_code: String) -> Result<()> {
     let invite = &mut ctx.accounts.invite;
     let group = &mut ctx.accounts.group;
     let member_record = &mut ctx.accounts.member_record;
     
     require!(invite.expires_at > Clock::get()?.unix_timestamp, ErrorCode::InviteExpired);
+    GroupBan::require_not_banned(&ctx.accounts.ban_record, Clock::get()?.unix_timestamp)?;
     require!(invite.uses < invite.max_uses, ErrorCode::InviteUsed);
     
     invite.uses += 1;
     member_record.group = group.key();
     member_record.member = ctx.accounts.member.key();
     member_record.joined_at = Clock::get()?.unix_timestamp;
+    member_record.role = GroupRole::Member;
+    member_record.rent_payer = ctx.accounts.fee_payer.key();
     member_record.bump = ctx.bumps.member_record;
-    group.member_count = group.member_count.checked_add(1).unwrap();
+    group.member_count = group.member_count.checked_add(1).ok_or(ErrorCode::Overflow)?;
     
     Ok(())
 }
@@ -32,7 +35,7 @@ pub struct UseInvite<'info> {
 
     #[account(
         mut,
-        seeds = [b"invite", group.key().as_ref(), invite_code.as_bytes()],
+        seeds = [b"invite", group.key().as_ref(), Invite::code_seed(&invite_code)?],
         bump = invite.bump,
     )]
     pub invite: Account<'info, Invite>,
@@ -49,5 +52,12 @@ pub struct UseInvite<'info> {
     )]
     pub member_record: Account<'info, GroupMember>,
 
+    /// CHECK: The member's ban address, which only matters once it holds a ban
+    #[account(
+        seeds = [b"ban", group.key().as_ref(), member.key().as_ref()],
+        bump,
+    )]
+    pub ban_record: AccountInfo<'info>,
+
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
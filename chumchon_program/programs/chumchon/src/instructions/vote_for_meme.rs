@@ -1,16 +1,44 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
 
+use super::vote_weight::cast_vote_weight;
 use crate::state::*;
 use crate::error::ErrorCode;
 
-pub fn handler(ctx: Context<VoteForMeme>) -> Result<()> {
-    let challenge = &mut ctx.accounts.challenge;
-    let submission = &mut ctx.accounts.submission;
+/// `votes` is how many votes to buy in a quadratic challenge, and 1 otherwise
+pub fn handler(ctx: Context<VoteForMeme>, votes: u32) -> Result<()> {
+    let challenge = &ctx.accounts.challenge;
+    let voter = ctx.accounts.voter.key();
     let clock = Clock::get()?;
-    require!(challenge.start_time < clock.unix_timestamp && clock.unix_timestamp < challenge.end_time, ErrorCode::ChallengeInactive);
-    require_neq!(submission.submitter, ctx.accounts.voter.key(), ErrorCode::CannotVoteOwnSubmission);
-    submission.votes += 1;
-    challenge.total_votes += 1;
+    challenge.require_active()?;
+    require!(challenge.submission_end <= clock.unix_timestamp && clock.unix_timestamp < challenge.voting_end, ErrorCode::VotingNotOpen);
+    require!(challenge.reveal_end.is_none(), ErrorCode::InvalidVotingMode);
+    challenge.require_member(ctx.accounts.member_record.as_ref(), voter)?;
+    require_neq!(ctx.accounts.submission.submitter, voter, ErrorCode::CannotVoteOwnSubmission);
+
+    let weight = cast_vote_weight(
+        &mut ctx.accounts.challenge,
+        &ctx.accounts.voter,
+        votes,
+        &ctx.accounts.voter_profile,
+        &ctx.accounts.voter_token_account,
+        &ctx.accounts.vote_vault,
+        &ctx.accounts.reward_vault,
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+    )?;
+    ctx.accounts.challenge.count_vote(&mut ctx.accounts.submission, weight)?;
+
+    let voter_record = &mut ctx.accounts.voter_record;
+    voter_record.challenge = ctx.accounts.challenge.key();
+    voter_record.submission = ctx.accounts.submission.key();
+    voter_record.voter = voter;
+    voter_record.commitment = None;
+    voter_record.revealed = true;
+    voter_record.weight = weight;
+    voter_record.voted_at = clock.unix_timestamp;
+    voter_record.rent_payer = ctx.accounts.fee_payer.key();
+    voter_record.bump = ctx.bumps.voter_record;
     Ok(())
 }
 
@@ -30,6 +58,7 @@ pub struct VoteForMeme<'info> {
     pub submission: Account<'info, MemeSubmission>,
 
     #[account(
+        mut,
         seeds = [b"challenge", challenge.creator.as_ref(), &challenge.start_time.to_le_bytes()],
         bump = challenge.bump,
     )]
@@ -38,14 +67,56 @@ pub struct VoteForMeme<'info> {
     /// CHECK: This is the submitter of the meme
     pub submitter: AccountInfo<'info>,
 
+    /// Required when the challenge belongs to a group
+    pub member_record: Option<Account<'info, GroupMember>>,
+
+    /// One per voter and challenge; move it with `change_meme_vote`
     #[account(
         init,
         payer = fee_payer,
         space = VoterRecord::LEN,
-        seeds = [b"voter", submission.key().as_ref(), voter.key().as_ref()],
+        seeds = [b"voter", challenge.key().as_ref(), voter.key().as_ref()],
         bump,
     )]
     pub voter_record: Account<'info, VoterRecord>,
 
+    /// Required in `OnePerProfile` challenges
+    #[account(
+        seeds = [b"user", voter.key().as_ref()],
+        bump = voter_profile.bump,
+    )]
+    pub voter_profile: Option<Account<'info, UserProfile>>,
+
+    /// Voting tokens of a `TokenWeighted` challenge, or what pays for quadratic votes on a token pool
+    #[account(
+        mut,
+        constraint = voter_token_account.owner == voter.key() @ ErrorCode::NotOwner,
+    )]
+    pub voter_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Mint of a `TokenWeighted` challenge's voting tokens
+    #[account(constraint = Some(voting_mint.key()) == challenge.voting_mint @ ErrorCode::InvalidToken)]
+    pub voting_mint: Option<Account<'info, Mint>>,
+
+    /// Holds a `TokenWeighted` vote's tokens until it is retracted or results are final
+    #[account(
+        init,
+        payer = fee_payer,
+        seeds = [b"vote_vault", challenge.key().as_ref(), voter.key().as_ref()],
+        bump,
+        token::mint = voting_mint,
+        token::authority = challenge,
+    )]
+    pub vote_vault: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"challenge_vault", challenge.key().as_ref()],
+        bump,
+    )]
+    pub reward_vault: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{Token, TokenAccount, Transfer};
+
+use super::prize_pool::{close_vault, pay_tokens};
+use crate::state::*;
+use crate::error::ErrorCode;
+
+/// Works out how much a vote counts under the challenge's voting mode. Quadratic
+/// votes are paid for here, growing the prize pool by their cost, and token-weighted
+/// votes lock the voter's tokens in `vote_vault` so they cannot be counted twice.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn cast_vote_weight<'info>(
+    challenge: &mut Account<'info, MemeChallenge>,
+    voter: &Signer<'info>,
+    votes: u32,
+    voter_profile: &Option<Account<'info, UserProfile>>,
+    voter_token_account: &Option<Account<'info, TokenAccount>>,
+    vote_vault: &Option<Account<'info, TokenAccount>>,
+    reward_vault: &Option<Account<'info, TokenAccount>>,
+    token_program: &Program<'info, Token>,
+    system_program: &Program<'info, System>,
+) -> Result<u64> {
+    if challenge.voting_mode != VotingMode::Quadratic {
+        require!(votes == 1, ErrorCode::InvalidAmount);
+    }
+
+    match challenge.voting_mode {
+        VotingMode::OnePerWallet => Ok(1),
+        VotingMode::OnePerProfile => {
+            require!(voter_profile.is_some(), ErrorCode::ProfileRequired);
+            Ok(1)
+        }
+        VotingMode::TokenWeighted => {
+            let token_account = voter_token_account.as_ref().ok_or(ErrorCode::MissingTokenAccount)?;
+            require!(Some(token_account.mint) == challenge.voting_mint, ErrorCode::InvalidToken);
+            let amount = token_account.amount;
+            require_gt!(amount, 0, ErrorCode::NoVotingPower);
+            let cpi_accounts = Transfer {
+                from: token_account.to_account_info(),
+                to: vote_vault.as_ref()
+                    .ok_or(ErrorCode::MissingTokenAccount)?.to_account_info(),
+                authority: voter.to_account_info(),
+            };
+            let cpi_context = CpiContext::new(token_program.to_account_info(), cpi_accounts);
+            anchor_spl::token::transfer(cpi_context, amount)?;
+            challenge.open_vote_vaults = challenge.open_vote_vaults.checked_add(1).ok_or(ErrorCode::Overflow)?;
+            Ok(amount)
+        }
+        VotingMode::Quadratic => {
+            require_gt!(votes, 0, ErrorCode::InvalidAmount);
+            let cost = (votes as u64)
+                .checked_mul(votes as u64)
+                .and_then(|squared| squared.checked_mul(challenge.vote_price))
+                .ok_or(ErrorCode::Overflow)?;
+
+            if challenge.reward_mint.is_some() {
+                let token_account = voter_token_account.as_ref().ok_or(ErrorCode::MissingTokenAccount)?;
+                require!(Some(token_account.mint) == challenge.reward_mint, ErrorCode::InvalidToken);
+                let cpi_accounts = Transfer {
+                    from: token_account.to_account_info(),
+                    to: reward_vault.as_ref()
+                        .ok_or(ErrorCode::MissingTokenAccount)?.to_account_info(),
+                    authority: voter.to_account_info(),
+                };
+                let cpi_context = CpiContext::new(token_program.to_account_info(), cpi_accounts);
+                anchor_spl::token::transfer(cpi_context, cost)?;
+            } else {
+                let cpi_accounts = system_program::Transfer {
+                    from: voter.to_account_info(),
+                    to: challenge.to_account_info(),
+                };
+                let cpi_context = CpiContext::new(system_program.to_account_info(), cpi_accounts);
+                system_program::transfer(cpi_context, cost)?;
+            }
+
+            challenge.reward_amount = challenge.reward_amount.checked_add(cost).ok_or(ErrorCode::Overflow)?;
+            Ok(votes as u64)
+        }
+    }
+}
+
+/// Hands a token-weighted vote's locked tokens back to the voter and closes the
+/// vote vault, whose rent goes back to `rent_payer`
+pub(crate) fn release_vote_tokens<'info>(
+    challenge: &mut Account<'info, MemeChallenge>,
+    vote_vault: &Option<Account<'info, TokenAccount>>,
+    voter_token_account: &Option<Account<'info, TokenAccount>>,
+    rent_payer: &AccountInfo<'info>,
+    token_program: &Program<'info, Token>,
+) -> Result<()> {
+    let vote_vault = vote_vault.as_ref().ok_or(ErrorCode::MissingTokenAccount)?;
+    let token_account = voter_token_account.as_ref().ok_or(ErrorCode::MissingTokenAccount)?;
+    pay_tokens(
+        challenge,
+        vote_vault.to_account_info(),
+        token_account.to_account_info(),
+        token_program.to_account_info(),
+        vote_vault.amount,
+    )?;
+    close_vault(
+        challenge,
+        vote_vault.to_account_info(),
+        rent_payer.clone(),
+        token_program.to_account_info(),
+    )?;
+    challenge.open_vote_vaults = challenge.open_vote_vaults.checked_sub(1).ok_or(ErrorCode::Overflow)?;
+    Ok(())
+}
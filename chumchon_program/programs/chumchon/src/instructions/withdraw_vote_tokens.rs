@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use super::vote_weight::release_vote_tokens;
+use crate::state::*;
+use crate::error::ErrorCode;
+
+/// Hands a `TokenWeighted` voter's locked tokens back once results are final and
+/// closes their vote's record
+pub fn handler(ctx: Context<WithdrawVoteTokens>) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(clock.unix_timestamp >= ctx.accounts.challenge.results_at(), ErrorCode::ChallengeNotEnded);
+
+    release_vote_tokens(
+        &mut ctx.accounts.challenge,
+        &ctx.accounts.vote_vault,
+        &ctx.accounts.voter_token_account,
+        &ctx.accounts.rent_payer,
+        &ctx.accounts.token_program,
+    )
+}
+
+#[derive(Accounts)]
+pub struct WithdrawVoteTokens<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"challenge", challenge.creator.as_ref(), &challenge.start_time.to_le_bytes()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Account<'info, MemeChallenge>,
+
+    #[account(
+        mut,
+        seeds = [b"voter", challenge.key().as_ref(), voter.key().as_ref()],
+        bump = voter_record.bump,
+        close = rent_payer,
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+
+    /// CHECK: Receives the rent of the vote vault and the voter record
+    #[account(mut, address = voter_record.rent_payer @ ErrorCode::NotOwner)]
+    pub rent_payer: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"vote_vault", challenge.key().as_ref(), voter.key().as_ref()],
+        bump,
+    )]
+    pub vote_vault: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = voter_token_account.owner == voter.key() @ ErrorCode::NotOwner,
+    )]
+    pub voter_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -3,8 +3,10 @@
 use anchor_lang::prelude::*;
 
 pub mod error;
+pub mod instructions;
 pub mod state;
 
+pub use instructions::*;
 pub use state::*;
 
 declare_id!("CVjwSHMQ9YTenzKwQczwXWzJFk5kwaUhKDtxDKVazJXj");
@@ -12,7 +14,6 @@ declare_id!("CVjwSHMQ9YTenzKwQczwXWzJFk5kwaUhKDtxDKVazJXj");
 #[program]
 pub mod chumchon {
     use super::*;
-    use crate::error::ErrorCode;
 
     pub fn create_user_profile(
         ctx: Context<CreateUserProfile>,
@@ -20,22 +21,7 @@ pub mod chumchon {
         bio: String,
         show_balance: bool,
     ) -> Result<()> {
-        require!(username.len() <= 50, ErrorCode::NameTooLong);
-        require!(bio.len() <= 200, ErrorCode::BioTooLong);
-        let profile = &mut ctx.accounts.profile;
-        let clock = Clock::get()?.unix_timestamp;
-        profile.owner = ctx.accounts.owner.key();
-        profile.username = username;
-        profile.bio = bio;
-        profile.profile_picture_url = None;
-        profile.nft_profile_picture = None;
-        profile.show_balance = show_balance;
-        profile.created_at = clock;
-        profile.last_active = clock;
-        profile.completed_tutorials = Vec::new();
-        profile.tutorial_rewards = 0;
-        profile.bump = ctx.bumps.profile;
-        Ok(())
+        create_user_profile::handler(ctx, username, bio, show_balance)
     }
 
     pub fn update_user_profile(
@@ -44,53 +30,296 @@ pub mod chumchon {
         bio: String,
         show_balance: bool,
     ) -> Result<()> {
-        require!(username.len() <= 50, ErrorCode::NameTooLong);
-        require!(bio.len() <= 200, ErrorCode::BioTooLong);
-        let profile = &mut ctx.accounts.profile;
-        let clock = Clock::get()?.unix_timestamp;
-        profile.username = username;
-        profile.bio = bio;
-        profile.show_balance = show_balance;
-        profile.last_active = clock;
-        Ok(())
+        update_user_profile::handler(ctx, username, bio, show_balance)
     }
-}
 
-#[derive(Accounts)]
-pub struct CreateUserProfile<'info> {
-    #[account(mut)]
-    pub fee_payer: Signer<'info>,
+    pub fn set_profile_nft(ctx: Context<SetProfileNft>, nft_mint: Pubkey) -> Result<()> {
+        set_profile_nft::handler(ctx, nft_mint)
+    }
 
-    #[account(
-        init,
-        payer = fee_payer,
-        space = UserProfile::LEN,
-        seeds = [b"user", owner.key().as_ref()],
-        bump,
-    )]
-    pub profile: Account<'info, UserProfile>,
+    pub fn complete_tutorial(ctx: Context<CompleteTutorial>, tutorial_id: u8) -> Result<()> {
+        complete_tutorial::handler(ctx, tutorial_id)
+    }
 
-    #[account(mut)]
-    pub owner: Signer<'info>,
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_group(
+        ctx: Context<CreateGroup>,
+        name: String,
+        description: String,
+        is_channel: bool,
+        is_whale_group: bool,
+        required_token: Option<Pubkey>,
+        required_amount: u64,
+        required_nft_collection: Option<Pubkey>,
+        required_sol_balance: u64,
+        escrow_enabled: bool,
+    ) -> Result<()> {
+        create_group::handler(
+            ctx,
+            name,
+            description,
+            is_channel,
+            is_whale_group,
+            required_token,
+            required_amount,
+            required_nft_collection,
+            required_sol_balance,
+            escrow_enabled,
+        )
+    }
 
-    pub system_program: Program<'info, System>,
-}
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_group(
+        ctx: Context<UpdateGroup>,
+        display_name: String,
+        description: String,
+        is_channel: bool,
+        is_whale_group: bool,
+        required_token: Option<Pubkey>,
+        required_amount: u64,
+        required_nft_collection: Option<Pubkey>,
+        required_sol_balance: u64,
+        escrow_enabled: bool,
+    ) -> Result<()> {
+        update_group::handler(
+            ctx,
+            display_name,
+            description,
+            is_channel,
+            is_whale_group,
+            required_token,
+            required_amount,
+            required_nft_collection,
+            required_sol_balance,
+            escrow_enabled,
+        )
+    }
 
-#[derive(Accounts)]
-pub struct UpdateUserProfile<'info> {
-    #[account(mut)]
-    pub fee_payer: Signer<'info>,
+    pub fn transfer_group_ownership(
+        ctx: Context<TransferGroupOwnership>,
+        new_owner: Option<Pubkey>,
+    ) -> Result<()> {
+        transfer_group_ownership::handler(ctx, new_owner)
+    }
 
-    #[account(
-        mut,
-        seeds = [b"user", owner.key().as_ref()],
-        bump = profile.bump,
-        constraint = profile.owner == owner.key() @ crate::error::ErrorCode::NotOwner,
-    )]
-    pub profile: Account<'info, UserProfile>,
+    pub fn accept_group_ownership(ctx: Context<AcceptGroupOwnership>) -> Result<()> {
+        accept_group_ownership::handler(ctx)
+    }
 
-    #[account(mut)]
-    pub owner: Signer<'info>,
+    pub fn join_group(ctx: Context<JoinGroup>) -> Result<()> {
+        join_group::handler(ctx)
+    }
+
+    pub fn leave_group(ctx: Context<LeaveGroup>) -> Result<()> {
+        leave_group::handler(ctx)
+    }
+
+    pub fn create_invite(
+        ctx: Context<CreateInvite>,
+        code: String,
+        max_uses: u32,
+        expires_at: i64,
+    ) -> Result<()> {
+        create_invite::handler(ctx, code, max_uses, expires_at)
+    }
+
+    pub fn use_invite(ctx: Context<UseInvite>, invite_code: String) -> Result<()> {
+        use_invite::handler(ctx, invite_code)
+    }
+
+    pub fn add_group_admin(ctx: Context<AddGroupAdmin>, role: GroupRole) -> Result<()> {
+        add_group_admin::handler(ctx, role)
+    }
+
+    pub fn remove_group_admin(ctx: Context<RemoveGroupAdmin>) -> Result<()> {
+        remove_group_admin::handler(ctx)
+    }
 
-    pub system_program: Program<'info, System>,
-}
\ No newline at end of file
+    pub fn kick_member(ctx: Context<KickMember>) -> Result<()> {
+        kick_member::handler(ctx)
+    }
+
+    pub fn ban_member(
+        ctx: Context<BanMember>,
+        member: Pubkey,
+        reason: u8,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        ban_member::handler(ctx, member, reason, expires_at)
+    }
+
+    pub fn unban_member(ctx: Context<UnbanMember>) -> Result<()> {
+        unban_member::handler(ctx)
+    }
+
+    pub fn send_message(ctx: Context<SendMessage>, content: String) -> Result<()> {
+        send_message::handler(ctx, content)
+    }
+
+    pub fn tip_message(ctx: Context<TipMessage>, message_id: u64, amount: u64) -> Result<()> {
+        tip_message::handler(ctx, message_id, amount)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_escrow(
+        ctx: Context<CreateEscrow>,
+        counterparty: Pubkey,
+        initiator_amount: u64,
+        counterparty_token: Option<Pubkey>,
+        counterparty_amount: u64,
+        expires_at: i64,
+        created_at: i64,
+    ) -> Result<()> {
+        create_escrow::handler(
+            ctx,
+            counterparty,
+            initiator_amount,
+            counterparty_token,
+            counterparty_amount,
+            expires_at,
+            created_at,
+        )
+    }
+
+    pub fn accept_escrow(ctx: Context<AcceptEscrow>, created_at: i64) -> Result<()> {
+        accept_escrow::handler(ctx, created_at)
+    }
+
+    pub fn complete_escrow(ctx: Context<CompleteEscrow>, created_at: i64) -> Result<()> {
+        complete_escrow::handler(ctx, created_at)
+    }
+
+    pub fn cancel_escrow(ctx: Context<CancelEscrow>, created_at: i64) -> Result<()> {
+        cancel_escrow::handler(ctx, created_at)
+    }
+
+    pub fn reclaim_expired_escrow(
+        ctx: Context<ReclaimExpiredEscrow>,
+        created_at: i64,
+    ) -> Result<()> {
+        reclaim_expired_escrow::handler(ctx, created_at)
+    }
+
+    pub fn dispute_escrow(ctx: Context<DisputeEscrow>, created_at: i64) -> Result<()> {
+        dispute_escrow::handler(ctx, created_at)
+    }
+
+    pub fn resolve_escrow_dispute(
+        ctx: Context<ResolveEscrowDispute>,
+        created_at: i64,
+        settle: bool,
+    ) -> Result<()> {
+        resolve_escrow_dispute::handler(ctx, created_at, settle)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_meme_challenge(
+        ctx: Context<CreateMemeChallenge>,
+        title: String,
+        description: String,
+        prompt: String,
+        reward_amount: u64,
+        start_time: i64,
+        submission_end: i64,
+        voting_end: i64,
+        reveal_end: Option<i64>,
+        prize_split_bps: Vec<u16>,
+        voting_mode: VotingMode,
+        voting_mint: Option<Pubkey>,
+        vote_price: u64,
+        entry_fee: u64,
+    ) -> Result<()> {
+        create_meme_challenge::handler(
+            ctx,
+            title,
+            description,
+            prompt,
+            reward_amount,
+            start_time,
+            submission_end,
+            voting_end,
+            reveal_end,
+            prize_split_bps,
+            voting_mode,
+            voting_mint,
+            vote_price,
+            entry_fee,
+        )
+    }
+
+    pub fn submit_meme(
+        ctx: Context<SubmitMeme>,
+        image_url: String,
+        title: String,
+        description: String,
+        content_hash: [u8; 32],
+    ) -> Result<()> {
+        submit_meme::handler(ctx, image_url, title, description, content_hash)
+    }
+
+    pub fn vote_for_meme(ctx: Context<VoteForMeme>, votes: u32) -> Result<()> {
+        vote_for_meme::handler(ctx, votes)
+    }
+
+    pub fn commit_meme_vote(
+        ctx: Context<CommitMemeVote>,
+        commitment: [u8; 32],
+        votes: u32,
+    ) -> Result<()> {
+        commit_meme_vote::handler(ctx, commitment, votes)
+    }
+
+    pub fn reveal_meme_vote(ctx: Context<RevealMemeVote>, salt: [u8; 32]) -> Result<()> {
+        reveal_meme_vote::handler(ctx, salt)
+    }
+
+    pub fn change_meme_vote(ctx: Context<ChangeMemeVote>) -> Result<()> {
+        change_meme_vote::handler(ctx)
+    }
+
+    pub fn withdraw_vote_tokens(ctx: Context<WithdrawVoteTokens>) -> Result<()> {
+        withdraw_vote_tokens::handler(ctx)
+    }
+
+    pub fn rank_meme_submission(ctx: Context<RankMemeSubmission>) -> Result<()> {
+        rank_meme_submission::handler(ctx)
+    }
+
+    pub fn contribute_to_challenge(ctx: Context<ContributeToChallenge>, amount: u64) -> Result<()> {
+        contribute_to_challenge::handler(ctx, amount)
+    }
+
+    pub fn refund_contribution(ctx: Context<RefundContribution>) -> Result<()> {
+        refund_contribution::handler(ctx)
+    }
+
+    pub fn refund_entry_fee(ctx: Context<RefundEntryFee>) -> Result<()> {
+        refund_entry_fee::handler(ctx)
+    }
+
+    pub fn close_meme_submission(ctx: Context<CloseMemeSubmission>) -> Result<()> {
+        close_meme_submission::handler(ctx)
+    }
+
+    pub fn close_challenge_contribution(ctx: Context<CloseChallengeContribution>) -> Result<()> {
+        close_challenge_contribution::handler(ctx)
+    }
+
+    pub fn close_voter_record(ctx: Context<CloseVoterRecord>) -> Result<()> {
+        close_voter_record::handler(ctx)
+    }
+
+    pub fn cancel_meme_challenge(ctx: Context<CancelMemeChallenge>) -> Result<()> {
+        cancel_meme_challenge::handler(ctx)
+    }
+
+    pub fn close_meme_challenge(ctx: Context<CloseMemeChallenge>) -> Result<()> {
+        close_meme_challenge::handler(ctx)
+    }
+
+    pub fn end_meme_challenge<'info>(
+        ctx: Context<'_, '_, 'info, 'info, EndMemeChallenge<'info>>,
+    ) -> Result<()> {
+        end_meme_challenge::handler(ctx)
+    }
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+/// One top-up of a challenge's prize pool by someone other than its creator
+#[account]
+pub struct ChallengeContribution {
+    pub challenge: Pubkey,
+    pub contributor: Pubkey,
+    /// Position among the challenge's contributions, which seeds this account
+    pub contribution_id: u32,
+    /// Paid in the challenge's reward currency
+    pub amount: u64,
+    pub contributed_at: i64,
+    /// Gets the record's rent back when the contribution is refunded
+    pub rent_payer: Pubkey,
+    pub bump: u8,
+}
+
+impl ChallengeContribution {
+    pub const LEN: usize = 8 + // discriminator
+        32 * 3 + // Pubkeys
+        4 + // contribution_id u32
+        8 + // amount u64
+        8 + // contributed_at i64
+        1; // bump
+}
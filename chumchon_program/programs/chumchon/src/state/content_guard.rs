@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+/// Claims an image hash within a challenge for the submission that entered it
+/// first, and closes along with that submission
+#[account]
+pub struct ContentGuard {
+    pub challenge: Pubkey,
+    pub content_hash: [u8; 32],
+    pub submission: Pubkey,
+    pub bump: u8,
+}
+
+impl ContentGuard {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // challenge Pubkey
+        32 + // content_hash [u8; 32]
+        32 + // submission Pubkey
+        1; // bump
+}
@@ -1,30 +1,106 @@
 use anchor_lang::prelude::*;
 
+use crate::error::ErrorCode;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EscrowStatus {
+    Open,
+    Accepted,
+    Settled,
+    Cancelled,
+    Expired,
+    Disputed,
+}
+
+impl EscrowStatus {
+    /// Every escrow instruction goes through this table before changing the status
+    pub fn can_transition_to(self, next: EscrowStatus) -> bool {
+        use EscrowStatus::*;
+        matches!(
+            (self, next),
+            (Open, Accepted)
+                | (Open, Cancelled)
+                | (Open, Expired)
+                | (Accepted, Settled)
+                | (Accepted, Expired)
+                | (Accepted, Disputed)
+                | (Disputed, Settled)
+                | (Disputed, Cancelled)
+                | (Disputed, Expired)
+        )
+    }
+}
+
+/// Logged whenever an escrow is opened or changes status. `initiator` and
+/// `created_at` seed the escrow's address.
+#[event]
+pub struct EscrowStatusChanged {
+    pub initiator: Pubkey,
+    pub created_at: i64,
+    pub counterparty: Pubkey,
+    pub status: EscrowStatus,
+    pub timestamp: i64,
+}
+
+/// How far an escrow's `created_at`, which seeds its address, may be from the clock
+pub const MAX_CREATED_AT_DRIFT: u64 = 60;
+
 #[account]
 pub struct Escrow {
     pub initiator: Pubkey,
     pub counterparty: Pubkey,
     pub group: Pubkey,
-    pub initiator_token: Pubkey,
+    /// Mint of the initiator's leg, or None for native SOL held in the escrow itself
+    pub initiator_token: Option<Pubkey>,
     pub initiator_amount: u64,
-    pub counterparty_token: Pubkey,
+    /// Mint of the counterparty's leg, or None for native SOL held in the escrow itself
+    pub counterparty_token: Option<Pubkey>,
     pub counterparty_amount: u64,
-    pub status: u8,
+    pub status: EscrowStatus,
     pub created_at: i64,
     pub expires_at: i64,
-    pub accepted: bool,
     pub accepted_at: Option<i64>,
-    pub completed: bool,
-    pub completed_at: Option<i64>,
+    pub disputed_at: Option<i64>,
+    /// When the escrow was settled, cancelled or expired
+    pub resolved_at: Option<i64>,
+    pub initiator_rent_payer: Pubkey,
+    pub counterparty_rent_payer: Pubkey,
     pub bump: u8,
 }
 
 impl Escrow {
     pub const LEN: usize = 8 + // discriminator
-        32 * 4 + // Pubkeys
+        32 * 5 + // Pubkeys
+        (1 + 32) * 2 + // Options<Pubkey>
         8 * 4 + // u64/i64
-        1 * 2 + // bools
-        (1 + 8) * 2 + // Options<i64>
-        1 + // status u8
+        (1 + 8) * 3 + // Options<i64>
+        1 + // status enum
         1; // bump u8
-}
\ No newline at end of file
+
+    pub fn transition(&mut self, next: EscrowStatus, now: i64) -> Result<()> {
+        require!(self.status.can_transition_to(next), ErrorCode::InvalidEscrowTransition);
+        match next {
+            EscrowStatus::Open => {}
+            EscrowStatus::Accepted => self.accepted_at = Some(now),
+            EscrowStatus::Disputed => self.disputed_at = Some(now),
+            EscrowStatus::Settled | EscrowStatus::Cancelled | EscrowStatus::Expired => {
+                self.resolved_at = Some(now)
+            }
+        }
+        self.status = next;
+        self.log_status(now);
+        Ok(())
+    }
+
+    /// Settled, cancelled and expired escrows are closed straight away, so
+    /// clients keep their history from these events rather than the account
+    pub fn log_status(&self, now: i64) {
+        emit!(EscrowStatusChanged {
+            initiator: self.initiator,
+            created_at: self.created_at,
+            counterparty: self.counterparty,
+            status: self.status,
+            timestamp: now,
+        });
+    }
+}
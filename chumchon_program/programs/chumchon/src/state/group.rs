@@ -1,12 +1,25 @@
 use anchor_lang::prelude::*;
 
+use crate::error::ErrorCode;
+use crate::state::{GroupMember, GroupRole};
+
 #[account]
 pub struct Group {
+    /// Seeds the group's address, so it never changes
     pub name: String,
+    /// The name shown to users, which starts out as `name`
+    pub display_name: String,
     pub description: String,
     pub creator: Pubkey,
+    /// Controls the group. Starts out as the creator, who stays in the
+    /// address seeds after handing the group over.
+    pub owner: Pubkey,
+    /// Offered ownership and yet to accept it
+    pub pending_owner: Option<Pubkey>,
     pub is_channel: bool,
     pub is_whale_group: bool,
+    /// Lets a non-whale group host escrow trades
+    pub escrow_enabled: bool,
     pub required_token: Option<Pubkey>,
     pub required_amount: u64,
     pub required_nft_collection: Option<Pubkey>,
@@ -21,11 +34,56 @@ pub struct Group {
 impl Group {
     pub const LEN: usize = 8 + // discriminator
         (4 + 32) + // name String
+        (4 + 32) + // display_name String
         (4 + 256) + // description String
-        32 + // creator Pubkey
-        1 * 2 + // bools
-        (1 + 32) * 2 + // Options<Pubkey>
-        8 * 4 + // u64/i64
+        32 * 2 + // creator/owner Pubkeys
+        3 + // bools
+        (1 + 32) * 3 + // Options<Pubkey>
+        8 * 5 + // u64/i64
         4 + // u32
         1; // bump
+
+    /// Role `member` holds here, if any. The owner holds `Owner` whether or
+    /// not they have joined; anyone else needs their membership record, which
+    /// callers seed under this group.
+    pub fn role_of(&self, member_record: Option<&Account<GroupMember>>, member: Pubkey) -> Option<GroupRole> {
+        if member == self.owner {
+            return Some(GroupRole::Owner);
+        }
+        member_record
+            .filter(|record| record.member == member)
+            .map(|record| self.member_role(record))
+    }
+
+    /// Role held by the member behind `record`, which only stores roles below `Owner`
+    pub fn member_role(&self, record: &GroupMember) -> GroupRole {
+        if record.member == self.owner {
+            GroupRole::Owner
+        } else {
+            record.role
+        }
+    }
+
+    /// Whether `member` holds `role` or one above it
+    pub fn has_role(&self, member_record: Option<&Account<GroupMember>>, member: Pubkey, role: GroupRole) -> bool {
+        self.role_of(member_record, member).is_some_and(|held| held >= role)
+    }
+
+    /// Only a higher role may grant or take away a role
+    pub fn require_outranks(
+        &self,
+        authority_member: Option<&Account<GroupMember>>,
+        authority: Pubkey,
+        role: GroupRole,
+    ) -> Result<()> {
+        let held = self.role_of(authority_member, authority).ok_or(ErrorCode::NotGroupAdmin)?;
+        require!(held > role, ErrorCode::NotGroupAdmin);
+        Ok(())
+    }
+
+    /// Counts out a member whose record is being closed
+    pub fn remove_member(&mut self) -> Result<()> {
+        self.member_count = self.member_count.checked_sub(1).ok_or(ErrorCode::Overflow)?;
+        Ok(())
+    }
 }
\ No newline at end of file
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::GroupRole;
+
+/// Keeps a wallet out of a group until the ban is lifted or runs out
+#[account]
+pub struct GroupBan {
+    pub group: Pubkey,
+    pub member: Pubkey,
+    pub banned_by: Pubkey,
+    /// Role `banned_by` held when banning; only they or someone above may lift the ban
+    pub banned_by_role: GroupRole,
+    /// Why the member was banned, as defined by clients
+    pub reason: u8,
+    pub banned_at: i64,
+    /// None for a permanent ban
+    pub expires_at: Option<i64>,
+    /// Gets the record's rent back when the ban is lifted
+    pub rent_payer: Pubkey,
+    pub bump: u8,
+}
+
+impl GroupBan {
+    pub const LEN: usize = 8 + // discriminator
+        32 * 4 + // Pubkeys
+        1 + // role enum
+        1 + // reason u8
+        8 + // banned_at i64
+        (1 + 8) + // Option<i64>
+        1; // bump
+
+    pub fn is_active(&self, now: i64) -> bool {
+        !matches!(self.expires_at, Some(expires_at) if expires_at <= now)
+    }
+
+    /// Fails when `ban_record`, the wallet's ban address in a group, holds a
+    /// ban still in force. Addresses the program does not own hold no ban.
+    pub fn require_not_banned(ban_record: &AccountInfo, now: i64) -> Result<()> {
+        if *ban_record.owner != crate::ID {
+            return Ok(());
+        }
+        let mut data: &[u8] = &ban_record.try_borrow_data()?;
+        let ban = GroupBan::try_deserialize(&mut data)?;
+        require!(!ban.is_active(now), ErrorCode::BannedFromGroup);
+        Ok(())
+    }
+}
@@ -1,16 +1,54 @@
 use anchor_lang::prelude::*;
 
+/// What a member may do in a group; each role can do everything the ones below it can
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum GroupRole {
+    Member,
+    /// Moderates the group's members and content
+    Moderator,
+    /// Also issues invites, posts in channels and appoints moderators
+    Admin,
+    /// The group's owner, who alone appoints admins. Held through the
+    /// group's `owner` rather than a membership record.
+    Owner,
+}
+
 #[account]
 pub struct GroupMember {
     pub group: Pubkey,
     pub member: Pubkey,
     pub joined_at: i64,
+    pub role: GroupRole,
+    /// Gets the record's rent back when the member leaves
+    pub rent_payer: Pubkey,
     pub bump: u8,
 }
 
 impl GroupMember {
     pub const LEN: usize = 8 + // discriminator
-        32 * 2 + // Pubkeys
+        32 * 3 + // Pubkeys
         8 + // i64
+        1 + // role enum
         1; // bump
+
+    /// Reads the membership held at `member_record`, the wallet's membership
+    /// address in a group, or None once the member has left
+    pub fn load(member_record: &AccountInfo) -> Result<Option<GroupMember>> {
+        if member_record.data_is_empty() {
+            return Ok(None);
+        }
+        require_keys_eq!(*member_record.owner, crate::ID, anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram);
+        let mut data: &[u8] = &member_record.try_borrow_data()?;
+        GroupMember::try_deserialize(&mut data).map(Some)
+    }
+
+    /// Closes the membership held at `member_record`, sending its rent to `destination`
+    pub fn close<'info>(member_record: &AccountInfo<'info>, destination: &AccountInfo<'info>) -> Result<()> {
+        let lamports = member_record.lamports();
+        member_record.sub_lamports(lamports)?;
+        destination.add_lamports(lamports)?;
+        member_record.assign(&System::id());
+        member_record.realloc(0, false)?;
+        Ok(())
+    }
 }
\ No newline at end of file
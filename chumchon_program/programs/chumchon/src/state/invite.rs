@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::error::ErrorCode;
+
 #[account]
 pub struct Invite {
     pub group: Pubkey,
@@ -18,4 +20,12 @@ impl Invite {
         4 * 2 + // u32
         8 + // i64
         1; // bump
+
+    /// The code as the seed of the invite's address. It is checked while the
+    /// address is derived, since a seed over 32 bytes cannot derive one at all.
+    pub fn code_seed(code: &str) -> Result<&[u8]> {
+        require!(!code.is_empty(), ErrorCode::InvalidInvite);
+        require!(code.len() <= 32, ErrorCode::CodeTooLong);
+        Ok(code.as_bytes())
+    }
 }
\ No newline at end of file
@@ -1,30 +1,241 @@
 use anchor_lang::prelude::*;
+use std::cmp::Ordering;
+
+use crate::error::ErrorCode;
+use crate::state::{GroupMember, MemeSubmission};
+
+/// Most places a challenge can pay out
+pub const MAX_PRIZE_PLACES: usize = 3;
+
+/// Basis points that make up the whole prize pool
+pub const PRIZE_SPLIT_TOTAL_BPS: u16 = 10_000;
+
+/// How much each vote in a challenge counts
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VotingMode {
+    /// Every wallet's vote counts once
+    OnePerWallet,
+    /// Only voters with a `UserProfile` may vote, once each
+    OnePerProfile,
+    /// A vote counts the voter's balance of `voting_mint`, which stays locked
+    /// until results are final
+    TokenWeighted,
+    /// Voters buy votes at `vote_price` times the square of the count, paid into the pool
+    Quadratic,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LeaderboardEntry {
+    pub submission: Pubkey,
+    pub submitter: Pubkey,
+    pub votes: u64,
+    pub submitted_at: i64,
+}
+
+impl LeaderboardEntry {
+    pub const LEN: usize = 32 * 2 + // Pubkeys
+        8 + // votes u64
+        8; // submitted_at i64
+
+    /// More votes rank higher, then the earlier submission, then the lower
+    /// submission address, so every way of building the board agrees on ties
+    pub fn rank(&self, other: &LeaderboardEntry) -> Ordering {
+        other.votes.cmp(&self.votes)
+            .then(self.submitted_at.cmp(&other.submitted_at))
+            .then(self.submission.cmp(&other.submission))
+    }
+
+    pub fn outranks(&self, other: &LeaderboardEntry) -> bool {
+        self.rank(other) == Ordering::Less
+    }
+}
 
 #[account]
 pub struct MemeChallenge {
     pub creator: Pubkey,
+    /// Group whose members alone may submit and vote, or None for an open challenge
+    pub group: Option<Pubkey>,
     pub title: String,      // Assume max 100 chars
     pub description: String, // Assume max 500 chars
     pub prompt: String,     // Assume max 300 chars
     pub reward_amount: u64,
+    /// Mint of the prize pool, or None for native SOL held in the challenge itself
+    pub reward_mint: Option<Pubkey>,
+    /// Share of the pool for each place in basis points, first place first
+    pub prize_split_bps: Vec<u16>,
+    /// Submissions are taken from `start_time` until `submission_end`, then votes until `voting_end`
     pub start_time: i64,
-    pub end_time: i64,
+    pub submission_end: i64,
+    pub voting_end: i64,
+    /// Set for commit–reveal challenges, whose votes are revealed between `voting_end` and this
+    pub reveal_end: Option<i64>,
+    pub voting_mode: VotingMode,
+    /// Mint whose balance weighs votes in `TokenWeighted` challenges
+    pub voting_mint: Option<Pubkey>,
+    /// Price of a single vote in `Quadratic` challenges, in the pool's currency
+    pub vote_price: u64,
+    /// Charged for each submission in the pool's currency and added to the pool; 0 for free entry
+    pub entry_fee: u64,
     pub submission_count: u32,
-    pub total_votes: u32,
-    pub winner: Option<Pubkey>,
+    /// Number of `ChallengeContribution`s made, which also seeds the next one
+    pub contribution_count: u32,
+    /// Part of `reward_amount` owed back to contributors if the challenge is cancelled
+    pub contributed_amount: u64,
+    pub total_votes: u64,
+    /// Token-weighted votes whose tokens are still locked in a vote vault
+    pub open_vote_vaults: u32,
+    /// Best submissions so far, one per paid place, best first
+    pub leaderboard: Vec<LeaderboardEntry>,
+    /// Set once an entry drops off the leaderboard, after which the challenge
+    /// can only end once every submission has been ranked again
+    pub leaderboard_stale: bool,
+    /// Submissions ranked again since results came in, which clears
+    /// `leaderboard_stale` once it reaches `submission_count`
+    pub ranked_count: u32,
+    /// Paid submitters in place order once the challenge is completed
+    pub winners: Vec<Pubkey>,
     pub completed: bool,
+    /// Set when the creator calls the challenge off, after which everyone
+    /// claims back what they paid in
+    pub cancelled: bool,
+    /// Gets the rent of the challenge and its vault back when they close
+    pub rent_payer: Pubkey,
     pub bump: u8,
 }
 
 impl MemeChallenge {
     pub const LEN: usize = 8 + // discriminator
         32 + // creator Pubkey
+        (1 + 32) + // group Option<Pubkey>
         (4 + 100) + // title String
         (4 + 500) + // description String
         (4 + 300) + // prompt String
-        8 * 3 + // u64 and i64 fields
-        4 * 2 + // u32 fields
-        (1 + 32) + // winner Option<Pubkey>
+        8 * 8 + // u64 and i64 fields
+        (1 + 8) + // reveal_end Option<i64>
+        (1 + 32) * 2 + // reward_mint, voting_mint Option<Pubkey>
+        (4 + 2 * MAX_PRIZE_PLACES) + // prize_split_bps Vec<u16>
+        1 + // voting_mode enum
+        4 * 4 + // submission_count, contribution_count, open_vote_vaults, ranked_count u32
+        (4 + LeaderboardEntry::LEN * MAX_PRIZE_PLACES) + // leaderboard Vec
+        1 + // leaderboard_stale bool
+        (4 + 32 * MAX_PRIZE_PLACES) + // winners Vec<Pubkey>
         1 + // completed bool
+        1 + // cancelled bool
+        32 + // rent_payer Pubkey
         1; // bump
-}
\ No newline at end of file
+
+    /// Results are final once the last vote that can count is in
+    pub fn results_at(&self) -> i64 {
+        self.reveal_end.unwrap_or(self.voting_end)
+    }
+
+    /// Group-scoped challenges only accept signers with a membership record in that group
+    pub fn require_member(
+        &self,
+        member_record: Option<&Account<GroupMember>>,
+        member: Pubkey,
+    ) -> Result<()> {
+        if let Some(group) = self.group {
+            let member_record = member_record.ok_or(ErrorCode::NotGroupMember)?;
+            require_keys_eq!(member_record.group, group, ErrorCode::NotGroupMember);
+            require_keys_eq!(member_record.member, member, ErrorCode::NotGroupMember);
+        }
+        Ok(())
+    }
+
+    /// Contributions are refunded when the challenge is cancelled or ends without any submissions
+    pub fn refunds_contributions(&self, now: i64) -> bool {
+        self.cancelled || (self.submission_count == 0 && now >= self.submission_end)
+    }
+
+    /// Cancelled challenges take no more entries, votes or contributions
+    pub fn require_active(&self) -> Result<()> {
+        require!(!self.cancelled, ErrorCode::ChallengeInactive);
+        Ok(())
+    }
+
+    /// Whether contributions are still waiting to be claimed back, submissions
+    /// to be closed with their entry fees, or locked vote tokens to be withdrawn,
+    /// so the challenge must stay open
+    pub fn owes_refunds(&self) -> bool {
+        self.contributed_amount > 0 || self.submission_count > 0 || self.open_vote_vaults > 0
+    }
+
+    /// What the pool holds beyond the contributions and entry fees owed back,
+    /// which is the creator's own once the challenge is cancelled
+    pub fn creator_share(&self) -> Result<u64> {
+        let entry_fees = self.entry_fee.checked_mul(self.submission_count as u64).ok_or(ErrorCode::Overflow)?;
+        self.reward_amount
+            .checked_sub(self.contributed_amount)
+            .and_then(|rest| rest.checked_sub(entry_fees))
+            .ok_or(error!(ErrorCode::Overflow))
+    }
+
+    /// The creator may call a challenge off until voting starts, or once it is
+    /// clear nobody entered
+    pub fn can_cancel(&self, now: i64) -> bool {
+        now < self.submission_end || self.submission_count == 0
+    }
+
+    /// Adds a vote of the given weight to a submission and re-ranks it
+    pub fn count_vote(&mut self, submission: &mut Account<MemeSubmission>, weight: u64) -> Result<()> {
+        submission.votes = submission.votes.checked_add(weight).ok_or(ErrorCode::Overflow)?;
+        self.total_votes = self.total_votes.checked_add(weight).ok_or(ErrorCode::Overflow)?;
+        self.record_votes(LeaderboardEntry {
+            submission: submission.key(),
+            submitter: submission.submitter,
+            votes: submission.votes,
+            submitted_at: submission.submitted_at,
+        });
+        Ok(())
+    }
+
+    /// Takes a moved or retracted vote back off a submission and re-ranks it. Once
+    /// an entry on a full leaderboard falls below the one that was last, a submission
+    /// off the board may be ahead of it, so it drops off and the board goes stale.
+    pub fn withdraw_vote(&mut self, submission: &mut Account<MemeSubmission>, weight: u64) -> Result<()> {
+        submission.votes = submission.votes.checked_sub(weight).ok_or(ErrorCode::Overflow)?;
+        self.total_votes = self.total_votes.checked_sub(weight).ok_or(ErrorCode::Overflow)?;
+        let entry = LeaderboardEntry {
+            submission: submission.key(),
+            submitter: submission.submitter,
+            votes: submission.votes,
+            submitted_at: submission.submitted_at,
+        };
+        let on_board = self.leaderboard.iter().any(|e| e.submission == entry.submission);
+        let cutoff = self.leaderboard.last().copied()
+            .filter(|_| on_board && self.leaderboard.len() == self.prize_split_bps.len());
+        self.record_votes(entry);
+        if cutoff.is_some_and(|last| last.outranks(&entry)) {
+            self.leaderboard.retain(|e| e.submission != entry.submission);
+            self.leaderboard_stale = true;
+        }
+        Ok(())
+    }
+
+    /// Counts a submission towards rebuilding a stale leaderboard once votes are
+    /// final. Entries still on the board hold their current votes, so once every
+    /// submission has been ranked again the board is exact.
+    pub fn count_ranked(&mut self) -> Result<()> {
+        self.ranked_count = self.ranked_count.checked_add(1).ok_or(ErrorCode::Overflow)?;
+        if self.ranked_count == self.submission_count {
+            self.leaderboard_stale = false;
+        }
+        Ok(())
+    }
+
+    /// Re-ranks a submission after its vote count changed, entering it onto the
+    /// leaderboard if a place is free or it now beats the last paid place.
+    pub fn record_votes(&mut self, entry: LeaderboardEntry) {
+        if let Some(existing) = self.leaderboard.iter_mut().find(|e| e.submission == entry.submission) {
+            *existing = entry;
+        } else if self.leaderboard.len() < self.prize_split_bps.len() {
+            self.leaderboard.push(entry);
+        } else if self.leaderboard.last().is_some_and(|last| entry.outranks(last)) {
+            *self.leaderboard.last_mut().unwrap() = entry;
+        } else {
+            return;
+        }
+        self.leaderboard.sort_by(LeaderboardEntry::rank);
+    }
+}
@@ -7,18 +7,26 @@ pub struct MemeSubmission {
     pub image_url: String,   // Assume max 200 chars
     pub title: String,       // Assume max 100 chars
     pub description: String, // Assume max 300 chars
-    pub votes: u32,
+    /// Hash of the image, which can be entered only once per challenge
+    pub content_hash: [u8; 32],
+    pub votes: u64,
     pub submitted_at: i64,
+    /// Set once the submission counts towards rebuilding a stale leaderboard
+    pub ranked: bool,
+    /// Gets the submission's rent back if it is closed for a refund
+    pub rent_payer: Pubkey,
     pub bump: u8,
 }
 
 impl MemeSubmission {
     pub const LEN: usize = 8 + // discriminator
-        32 * 2 + // Pubkeys
+        32 * 3 + // Pubkeys
         (4 + 200) + // image_url String
         (4 + 100) + // title String
         (4 + 300) + // description String
-        4 + // votes u32
+        32 + // content_hash [u8; 32]
+        8 + // votes u64
         8 + // submitted_at i64
+        1 + // ranked bool
         1; // bump
 }
\ No newline at end of file
@@ -4,6 +4,7 @@ use anchor_lang::prelude::*;
 pub struct Message {
     pub group: Pubkey,
     pub sender: Pubkey,
+    pub message_id: u64,
     pub content: String,  // Assume max 500 chars
     pub timestamp: i64,
     pub tips_received: u64,
@@ -13,6 +14,7 @@ pub struct Message {
 impl Message {
     pub const LEN: usize = 8 + // discriminator
         32 * 2 + // Pubkeys
+        8 + // message_id u64
         (4 + 500) + // content String
         8 * 2 + // i64 fields
         1; // bump
@@ -1,5 +1,8 @@
+pub mod challenge_contribution;
+pub mod content_guard;
 pub mod escrow;
 pub mod group;
+pub mod group_ban;
 pub mod group_member;
 pub mod invite;
 pub mod meme_challenge;
@@ -9,8 +12,11 @@ pub mod message;
 pub mod user_profile;
 pub mod voter_record;
 
+pub use challenge_contribution::*;
+pub use content_guard::*;
 pub use escrow::*;
 pub use group::*;
+pub use group_ban::*;
 pub use group_member::*;
 pub use invite::*;
 pub use meme_challenge::*;
@@ -1,16 +1,36 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
 
 #[account]
 pub struct VoterRecord {
+    pub challenge: Pubkey,
+    /// Submission voted for, unset until a commit–reveal vote is revealed.
+    /// Records are keyed by challenge, so each voter holds one vote per challenge.
     pub submission: Pubkey,
     pub voter: Pubkey,
+    /// Hidden vote of a commit–reveal challenge
+    pub commitment: Option<[u8; 32]>,
+    /// Whether the vote counts; open votes count as soon as they are cast
+    pub revealed: bool,
+    /// How much the vote counts under the challenge's voting mode
+    pub weight: u64,
     pub voted_at: i64,
+    /// Gets the rent of the record and any vote vault back when they close
+    pub rent_payer: Pubkey,
     pub bump: u8,
 }
 
 impl VoterRecord {
     pub const LEN: usize = 8 + // discriminator
-        32 * 2 + // Pubkeys
+        32 * 4 + // Pubkeys
+        (1 + 32) + // commitment Option<[u8; 32]>
+        1 + // revealed bool
+        8 + // weight u64
         8 + // voted_at i64
         1; // bump
-}
\ No newline at end of file
+
+    /// Hash a voter commits to; binding the voter stops others copying a commitment
+    pub fn commitment_for(submission: &Pubkey, voter: &Pubkey, salt: &[u8; 32]) -> [u8; 32] {
+        hashv(&[submission.as_ref(), voter.as_ref(), salt]).to_bytes()
+    }
+}
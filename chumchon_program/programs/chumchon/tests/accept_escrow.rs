@@ -0,0 +1,163 @@
+mod common;
+
+use chumchon::{error::ErrorCode, Escrow, EscrowStatus};
+use common::{
+    assert_error, balance, create_escrow, create_escrow_with_legs, create_token_account, fetch, ix,
+    join_group, now, pda, process, start, token_balance, warp_to_timestamp, Leg, LAMPORTS_PER_SOL,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn accept_escrow_success() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow(&mut context, expires_at).await;
+    let counterparty_leg = setup.counterparty_leg.unwrap();
+
+    let accept_ix = setup.accept_ix(context.payer.pubkey());
+    process(&mut context, &[accept_ix], &[&setup.counterparty])
+        .await
+        .unwrap();
+
+    let escrow: Escrow = fetch(&mut context, setup.escrow).await;
+    assert_eq!(escrow.status, EscrowStatus::Accepted);
+    assert!(escrow.accepted_at.is_some());
+    assert_eq!(
+        token_balance(&mut context, counterparty_leg.deposit_account).await,
+        0
+    );
+    assert_eq!(
+        token_balance(&mut context, pda::counterparty_vault(&setup.escrow)).await,
+        250
+    );
+}
+
+#[tokio::test]
+async fn accept_escrow_locks_native_sol() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow_with_legs(&mut context, expires_at, Leg::Token, Leg::Sol).await;
+    let escrow_balance = balance(&mut context, setup.escrow).await;
+
+    let accept_ix = setup.accept_ix(context.payer.pubkey());
+    process(&mut context, &[accept_ix], &[&setup.counterparty])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        balance(&mut context, setup.counterparty.pubkey()).await,
+        LAMPORTS_PER_SOL - 250
+    );
+    assert_eq!(
+        balance(&mut context, setup.escrow).await,
+        escrow_balance + 250
+    );
+}
+
+#[tokio::test]
+async fn accept_escrow_requires_counterparty() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow(&mut context, expires_at).await;
+    let counterparty_leg = setup.counterparty_leg.unwrap();
+    let stranger = Keypair::new();
+    join_group(&mut context, &stranger, setup.group).await;
+    let stranger_token_account =
+        create_token_account(&mut context, &counterparty_leg.mint, &stranger.pubkey()).await;
+
+    let accept_ix = ix::accept_escrow(
+        context.payer.pubkey(),
+        stranger.pubkey(),
+        setup.initiator.pubkey(),
+        setup.group,
+        setup.created_at,
+        Some(counterparty_leg.mint),
+        Some(stranger_token_account),
+    );
+    let result = process(&mut context, &[accept_ix], &[&stranger]).await;
+    assert_error(result, ErrorCode::NotOwner);
+}
+
+#[tokio::test]
+async fn accept_escrow_requires_counterparty_token() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow(&mut context, expires_at).await;
+    let initiator_leg = setup.initiator_leg.unwrap();
+
+    let accept_ix = ix::accept_escrow(
+        context.payer.pubkey(),
+        setup.counterparty.pubkey(),
+        setup.initiator.pubkey(),
+        setup.group,
+        setup.created_at,
+        Some(initiator_leg.mint),
+        Some(initiator_leg.receive_account),
+    );
+    let result = process(&mut context, &[accept_ix], &[&setup.counterparty]).await;
+    assert_error(result, ErrorCode::InvalidToken);
+
+    // Paying in SOL when the escrow asks for tokens is rejected too
+    let accept_ix = ix::accept_escrow(
+        context.payer.pubkey(),
+        setup.counterparty.pubkey(),
+        setup.initiator.pubkey(),
+        setup.group,
+        setup.created_at,
+        None,
+        None,
+    );
+    let result = process(&mut context, &[accept_ix], &[&setup.counterparty]).await;
+    assert_error(result, ErrorCode::InvalidToken);
+}
+
+#[tokio::test]
+async fn accept_escrow_rejects_second_acceptance() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow_with_legs(&mut context, expires_at, Leg::Token, Leg::Sol).await;
+
+    let accept_ix = setup.accept_ix(context.payer.pubkey());
+    process(
+        &mut context,
+        std::slice::from_ref(&accept_ix),
+        &[&setup.counterparty],
+    )
+    .await
+    .unwrap();
+
+    let result = process(&mut context, &[accept_ix], &[&setup.counterparty]).await;
+    assert_error(result, ErrorCode::EscrowAlreadyAccepted);
+}
+
+#[tokio::test]
+async fn accept_escrow_rejects_second_token_acceptance() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow(&mut context, expires_at).await;
+
+    let accept_ix = setup.accept_ix(context.payer.pubkey());
+    process(
+        &mut context,
+        std::slice::from_ref(&accept_ix),
+        &[&setup.counterparty],
+    )
+    .await
+    .unwrap();
+
+    let result = process(&mut context, &[accept_ix], &[&setup.counterparty]).await;
+    assert_error(result, ErrorCode::EscrowAlreadyAccepted);
+}
+
+#[tokio::test]
+async fn accept_escrow_rejects_expired_escrow() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow(&mut context, expires_at).await;
+    warp_to_timestamp(&mut context, expires_at + 1).await;
+
+    let accept_ix = setup.accept_ix(context.payer.pubkey());
+    let result = process(&mut context, &[accept_ix], &[&setup.counterparty]).await;
+    assert_error(result, ErrorCode::EscrowExpired);
+}
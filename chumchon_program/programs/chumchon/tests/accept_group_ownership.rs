@@ -0,0 +1,112 @@
+mod common;
+
+use chumchon::{error::ErrorCode, instruction, Group, GroupRole};
+use common::{
+    add_group_admin, assert_error, create_group, fetch, group_args, ix, join_group, now, pda,
+    process, send_message, start,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn accept_group_ownership_success() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let successor = Keypair::new();
+    let member = Keypair::new();
+    let group = create_group(
+        &mut context,
+        &creator,
+        instruction::CreateGroup {
+            is_channel: true,
+            ..group_args("chums")
+        },
+    )
+    .await;
+    join_group(&mut context, &successor, group).await;
+    join_group(&mut context, &member, group).await;
+    let transfer_ix =
+        ix::transfer_group_ownership(creator.pubkey(), group, Some(successor.pubkey()));
+    process(&mut context, &[transfer_ix], &[&creator])
+        .await
+        .unwrap();
+
+    let accept_ix = ix::accept_group_ownership(successor.pubkey(), group);
+    process(&mut context, &[accept_ix], &[&successor])
+        .await
+        .unwrap();
+
+    let group_account: Group = fetch(&mut context, group).await;
+    assert_eq!(group_account.owner, successor.pubkey());
+    assert_eq!(group_account.creator, creator.pubkey());
+    assert_eq!(group_account.pending_owner, None);
+    assert_eq!(pda::group("chums", &creator.pubkey()), group);
+
+    // The new owner runs the group through their membership
+    send_message(&mut context, &successor, group, "gm").await;
+    add_group_admin(
+        &mut context,
+        &successor,
+        group,
+        &member.pubkey(),
+        GroupRole::Admin,
+    )
+    .await;
+    let payer = context.payer.pubkey();
+    let kick_ix = ix::kick_member(member.pubkey(), group, true, successor.pubkey(), payer);
+    let result = process(&mut context, &[kick_ix], &[&member]).await;
+    assert_error(result, ErrorCode::NotGroupAdmin);
+    let invite_ix = ix::create_invite_as(
+        context.payer.pubkey(),
+        successor.pubkey(),
+        group,
+        true,
+        instruction::CreateInvite {
+            code: "gmchums".to_string(),
+            max_uses: 5,
+            expires_at: now(&mut context).await + 3600,
+        },
+    );
+    process(&mut context, &[invite_ix], &[&successor])
+        .await
+        .unwrap();
+
+    // While the creator no longer has any say
+    let invite_ix = ix::create_invite(
+        context.payer.pubkey(),
+        creator.pubkey(),
+        group,
+        instruction::CreateInvite {
+            code: "gmagain".to_string(),
+            max_uses: 5,
+            expires_at: now(&mut context).await + 3600,
+        },
+    );
+    let result = process(&mut context, &[invite_ix], &[&creator]).await;
+    assert_error(result, ErrorCode::NotGroupAdmin);
+    let transfer_ix = ix::transfer_group_ownership(creator.pubkey(), group, Some(creator.pubkey()));
+    let result = process(&mut context, &[transfer_ix], &[&creator]).await;
+    assert_error(result, ErrorCode::NotGroupOwner);
+}
+
+#[tokio::test]
+async fn accept_group_ownership_requires_offer() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let successor = Keypair::new();
+    let stranger = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+
+    let accept_ix = ix::accept_group_ownership(successor.pubkey(), group);
+    let result = process(&mut context, &[accept_ix], &[&successor]).await;
+    assert_error(result, ErrorCode::NotPendingOwner);
+
+    let transfer_ix =
+        ix::transfer_group_ownership(creator.pubkey(), group, Some(successor.pubkey()));
+    process(&mut context, &[transfer_ix], &[&creator])
+        .await
+        .unwrap();
+    let accept_ix = ix::accept_group_ownership(stranger.pubkey(), group);
+    let result = process(&mut context, &[accept_ix], &[&stranger]).await;
+    assert_error(result, ErrorCode::NotPendingOwner);
+}
@@ -0,0 +1,119 @@
+mod common;
+
+use chumchon::{error::ErrorCode, GroupMember, GroupRole};
+use common::{
+    add_group_admin, assert_error, create_group, fetch, group_args, ix, join_group, pda, process,
+    start,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn add_group_admin_success() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let admin = Keypair::new();
+    let moderator = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    join_group(&mut context, &admin, group).await;
+    join_group(&mut context, &moderator, group).await;
+
+    add_group_admin(
+        &mut context,
+        &creator,
+        group,
+        &admin.pubkey(),
+        GroupRole::Admin,
+    )
+    .await;
+    let record: GroupMember = fetch(&mut context, pda::group_member(&group, &admin.pubkey())).await;
+    assert_eq!(record.role, GroupRole::Admin);
+
+    // Admins may appoint moderators
+    let add_ix = ix::add_group_admin(
+        admin.pubkey(),
+        group,
+        true,
+        moderator.pubkey(),
+        GroupRole::Moderator,
+    );
+    process(&mut context, &[add_ix], &[&admin]).await.unwrap();
+    let record: GroupMember =
+        fetch(&mut context, pda::group_member(&group, &moderator.pubkey())).await;
+    assert_eq!(record.role, GroupRole::Moderator);
+}
+
+#[tokio::test]
+async fn add_group_admin_requires_higher_role() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let admin = Keypair::new();
+    let member = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    join_group(&mut context, &admin, group).await;
+    join_group(&mut context, &member, group).await;
+
+    // A plain member cannot promote anyone
+    let add_ix = ix::add_group_admin(
+        member.pubkey(),
+        group,
+        true,
+        admin.pubkey(),
+        GroupRole::Moderator,
+    );
+    let result = process(&mut context, &[add_ix], &[&member]).await;
+    assert_error(result, ErrorCode::NotGroupAdmin);
+
+    // Only the creator appoints admins
+    add_group_admin(
+        &mut context,
+        &creator,
+        group,
+        &admin.pubkey(),
+        GroupRole::Admin,
+    )
+    .await;
+    let add_ix = ix::add_group_admin(
+        admin.pubkey(),
+        group,
+        true,
+        member.pubkey(),
+        GroupRole::Admin,
+    );
+    let result = process(&mut context, &[add_ix], &[&admin]).await;
+    assert_error(result, ErrorCode::NotGroupAdmin);
+
+    // Nor can an admin turn a fellow admin into a moderator
+    add_group_admin(
+        &mut context,
+        &creator,
+        group,
+        &member.pubkey(),
+        GroupRole::Admin,
+    )
+    .await;
+    let add_ix = ix::add_group_admin(
+        admin.pubkey(),
+        group,
+        true,
+        member.pubkey(),
+        GroupRole::Moderator,
+    );
+    let result = process(&mut context, &[add_ix], &[&admin]).await;
+    assert_error(result, ErrorCode::NotGroupAdmin);
+}
+
+#[tokio::test]
+async fn add_group_admin_rejects_other_roles() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let member = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    join_group(&mut context, &member, group).await;
+
+    for role in [GroupRole::Member, GroupRole::Owner] {
+        let add_ix = ix::add_group_admin(creator.pubkey(), group, false, member.pubkey(), role);
+        let result = process(&mut context, &[add_ix], &[&creator]).await;
+        assert_error(result, ErrorCode::InvalidRole);
+    }
+}
@@ -0,0 +1,227 @@
+mod common;
+
+use chumchon::{error::ErrorCode, Group, GroupBan, GroupRole};
+use common::{
+    account_exists, add_group_admin, assert_error, ban_member, create_group, fetch, group_args, ix,
+    join_group, now, pda, process, start, warp_to_timestamp,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn ban_member_success() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let member = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    join_group(&mut context, &member, group).await;
+
+    ban_member(&mut context, &creator, group, &member.pubkey()).await;
+
+    assert!(!account_exists(&mut context, pda::group_member(&group, &member.pubkey())).await);
+    let group_account: Group = fetch(&mut context, group).await;
+    assert_eq!(group_account.member_count, 0);
+    let ban: GroupBan = fetch(&mut context, pda::group_ban(&group, &member.pubkey())).await;
+    assert_eq!(ban.group, group);
+    assert_eq!(ban.member, member.pubkey());
+    assert_eq!(ban.banned_by, creator.pubkey());
+    assert_eq!(ban.banned_by_role, GroupRole::Owner);
+    assert_eq!(ban.reason, 1);
+    assert_eq!(ban.expires_at, None);
+
+    let join_ix = ix::join_group(context.payer.pubkey(), member.pubkey(), group, None, None);
+    let result = process(&mut context, &[join_ix], &[&member]).await;
+    assert_error(result, ErrorCode::BannedFromGroup);
+}
+
+#[tokio::test]
+async fn ban_member_after_leaving() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let member = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    join_group(&mut context, &member, group).await;
+    let leave_ix = ix::leave_group(member.pubkey(), group, context.payer.pubkey());
+    process(&mut context, &[leave_ix], &[&member])
+        .await
+        .unwrap();
+
+    let ban_ix = ix::ban_member(
+        context.payer.pubkey(),
+        creator.pubkey(),
+        group,
+        false,
+        member.pubkey(),
+        None,
+        None,
+    );
+    process(&mut context, &[ban_ix], &[&creator]).await.unwrap();
+
+    let join_ix = ix::join_group(context.payer.pubkey(), member.pubkey(), group, None, None);
+    let result = process(&mut context, &[join_ix], &[&member]).await;
+    assert_error(result, ErrorCode::BannedFromGroup);
+}
+
+#[tokio::test]
+async fn ban_member_until_expiry() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let member = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    join_group(&mut context, &member, group).await;
+    let now = now(&mut context).await;
+
+    let payer = context.payer.pubkey();
+    let ban_ix = |expires_at| {
+        ix::ban_member(
+            payer,
+            creator.pubkey(),
+            group,
+            false,
+            member.pubkey(),
+            Some(payer),
+            Some(expires_at),
+        )
+    };
+    let result = process(&mut context, &[ban_ix(now)], &[&creator]).await;
+    assert_error(result, ErrorCode::InvalidExpiry);
+    process(&mut context, &[ban_ix(now + 3600)], &[&creator])
+        .await
+        .unwrap();
+
+    let join_ix = ix::join_group(payer, member.pubkey(), group, None, None);
+    let result = process(&mut context, std::slice::from_ref(&join_ix), &[&member]).await;
+    assert_error(result, ErrorCode::BannedFromGroup);
+
+    warp_to_timestamp(&mut context, now + 3600).await;
+    process(&mut context, &[join_ix], &[&member]).await.unwrap();
+}
+
+#[tokio::test]
+async fn ban_member_again_after_expiry() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let member = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    join_group(&mut context, &member, group).await;
+    let now = now(&mut context).await;
+
+    let payer = context.payer.pubkey();
+    let ban_ix = |expires_at| {
+        ix::ban_member(
+            payer,
+            creator.pubkey(),
+            group,
+            false,
+            member.pubkey(),
+            Some(payer),
+            expires_at,
+        )
+    };
+    process(&mut context, &[ban_ix(Some(now + 3600))], &[&creator])
+        .await
+        .unwrap();
+    let result = process(&mut context, &[ban_ix(None)], &[&creator]).await;
+    assert_error(result, ErrorCode::AlreadyBanned);
+
+    // The expired ban stays on record until it is lifted, and a new ban replaces it
+    warp_to_timestamp(&mut context, now + 3600).await;
+    process(&mut context, &[ban_ix(None)], &[&creator])
+        .await
+        .unwrap();
+
+    let ban: GroupBan = fetch(&mut context, pda::group_ban(&group, &member.pubkey())).await;
+    assert_eq!(ban.expires_at, None);
+    assert_eq!(ban.rent_payer, payer);
+    let join_ix = ix::join_group(payer, member.pubkey(), group, None, None);
+    let result = process(&mut context, &[join_ix], &[&member]).await;
+    assert_error(result, ErrorCode::BannedFromGroup);
+}
+
+#[tokio::test]
+async fn ban_member_requires_higher_role() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let moderator = Keypair::new();
+    let admin = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    join_group(&mut context, &moderator, group).await;
+    join_group(&mut context, &admin, group).await;
+    add_group_admin(
+        &mut context,
+        &creator,
+        group,
+        &moderator.pubkey(),
+        GroupRole::Moderator,
+    )
+    .await;
+    add_group_admin(
+        &mut context,
+        &creator,
+        group,
+        &admin.pubkey(),
+        GroupRole::Admin,
+    )
+    .await;
+    let payer = context.payer.pubkey();
+
+    let ban_ix = ix::ban_member(
+        payer,
+        moderator.pubkey(),
+        group,
+        true,
+        admin.pubkey(),
+        Some(payer),
+        None,
+    );
+    let result = process(&mut context, &[ban_ix], &[&moderator]).await;
+    assert_error(result, ErrorCode::NotGroupAdmin);
+
+    // Leaving out the rent payer does not pass the admin off as having left
+    let ban_ix = ix::ban_member(
+        payer,
+        moderator.pubkey(),
+        group,
+        true,
+        admin.pubkey(),
+        None,
+        None,
+    );
+    let result = process(&mut context, &[ban_ix], &[&moderator]).await;
+    assert_error(result, ErrorCode::NotGroupAdmin);
+
+    // The creator cannot be banned even without a membership record
+    let ban_ix = ix::ban_member(
+        payer,
+        admin.pubkey(),
+        group,
+        true,
+        creator.pubkey(),
+        None,
+        None,
+    );
+    let result = process(&mut context, &[ban_ix], &[&admin]).await;
+    assert_error(result, ErrorCode::NotGroupAdmin);
+}
+
+#[tokio::test]
+async fn ban_member_requires_rent_payer_while_member() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let member = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    join_group(&mut context, &member, group).await;
+
+    let ban_ix = ix::ban_member(
+        context.payer.pubkey(),
+        creator.pubkey(),
+        group,
+        false,
+        member.pubkey(),
+        None,
+        None,
+    );
+    let result = process(&mut context, &[ban_ix], &[&creator]).await;
+    assert_error(result, ErrorCode::NotOwner);
+    assert!(account_exists(&mut context, pda::group_member(&group, &member.pubkey())).await);
+}
@@ -0,0 +1,75 @@
+mod common;
+
+use chumchon::error::ErrorCode;
+use common::{
+    account_exists, assert_error, balance, create_escrow, create_escrow_with_legs, now, pda,
+    process, start, token_balance, Leg, LAMPORTS_PER_SOL,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn cancel_escrow_success() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow(&mut context, expires_at).await;
+    let rent_payer_balance = balance(&mut context, setup.rent_payer).await;
+
+    let cancel_ix = setup.cancel_ix(context.payer.pubkey(), setup.initiator.pubkey());
+    process(&mut context, &[cancel_ix], &[&setup.initiator])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        token_balance(&mut context, setup.initiator_leg.unwrap().deposit_account).await,
+        100
+    );
+    assert!(!account_exists(&mut context, setup.escrow).await);
+    assert!(!account_exists(&mut context, pda::initiator_vault(&setup.escrow)).await);
+    assert!(balance(&mut context, setup.rent_payer).await > rent_payer_balance);
+}
+
+#[tokio::test]
+async fn cancel_escrow_refunds_native_sol() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow_with_legs(&mut context, expires_at, Leg::Sol, Leg::Token).await;
+
+    let cancel_ix = setup.cancel_ix(context.payer.pubkey(), setup.initiator.pubkey());
+    process(&mut context, &[cancel_ix], &[&setup.initiator])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        balance(&mut context, setup.initiator.pubkey()).await,
+        LAMPORTS_PER_SOL
+    );
+    assert!(!account_exists(&mut context, setup.escrow).await);
+}
+
+#[tokio::test]
+async fn cancel_escrow_requires_initiator() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow(&mut context, expires_at).await;
+    let stranger = Keypair::new();
+
+    let cancel_ix = setup.cancel_ix(context.payer.pubkey(), stranger.pubkey());
+    let result = process(&mut context, &[cancel_ix], &[&stranger]).await;
+    assert_error(result, ErrorCode::NotOwner);
+}
+
+#[tokio::test]
+async fn cancel_escrow_rejects_accepted_escrow() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow(&mut context, expires_at).await;
+    let accept_ix = setup.accept_ix(context.payer.pubkey());
+    process(&mut context, &[accept_ix], &[&setup.counterparty])
+        .await
+        .unwrap();
+
+    let cancel_ix = setup.cancel_ix(context.payer.pubkey(), setup.initiator.pubkey());
+    let result = process(&mut context, &[cancel_ix], &[&setup.initiator]).await;
+    assert_error(result, ErrorCode::EscrowAlreadyAccepted);
+}
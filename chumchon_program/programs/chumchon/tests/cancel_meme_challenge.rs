@@ -0,0 +1,121 @@
+mod common;
+
+use chumchon::{error::ErrorCode, instruction, MemeChallenge};
+use common::{
+    assert_error, contribute_to_challenge, create_meme_challenge, fetch, ix, process, start,
+    submission_args, submit_meme, warp_to_timestamp, LAMPORTS_PER_SOL,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn cancel_meme_challenge_success() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let contributor = Keypair::new();
+    let submitter = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    contribute_to_challenge(&mut context, &contributor, challenge, LAMPORTS_PER_SOL / 2).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+
+    let cancel_ix = ix::cancel_meme_challenge(creator.pubkey(), challenge);
+    process(&mut context, std::slice::from_ref(&cancel_ix), &[&creator])
+        .await
+        .unwrap();
+
+    // Refunds are claimed afterwards, so the pool stays put for now
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert!(challenge_account.cancelled);
+    assert_eq!(challenge_account.reward_amount, 3 * LAMPORTS_PER_SOL / 2);
+    assert_eq!(challenge_account.contributed_amount, LAMPORTS_PER_SOL / 2);
+
+    let result = process(&mut context, &[cancel_ix], &[&creator]).await;
+    assert_error(result, ErrorCode::ChallengeInactive);
+}
+
+#[tokio::test]
+async fn cancel_meme_challenge_stops_entries_and_votes() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let latecomer = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    let cancel_ix = ix::cancel_meme_challenge(creator.pubkey(), challenge);
+    process(&mut context, &[cancel_ix], &[&creator])
+        .await
+        .unwrap();
+
+    let submit_ix = ix::submit_meme(
+        context.payer.pubkey(),
+        latecomer.pubkey(),
+        challenge,
+        None,
+        instruction::SubmitMeme {
+            content_hash: latecomer.pubkey().to_bytes(),
+            ..submission_args()
+        },
+    );
+    let result = process(&mut context, &[submit_ix], &[&latecomer]).await;
+    assert_error(result, ErrorCode::ChallengeInactive);
+
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+    let voter = Keypair::new();
+    let vote_ix = ix::vote_for_meme(
+        context.payer.pubkey(),
+        voter.pubkey(),
+        challenge,
+        None,
+        submitter.pubkey(),
+    );
+    let result = process(&mut context, &[vote_ix], &[&voter]).await;
+    assert_error(result, ErrorCode::ChallengeInactive);
+}
+
+#[tokio::test]
+async fn cancel_meme_challenge_once_voting_starts() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+
+    let cancel_ix = ix::cancel_meme_challenge(creator.pubkey(), challenge);
+    let result = process(&mut context, &[cancel_ix], &[&creator]).await;
+    assert_error(result, ErrorCode::ChallengeNotCancellable);
+}
+
+#[tokio::test]
+async fn cancel_meme_challenge_without_submissions() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.voting_end).await;
+
+    let cancel_ix = ix::cancel_meme_challenge(creator.pubkey(), challenge);
+    process(&mut context, &[cancel_ix], &[&creator])
+        .await
+        .unwrap();
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert!(challenge_account.cancelled);
+}
+
+#[tokio::test]
+async fn cancel_meme_challenge_requires_creator() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let stranger = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+
+    let cancel_ix = ix::cancel_meme_challenge(stranger.pubkey(), challenge);
+    let result = process(&mut context, &[cancel_ix], &[&stranger]).await;
+    assert_error(result, ErrorCode::NotGroupCreator);
+}
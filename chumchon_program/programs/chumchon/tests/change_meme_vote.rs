@@ -0,0 +1,333 @@
+mod common;
+
+use chumchon::{
+    error::ErrorCode, instruction, MemeChallenge, MemeSubmission, VoterRecord, VotingMode,
+};
+use common::{
+    account_exists, assert_error, balance, commit_meme_vote, create_commit_reveal_challenge,
+    create_meme_challenge, create_meme_challenge_with, create_mint, create_token_account, fetch,
+    fund, ix, mint_to, pda, process, start, submit_meme, token_balance, vote_for_meme,
+    warp_to_timestamp, LAMPORTS_PER_SOL,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn change_meme_vote_moves_vote() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let first = Keypair::new();
+    let second = Keypair::new();
+    let voter = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &first, challenge).await;
+    submit_meme(&mut context, &second, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+    vote_for_meme(&mut context, &voter, challenge, &first.pubkey()).await;
+
+    let change_ix = ix::change_meme_vote(
+        voter.pubkey(),
+        context.payer.pubkey(),
+        challenge,
+        None,
+        first.pubkey(),
+        Some(second.pubkey()),
+    );
+    process(&mut context, &[change_ix], &[&voter])
+        .await
+        .unwrap();
+
+    let first_submission = pda::meme_submission(&challenge, &first.pubkey());
+    let second_submission = pda::meme_submission(&challenge, &second.pubkey());
+    let first_account: MemeSubmission = fetch(&mut context, first_submission).await;
+    let second_account: MemeSubmission = fetch(&mut context, second_submission).await;
+    assert_eq!(first_account.votes, 0);
+    assert_eq!(second_account.votes, 1);
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(challenge_account.total_votes, 1);
+    assert_eq!(
+        challenge_account.leaderboard[0].submission,
+        second_submission
+    );
+    let record: VoterRecord =
+        fetch(&mut context, pda::voter_record(&challenge, &voter.pubkey())).await;
+    assert_eq!(record.submission, second_submission);
+
+    // The old submission no longer holds the vote
+    let change_ix = ix::change_meme_vote(
+        voter.pubkey(),
+        context.payer.pubkey(),
+        challenge,
+        None,
+        first.pubkey(),
+        Some(second.pubkey()),
+    );
+    let result = process(&mut context, &[change_ix], &[&voter]).await;
+    assert_error(result, ErrorCode::NotVotedFor);
+
+    let change_ix = ix::change_meme_vote(
+        voter.pubkey(),
+        context.payer.pubkey(),
+        challenge,
+        None,
+        second.pubkey(),
+        Some(second.pubkey()),
+    );
+    let result = process(&mut context, &[change_ix], &[&voter]).await;
+    assert_error(result, ErrorCode::AlreadyVoted);
+}
+
+#[tokio::test]
+async fn change_meme_vote_retracts_vote() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let voter = Keypair::new();
+    let rent_payer = Keypair::new();
+    fund(&mut context, &rent_payer.pubkey(), LAMPORTS_PER_SOL);
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+    let vote_ix = ix::vote_for_meme(
+        rent_payer.pubkey(),
+        voter.pubkey(),
+        challenge,
+        None,
+        submitter.pubkey(),
+    );
+    process(&mut context, &[vote_ix], &[&rent_payer, &voter])
+        .await
+        .unwrap();
+
+    let change_ix = ix::change_meme_vote(
+        voter.pubkey(),
+        voter.pubkey(),
+        challenge,
+        None,
+        submitter.pubkey(),
+        None,
+    );
+    let result = process(&mut context, &[change_ix], &[&voter]).await;
+    assert_error(result, ErrorCode::NotOwner);
+
+    let change_ix = ix::change_meme_vote(
+        voter.pubkey(),
+        rent_payer.pubkey(),
+        challenge,
+        None,
+        submitter.pubkey(),
+        None,
+    );
+    process(&mut context, &[change_ix], &[&voter])
+        .await
+        .unwrap();
+
+    // The record's rent goes back to whoever paid for the vote
+    assert_eq!(
+        balance(&mut context, rent_payer.pubkey()).await,
+        LAMPORTS_PER_SOL
+    );
+    let submission: MemeSubmission = fetch(
+        &mut context,
+        pda::meme_submission(&challenge, &submitter.pubkey()),
+    )
+    .await;
+    assert_eq!(submission.votes, 0);
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(challenge_account.total_votes, 0);
+    // Another submission could now be ahead, so the entry waits to be ranked again
+    assert!(challenge_account.leaderboard.is_empty());
+    assert!(!account_exists(&mut context, pda::voter_record(&challenge, &voter.pubkey())).await);
+
+    // A retracted vote can be cast again
+    vote_for_meme(&mut context, &voter, challenge, &submitter.pubkey()).await;
+}
+
+#[tokio::test]
+async fn change_meme_vote_retract_off_the_board_keeps_it_fresh() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let leader = Keypair::new();
+    let trailer = Keypair::new();
+    let voter = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &leader, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 60).await;
+    submit_meme(&mut context, &trailer, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+    vote_for_meme(&mut context, &Keypair::new(), challenge, &leader.pubkey()).await;
+    vote_for_meme(&mut context, &voter, challenge, &trailer.pubkey()).await;
+
+    let change_ix = ix::change_meme_vote(
+        voter.pubkey(),
+        context.payer.pubkey(),
+        challenge,
+        None,
+        trailer.pubkey(),
+        None,
+    );
+    process(&mut context, &[change_ix], &[&voter])
+        .await
+        .unwrap();
+
+    // Only the single place's holder is on the board, and it kept its lead
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert!(!challenge_account.leaderboard_stale);
+    assert_eq!(challenge_account.leaderboard.len(), 1);
+    assert_eq!(challenge_account.leaderboard[0].submitter, leader.pubkey());
+}
+
+#[tokio::test]
+async fn change_meme_vote_retract_returns_locked_tokens() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let voter = Keypair::new();
+    let mint_authority = Keypair::new();
+    let mint = create_mint(&mut context, &mint_authority.pubkey()).await;
+    let voter_token_account = create_token_account(&mut context, &mint, &voter.pubkey()).await;
+    mint_to(
+        &mut context,
+        &mint,
+        &voter_token_account,
+        &mint_authority,
+        40,
+    )
+    .await;
+    let challenge = create_meme_challenge_with(&mut context, &creator, |args| {
+        instruction::CreateMemeChallenge {
+            voting_mode: VotingMode::TokenWeighted,
+            voting_mint: Some(mint),
+            ..args
+        }
+    })
+    .await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+    let vote_ix = ix::vote_for_meme_weighted(
+        context.payer.pubkey(),
+        voter.pubkey(),
+        challenge,
+        None,
+        submitter.pubkey(),
+        ix::VoteWeight {
+            voter_token_account: Some(voter_token_account),
+            voting_mint: Some(mint),
+            ..Default::default()
+        },
+    );
+    process(&mut context, &[vote_ix], &[&voter]).await.unwrap();
+    assert_eq!(token_balance(&mut context, voter_token_account).await, 0);
+
+    let change_ix = ix::change_meme_vote_weighted(
+        voter.pubkey(),
+        context.payer.pubkey(),
+        challenge,
+        None,
+        submitter.pubkey(),
+        None,
+        Some(voter_token_account),
+    );
+    process(&mut context, &[change_ix], &[&voter])
+        .await
+        .unwrap();
+
+    assert_eq!(token_balance(&mut context, voter_token_account).await, 40);
+    assert!(!account_exists(&mut context, pda::vote_vault(&challenge, &voter.pubkey())).await);
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(challenge_account.total_votes, 0);
+    assert_eq!(challenge_account.open_vote_vaults, 0);
+}
+
+#[tokio::test]
+async fn change_meme_vote_rejects_own_submission() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let voter = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    submit_meme(&mut context, &voter, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+    vote_for_meme(&mut context, &voter, challenge, &submitter.pubkey()).await;
+
+    let change_ix = ix::change_meme_vote(
+        voter.pubkey(),
+        context.payer.pubkey(),
+        challenge,
+        None,
+        submitter.pubkey(),
+        Some(voter.pubkey()),
+    );
+    let result = process(&mut context, &[change_ix], &[&voter]).await;
+    assert_error(result, ErrorCode::CannotVoteOwnSubmission);
+}
+
+#[tokio::test]
+async fn change_meme_vote_outside_voting_window() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let voter = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+    vote_for_meme(&mut context, &voter, challenge, &submitter.pubkey()).await;
+    warp_to_timestamp(&mut context, challenge_account.voting_end).await;
+
+    let change_ix = ix::change_meme_vote(
+        voter.pubkey(),
+        context.payer.pubkey(),
+        challenge,
+        None,
+        submitter.pubkey(),
+        None,
+    );
+    let result = process(&mut context, &[change_ix], &[&voter]).await;
+    assert_error(result, ErrorCode::VotingNotOpen);
+}
+
+#[tokio::test]
+async fn change_meme_vote_rejects_committed_votes() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let voter = Keypair::new();
+    let challenge = create_commit_reveal_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+    commit_meme_vote(
+        &mut context,
+        &voter,
+        challenge,
+        &submitter.pubkey(),
+        [7; 32],
+    )
+    .await;
+
+    // Hidden votes are not on any submission yet
+    let change_ix = ix::change_meme_vote(
+        voter.pubkey(),
+        context.payer.pubkey(),
+        challenge,
+        None,
+        submitter.pubkey(),
+        None,
+    );
+    let result = process(&mut context, &[change_ix], &[&voter]).await;
+    assert_error(result, ErrorCode::NotVotedFor);
+}
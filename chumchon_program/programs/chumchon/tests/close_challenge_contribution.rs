@@ -0,0 +1,70 @@
+mod common;
+
+use chumchon::{error::ErrorCode, MemeChallenge};
+use common::{
+    account_exists, assert_error, balance, contribute_to_challenge, create_meme_challenge, fetch,
+    ix, pda, process, start, submit_meme, warp_to_timestamp, LAMPORTS_PER_SOL,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn close_challenge_contribution_after_completion() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let contributor = Keypair::new();
+    let submitter = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    contribute_to_challenge(&mut context, &contributor, challenge, LAMPORTS_PER_SOL / 2).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    let contribution = pda::challenge_contribution(&challenge, 0);
+    let payer = context.payer.pubkey();
+
+    // The contribution may still be owed back until the challenge pays out
+    let close_ix = ix::close_challenge_contribution(contributor.pubkey(), challenge, 0, payer);
+    let result = process(
+        &mut context,
+        std::slice::from_ref(&close_ix),
+        &[&contributor],
+    )
+    .await;
+    assert_error(result, ErrorCode::ChallengeNotEnded);
+
+    warp_to_timestamp(&mut context, challenge_account.voting_end).await;
+    let end_ix = ix::end_meme_challenge(payer, challenge, payer, false, &[submitter.pubkey()]);
+    process(&mut context, &[end_ix], &[]).await.unwrap();
+
+    let record_rent = balance(&mut context, contribution).await;
+    let payer_before = balance(&mut context, payer).await;
+    process(&mut context, &[close_ix], &[&contributor])
+        .await
+        .unwrap();
+
+    // The record's rent goes back to whoever paid for it
+    assert!(balance(&mut context, payer).await > payer_before + record_rent / 2);
+    assert!(!account_exists(&mut context, contribution).await);
+}
+
+#[tokio::test]
+async fn close_challenge_contribution_requires_contributor() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let contributor = Keypair::new();
+    let stranger = Keypair::new();
+    let submitter = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    contribute_to_challenge(&mut context, &contributor, challenge, LAMPORTS_PER_SOL / 2).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.voting_end).await;
+    let payer = context.payer.pubkey();
+    let end_ix = ix::end_meme_challenge(payer, challenge, payer, false, &[submitter.pubkey()]);
+    process(&mut context, &[end_ix], &[]).await.unwrap();
+
+    let close_ix = ix::close_challenge_contribution(stranger.pubkey(), challenge, 0, payer);
+    let result = process(&mut context, &[close_ix], &[&stranger]).await;
+    assert_error(result, ErrorCode::NotOwner);
+}
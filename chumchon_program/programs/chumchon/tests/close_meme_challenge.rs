@@ -0,0 +1,284 @@
+mod common;
+
+use chumchon::{error::ErrorCode, instruction, MemeChallenge};
+use common::{
+    account_exists, assert_error, balance, challenge_args, contribute_to_challenge,
+    create_meme_challenge, create_meme_challenge_with, create_mint, create_token_account, fetch,
+    fund, ix, mint_to, now, pda, process, start, store, submit_meme, token_balance,
+    warp_to_timestamp, LAMPORTS_PER_SOL,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn close_meme_challenge_returns_creator_share() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let contributor = Keypair::new();
+    let submitter = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    contribute_to_challenge(&mut context, &contributor, challenge, LAMPORTS_PER_SOL / 2).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    let cancel_ix = ix::cancel_meme_challenge(creator.pubkey(), challenge);
+    process(&mut context, &[cancel_ix], &[&creator])
+        .await
+        .unwrap();
+
+    // The creator takes their share without waiting for contributors to claim theirs
+    let payer = context.payer.pubkey();
+    let close_ix = ix::close_meme_challenge(creator.pubkey(), challenge, payer, None);
+    let creator_before = balance(&mut context, creator.pubkey()).await;
+    process(&mut context, std::slice::from_ref(&close_ix), &[&creator])
+        .await
+        .unwrap();
+    assert_eq!(
+        balance(&mut context, creator.pubkey()).await - creator_before,
+        LAMPORTS_PER_SOL
+    );
+    assert!(account_exists(&mut context, challenge).await);
+
+    let contributor_before = balance(&mut context, contributor.pubkey()).await;
+    let refund_ix = ix::refund_contribution(contributor.pubkey(), challenge, 0, payer, None);
+    process(&mut context, &[refund_ix], &[&contributor])
+        .await
+        .unwrap();
+    assert_eq!(
+        balance(&mut context, contributor.pubkey()).await - contributor_before,
+        LAMPORTS_PER_SOL / 2
+    );
+
+    // The free entry still has to be closed before the challenge can be
+    process(&mut context, std::slice::from_ref(&close_ix), &[&creator])
+        .await
+        .unwrap();
+    assert!(account_exists(&mut context, challenge).await);
+    let refund_ix = ix::refund_entry_fee(
+        submitter.pubkey(),
+        challenge,
+        submitter.pubkey().to_bytes(),
+        payer,
+        None,
+    );
+    process(&mut context, &[refund_ix], &[&submitter])
+        .await
+        .unwrap();
+
+    // With nothing left owed, calling it again closes the challenge
+    let challenge_rent = balance(&mut context, challenge).await;
+    let creator_before = balance(&mut context, creator.pubkey()).await;
+    let payer_before = balance(&mut context, payer).await;
+    process(&mut context, &[close_ix], &[&creator])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        balance(&mut context, creator.pubkey()).await,
+        creator_before
+    );
+    assert!(balance(&mut context, payer).await > payer_before + challenge_rent / 2);
+    assert!(!account_exists(&mut context, challenge).await);
+}
+
+#[tokio::test]
+async fn close_meme_challenge_token_pool() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let contributor = Keypair::new();
+    let mint_authority = Keypair::new();
+    let mint = create_mint(&mut context, &mint_authority.pubkey()).await;
+    let creator_token_account = create_token_account(&mut context, &mint, &creator.pubkey()).await;
+    let contributor_token_account =
+        create_token_account(&mut context, &mint, &contributor.pubkey()).await;
+    mint_to(
+        &mut context,
+        &mint,
+        &creator_token_account,
+        &mint_authority,
+        500,
+    )
+    .await;
+    mint_to(
+        &mut context,
+        &mint,
+        &contributor_token_account,
+        &mint_authority,
+        200,
+    )
+    .await;
+    fund(&mut context, &creator.pubkey(), LAMPORTS_PER_SOL);
+    fund(&mut context, &contributor.pubkey(), LAMPORTS_PER_SOL);
+    let start_time = now(&mut context).await + 60;
+    let create_ix = ix::create_meme_challenge(
+        context.payer.pubkey(),
+        creator.pubkey(),
+        None,
+        Some(mint),
+        Some(creator_token_account),
+        instruction::CreateMemeChallenge {
+            reward_amount: 500,
+            ..challenge_args(start_time)
+        },
+    );
+    process(&mut context, &[create_ix], &[&creator])
+        .await
+        .unwrap();
+    let challenge = pda::meme_challenge(&creator.pubkey(), start_time);
+    let contribute_ix = ix::contribute_to_challenge(
+        context.payer.pubkey(),
+        contributor.pubkey(),
+        challenge,
+        0,
+        Some(contributor_token_account),
+        200,
+    );
+    process(&mut context, &[contribute_ix], &[&contributor])
+        .await
+        .unwrap();
+    let cancel_ix = ix::cancel_meme_challenge(creator.pubkey(), challenge);
+    process(&mut context, &[cancel_ix], &[&creator])
+        .await
+        .unwrap();
+
+    let payer = context.payer.pubkey();
+    let refund_ix = ix::refund_contribution(
+        contributor.pubkey(),
+        challenge,
+        0,
+        payer,
+        Some(contributor_token_account),
+    );
+    process(&mut context, &[refund_ix], &[&contributor])
+        .await
+        .unwrap();
+    let close_ix = ix::close_meme_challenge(
+        creator.pubkey(),
+        challenge,
+        payer,
+        Some(creator_token_account),
+    );
+    process(&mut context, &[close_ix], &[&creator])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        token_balance(&mut context, creator_token_account).await,
+        500
+    );
+    assert_eq!(
+        token_balance(&mut context, contributor_token_account).await,
+        200
+    );
+    assert!(!account_exists(&mut context, pda::challenge_vault(&challenge)).await);
+    assert!(!account_exists(&mut context, challenge).await);
+}
+
+#[tokio::test]
+async fn close_meme_challenge_leaves_entry_fees_owed() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let challenge = create_meme_challenge_with(&mut context, &creator, |args| {
+        instruction::CreateMemeChallenge {
+            entry_fee: LAMPORTS_PER_SOL / 10,
+            ..args
+        }
+    })
+    .await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    fund(&mut context, &submitter.pubkey(), LAMPORTS_PER_SOL);
+    submit_meme(&mut context, &submitter, challenge).await;
+    let cancel_ix = ix::cancel_meme_challenge(creator.pubkey(), challenge);
+    process(&mut context, &[cancel_ix], &[&creator])
+        .await
+        .unwrap();
+
+    let payer = context.payer.pubkey();
+    let close_ix = ix::close_meme_challenge(creator.pubkey(), challenge, payer, None);
+    process(&mut context, std::slice::from_ref(&close_ix), &[&creator])
+        .await
+        .unwrap();
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(challenge_account.reward_amount, LAMPORTS_PER_SOL / 10);
+
+    let submitter_before = balance(&mut context, submitter.pubkey()).await;
+    let refund_ix = ix::refund_entry_fee(
+        submitter.pubkey(),
+        challenge,
+        submitter.pubkey().to_bytes(),
+        payer,
+        None,
+    );
+    process(&mut context, &[refund_ix], &[&submitter])
+        .await
+        .unwrap();
+    assert_eq!(
+        balance(&mut context, submitter.pubkey()).await - submitter_before,
+        LAMPORTS_PER_SOL / 10
+    );
+    process(&mut context, &[close_ix], &[&creator])
+        .await
+        .unwrap();
+    assert!(!account_exists(&mut context, challenge).await);
+}
+
+#[tokio::test]
+async fn close_meme_challenge_waits_for_vote_tokens() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let cancel_ix = ix::cancel_meme_challenge(creator.pubkey(), challenge);
+    process(&mut context, &[cancel_ix], &[&creator])
+        .await
+        .unwrap();
+    let mut challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    challenge_account.open_vote_vaults = 1;
+    store(&mut context, challenge, &challenge_account).await;
+
+    let payer = context.payer.pubkey();
+    let close_ix = ix::close_meme_challenge(creator.pubkey(), challenge, payer, None);
+    process(&mut context, std::slice::from_ref(&close_ix), &[&creator])
+        .await
+        .unwrap();
+    assert!(account_exists(&mut context, challenge).await);
+
+    // Once the last voter has withdrawn their tokens the challenge closes
+    let mut challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    challenge_account.open_vote_vaults = 0;
+    store(&mut context, challenge, &challenge_account).await;
+    process(&mut context, &[close_ix], &[&creator])
+        .await
+        .unwrap();
+    assert!(!account_exists(&mut context, challenge).await);
+}
+
+#[tokio::test]
+async fn close_meme_challenge_requires_cancellation() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+
+    let payer = context.payer.pubkey();
+    let close_ix = ix::close_meme_challenge(creator.pubkey(), challenge, payer, None);
+    let result = process(&mut context, &[close_ix], &[&creator]).await;
+    assert_error(result, ErrorCode::ChallengeNotCancelled);
+}
+
+#[tokio::test]
+async fn close_meme_challenge_requires_creator() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let stranger = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let cancel_ix = ix::cancel_meme_challenge(creator.pubkey(), challenge);
+    process(&mut context, &[cancel_ix], &[&creator])
+        .await
+        .unwrap();
+
+    let payer = context.payer.pubkey();
+    let close_ix = ix::close_meme_challenge(stranger.pubkey(), challenge, payer, None);
+    let result = process(&mut context, &[close_ix], &[&stranger]).await;
+    assert_error(result, ErrorCode::NotGroupCreator);
+}
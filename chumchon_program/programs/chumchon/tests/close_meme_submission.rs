@@ -0,0 +1,70 @@
+mod common;
+
+use chumchon::{error::ErrorCode, MemeChallenge};
+use common::{
+    account_exists, assert_error, balance, create_meme_challenge, fetch, ix, pda, process, start,
+    submit_meme, warp_to_timestamp,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn close_meme_submission_after_completion() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    let submission = pda::meme_submission(&challenge, &submitter.pubkey());
+    let content_guard = pda::content_guard(&challenge, &submitter.pubkey().to_bytes());
+    let payer = context.payer.pubkey();
+
+    // The submission still competes until the challenge pays out
+    let content_hash = submitter.pubkey().to_bytes();
+    let close_ix = ix::close_meme_submission(submitter.pubkey(), challenge, content_hash, payer);
+    let result = process(&mut context, std::slice::from_ref(&close_ix), &[&submitter]).await;
+    assert_error(result, ErrorCode::ChallengeNotEnded);
+
+    warp_to_timestamp(&mut context, challenge_account.voting_end).await;
+    let end_ix = ix::end_meme_challenge(payer, challenge, payer, false, &[submitter.pubkey()]);
+    process(&mut context, &[end_ix], &[]).await.unwrap();
+
+    let submission_rent =
+        balance(&mut context, submission).await + balance(&mut context, content_guard).await;
+    let payer_before = balance(&mut context, payer).await;
+    process(&mut context, &[close_ix], &[&submitter])
+        .await
+        .unwrap();
+
+    // The rent of the submission and its guard goes back to whoever paid it
+    assert!(balance(&mut context, payer).await > payer_before + submission_rent / 2);
+    assert!(!account_exists(&mut context, submission).await);
+    assert!(!account_exists(&mut context, content_guard).await);
+}
+
+#[tokio::test]
+async fn close_meme_submission_requires_rent_payer() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let stranger = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.voting_end).await;
+    let payer = context.payer.pubkey();
+    let end_ix = ix::end_meme_challenge(payer, challenge, payer, false, &[submitter.pubkey()]);
+    process(&mut context, &[end_ix], &[]).await.unwrap();
+
+    let close_ix = ix::close_meme_submission(
+        submitter.pubkey(),
+        challenge,
+        submitter.pubkey().to_bytes(),
+        stranger.pubkey(),
+    );
+    let result = process(&mut context, &[close_ix], &[&submitter]).await;
+    assert_error(result, ErrorCode::NotOwner);
+}
@@ -0,0 +1,90 @@
+mod common;
+
+use chumchon::{error::ErrorCode, instruction, MemeChallenge, VotingMode};
+use common::{
+    account_exists, assert_error, balance, create_meme_challenge, create_meme_challenge_with,
+    create_mint, create_token_account, fetch, ix, mint_to, pda, process, start, submit_meme,
+    vote_for_meme, warp_to_timestamp,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn close_voter_record_after_results() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let voter = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+    vote_for_meme(&mut context, &voter, challenge, &submitter.pubkey()).await;
+    let voter_record = pda::voter_record(&challenge, &voter.pubkey());
+    let payer = context.payer.pubkey();
+
+    // The vote may still be moved or retracted until results are final
+    let close_ix = ix::close_voter_record(voter.pubkey(), challenge, payer);
+    let result = process(&mut context, std::slice::from_ref(&close_ix), &[&voter]).await;
+    assert_error(result, ErrorCode::ChallengeNotEnded);
+
+    warp_to_timestamp(&mut context, challenge_account.voting_end).await;
+    let record_rent = balance(&mut context, voter_record).await;
+    let payer_before = balance(&mut context, payer).await;
+    process(&mut context, &[close_ix], &[&voter]).await.unwrap();
+
+    // The record's rent goes back to whoever paid for it
+    assert!(balance(&mut context, payer).await > payer_before + record_rent / 2);
+    assert!(!account_exists(&mut context, voter_record).await);
+}
+
+#[tokio::test]
+async fn close_voter_record_rejects_token_weighted_votes() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let voter = Keypair::new();
+    let mint_authority = Keypair::new();
+    let mint = create_mint(&mut context, &mint_authority.pubkey()).await;
+    let voter_token_account = create_token_account(&mut context, &mint, &voter.pubkey()).await;
+    mint_to(
+        &mut context,
+        &mint,
+        &voter_token_account,
+        &mint_authority,
+        40,
+    )
+    .await;
+    let challenge = create_meme_challenge_with(&mut context, &creator, |args| {
+        instruction::CreateMemeChallenge {
+            voting_mode: VotingMode::TokenWeighted,
+            voting_mint: Some(mint),
+            ..args
+        }
+    })
+    .await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+    let vote_ix = ix::vote_for_meme_weighted(
+        context.payer.pubkey(),
+        voter.pubkey(),
+        challenge,
+        None,
+        submitter.pubkey(),
+        ix::VoteWeight {
+            voter_token_account: Some(voter_token_account),
+            voting_mint: Some(mint),
+            ..Default::default()
+        },
+    );
+    process(&mut context, &[vote_ix], &[&voter]).await.unwrap();
+    warp_to_timestamp(&mut context, challenge_account.voting_end).await;
+
+    // Closing the record here would strand the locked tokens
+    let close_ix = ix::close_voter_record(voter.pubkey(), challenge, context.payer.pubkey());
+    let result = process(&mut context, &[close_ix], &[&voter]).await;
+    assert_error(result, ErrorCode::InvalidVotingMode);
+}
@@ -0,0 +1,120 @@
+mod common;
+
+use chumchon::{error::ErrorCode, MemeChallenge, MemeSubmission, VoterRecord};
+use common::{
+    assert_error, create_commit_reveal_challenge, create_meme_challenge, fetch, ix, pda, process,
+    start, submit_meme, warp_to_timestamp,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn commit_meme_vote_success() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let voter = Keypair::new();
+    let challenge = create_commit_reveal_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    let submission = pda::meme_submission(&challenge, &submitter.pubkey());
+    let commitment = VoterRecord::commitment_for(&submission, &voter.pubkey(), &[7; 32]);
+
+    let commit_ix = ix::commit_meme_vote(
+        context.payer.pubkey(),
+        voter.pubkey(),
+        challenge,
+        None,
+        commitment,
+        ix::VoteWeight::default(),
+    );
+    let result = process(&mut context, std::slice::from_ref(&commit_ix), &[&voter]).await;
+    assert_error(result, ErrorCode::VotingNotOpen);
+
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+    process(&mut context, &[commit_ix], &[&voter])
+        .await
+        .unwrap();
+
+    let record: VoterRecord =
+        fetch(&mut context, pda::voter_record(&challenge, &voter.pubkey())).await;
+    assert_eq!(record.challenge, challenge);
+    assert_eq!(record.voter, voter.pubkey());
+    assert_eq!(record.commitment, Some(commitment));
+    assert!(!record.revealed);
+
+    // The commitment stays hidden from the counts
+    let submission_account: MemeSubmission = fetch(&mut context, submission).await;
+    assert_eq!(submission_account.votes, 0);
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(challenge_account.total_votes, 0);
+}
+
+#[tokio::test]
+async fn commit_meme_vote_requires_commit_reveal_challenge() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let voter = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+
+    let commit_ix = ix::commit_meme_vote(
+        context.payer.pubkey(),
+        voter.pubkey(),
+        challenge,
+        None,
+        [0; 32],
+        ix::VoteWeight::default(),
+    );
+    let result = process(&mut context, &[commit_ix], &[&voter]).await;
+    assert_error(result, ErrorCode::InvalidVotingMode);
+}
+
+#[tokio::test]
+async fn commit_meme_vote_requires_submissions() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let voter = Keypair::new();
+    let challenge = create_commit_reveal_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+
+    let commit_ix = ix::commit_meme_vote(
+        context.payer.pubkey(),
+        voter.pubkey(),
+        challenge,
+        None,
+        [0; 32],
+        ix::VoteWeight::default(),
+    );
+    let result = process(&mut context, &[commit_ix], &[&voter]).await;
+    assert_error(result, ErrorCode::NoSubmissions);
+}
+
+#[tokio::test]
+async fn commit_reveal_challenge_rejects_open_votes() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let voter = Keypair::new();
+    let challenge = create_commit_reveal_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+
+    let vote_ix = ix::vote_for_meme(
+        context.payer.pubkey(),
+        voter.pubkey(),
+        challenge,
+        None,
+        submitter.pubkey(),
+    );
+    let result = process(&mut context, &[vote_ix], &[&voter]).await;
+    assert_error(result, ErrorCode::InvalidVotingMode);
+}
@@ -0,0 +1,1028 @@
+use anchor_lang::{
+    prelude::Pubkey,
+    solana_program::instruction::{AccountMeta, Instruction},
+    system_program, InstructionData, ToAccountMetas,
+};
+use chumchon::{accounts, instruction, GroupRole};
+
+use super::pda;
+
+fn build(accounts: impl ToAccountMetas, args: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: chumchon::ID,
+        accounts: accounts.to_account_metas(None),
+        data: args.data(),
+    }
+}
+
+pub fn create_user_profile(
+    fee_payer: Pubkey,
+    owner: Pubkey,
+    args: instruction::CreateUserProfile,
+) -> Instruction {
+    build(
+        accounts::CreateUserProfile {
+            fee_payer,
+            profile: pda::user_profile(&owner),
+            owner,
+            system_program: system_program::ID,
+        },
+        args,
+    )
+}
+
+pub fn update_user_profile(
+    fee_payer: Pubkey,
+    owner: Pubkey,
+    args: instruction::UpdateUserProfile,
+) -> Instruction {
+    build(
+        accounts::UpdateUserProfile {
+            fee_payer,
+            profile: pda::user_profile(&owner),
+            owner,
+            system_program: system_program::ID,
+        },
+        args,
+    )
+}
+
+pub fn set_profile_nft(
+    fee_payer: Pubkey,
+    owner: Pubkey,
+    nft_token_account: Pubkey,
+    nft_mint: Pubkey,
+) -> Instruction {
+    build(
+        accounts::SetProfileNft {
+            fee_payer,
+            profile: pda::user_profile(&owner),
+            owner,
+            nft_token_account,
+            nft_mint,
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::SetProfileNft { nft_mint },
+    )
+}
+
+pub fn complete_tutorial(fee_payer: Pubkey, user: Pubkey, tutorial_id: u8) -> Instruction {
+    build(
+        accounts::CompleteTutorial {
+            fee_payer,
+            profile: pda::user_profile(&user),
+            user,
+            system_program: system_program::ID,
+        },
+        instruction::CompleteTutorial { tutorial_id },
+    )
+}
+
+pub fn create_group(
+    fee_payer: Pubkey,
+    creator: Pubkey,
+    args: instruction::CreateGroup,
+) -> Instruction {
+    build(
+        accounts::CreateGroup {
+            fee_payer,
+            creator,
+            group: pda::group(&args.name, &creator),
+            system_program: system_program::ID,
+        },
+        args,
+    )
+}
+
+pub fn update_group(
+    authority: Pubkey,
+    group: Pubkey,
+    authority_is_member: bool,
+    args: instruction::UpdateGroup,
+) -> Instruction {
+    build(
+        accounts::UpdateGroup {
+            authority,
+            group,
+            authority_member: authority_is_member.then(|| pda::group_member(&group, &authority)),
+        },
+        args,
+    )
+}
+
+/// Offers the group to `new_owner`, or withdraws the offer when None.
+pub fn transfer_group_ownership(
+    owner: Pubkey,
+    group: Pubkey,
+    new_owner: Option<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::TransferGroupOwnership { owner, group },
+        instruction::TransferGroupOwnership { new_owner },
+    )
+}
+
+pub fn accept_group_ownership(new_owner: Pubkey, group: Pubkey) -> Instruction {
+    build(
+        accounts::AcceptGroupOwnership { new_owner, group },
+        instruction::AcceptGroupOwnership {},
+    )
+}
+
+pub fn join_group(
+    fee_payer: Pubkey,
+    member: Pubkey,
+    group: Pubkey,
+    member_token_account: Option<Pubkey>,
+    member_nft_account: Option<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::JoinGroup {
+            fee_payer,
+            member,
+            group,
+            member_record: pda::group_member(&group, &member),
+            ban_record: pda::group_ban(&group, &member),
+            member_token_account,
+            member_nft_account,
+            system_program: system_program::ID,
+        },
+        instruction::JoinGroup {},
+    )
+}
+
+pub fn leave_group(member: Pubkey, group: Pubkey, rent_payer: Pubkey) -> Instruction {
+    build(
+        accounts::LeaveGroup {
+            member,
+            group,
+            member_record: pda::group_member(&group, &member),
+            rent_payer,
+        },
+        instruction::LeaveGroup {},
+    )
+}
+
+/// An invite from the group's creator, who needs no membership record.
+pub fn create_invite(
+    fee_payer: Pubkey,
+    creator: Pubkey,
+    group: Pubkey,
+    args: instruction::CreateInvite,
+) -> Instruction {
+    create_invite_as(fee_payer, creator, group, false, args)
+}
+
+/// `is_member` passes the issuer's membership record, which admins need.
+pub fn create_invite_as(
+    fee_payer: Pubkey,
+    creator: Pubkey,
+    group: Pubkey,
+    is_member: bool,
+    args: instruction::CreateInvite,
+) -> Instruction {
+    build(
+        accounts::CreateInvite {
+            fee_payer,
+            invite: pda::invite(&group, &args.code),
+            group,
+            creator,
+            creator_member: is_member.then(|| pda::group_member(&group, &creator)),
+            system_program: system_program::ID,
+        },
+        args,
+    )
+}
+
+/// `authority_is_member` passes the authority's membership record, which all but the creator need.
+pub fn add_group_admin(
+    authority: Pubkey,
+    group: Pubkey,
+    authority_is_member: bool,
+    member: Pubkey,
+    role: GroupRole,
+) -> Instruction {
+    build(
+        accounts::AddGroupAdmin {
+            authority,
+            group,
+            authority_member: authority_is_member.then(|| pda::group_member(&group, &authority)),
+            member_record: pda::group_member(&group, &member),
+        },
+        instruction::AddGroupAdmin { role },
+    )
+}
+
+pub fn remove_group_admin(
+    authority: Pubkey,
+    group: Pubkey,
+    authority_is_member: bool,
+    member: Pubkey,
+) -> Instruction {
+    build(
+        accounts::RemoveGroupAdmin {
+            authority,
+            group,
+            authority_member: authority_is_member.then(|| pda::group_member(&group, &authority)),
+            member_record: pda::group_member(&group, &member),
+        },
+        instruction::RemoveGroupAdmin {},
+    )
+}
+
+pub fn kick_member(
+    authority: Pubkey,
+    group: Pubkey,
+    authority_is_member: bool,
+    member: Pubkey,
+    rent_payer: Pubkey,
+) -> Instruction {
+    build(
+        accounts::KickMember {
+            authority,
+            group,
+            authority_member: authority_is_member.then(|| pda::group_member(&group, &authority)),
+            member_record: pda::group_member(&group, &member),
+            rent_payer,
+        },
+        instruction::KickMember {},
+    )
+}
+
+/// `rent_payer` gets back the membership rent of a member still in the group; None once they have left.
+pub fn ban_member(
+    fee_payer: Pubkey,
+    authority: Pubkey,
+    group: Pubkey,
+    authority_is_member: bool,
+    member: Pubkey,
+    rent_payer: Option<Pubkey>,
+    expires_at: Option<i64>,
+) -> Instruction {
+    build(
+        accounts::BanMember {
+            fee_payer,
+            authority,
+            group,
+            authority_member: authority_is_member.then(|| pda::group_member(&group, &authority)),
+            member_record: pda::group_member(&group, &member),
+            rent_payer,
+            ban_record: pda::group_ban(&group, &member),
+            system_program: system_program::ID,
+        },
+        instruction::BanMember {
+            member,
+            reason: 1,
+            expires_at,
+        },
+    )
+}
+
+pub fn unban_member(
+    authority: Pubkey,
+    group: Pubkey,
+    authority_is_member: bool,
+    member: Pubkey,
+    rent_payer: Pubkey,
+) -> Instruction {
+    build(
+        accounts::UnbanMember {
+            authority,
+            group,
+            authority_member: authority_is_member.then(|| pda::group_member(&group, &authority)),
+            ban_record: pda::group_ban(&group, &member),
+            rent_payer,
+        },
+        instruction::UnbanMember {},
+    )
+}
+
+pub fn use_invite(fee_payer: Pubkey, member: Pubkey, group: Pubkey, code: &str) -> Instruction {
+    build(
+        accounts::UseInvite {
+            fee_payer,
+            member,
+            invite: pda::invite(&group, code),
+            group,
+            member_record: pda::group_member(&group, &member),
+            ban_record: pda::group_ban(&group, &member),
+            system_program: system_program::ID,
+        },
+        instruction::UseInvite {
+            invite_code: code.to_string(),
+        },
+    )
+}
+
+pub fn send_message(
+    fee_payer: Pubkey,
+    sender: Pubkey,
+    group: Pubkey,
+    message_id: u64,
+    content: &str,
+) -> Instruction {
+    send_message_as(fee_payer, sender, group, true, message_id, content)
+}
+
+/// `is_member` passes the sender's membership record, which all but the owner need.
+pub fn send_message_as(
+    fee_payer: Pubkey,
+    sender: Pubkey,
+    group: Pubkey,
+    is_member: bool,
+    message_id: u64,
+    content: &str,
+) -> Instruction {
+    build(
+        accounts::SendMessage {
+            fee_payer,
+            sender,
+            group,
+            member_record: is_member.then(|| pda::group_member(&group, &sender)),
+            message: pda::message(&group, message_id),
+            system_program: system_program::ID,
+        },
+        instruction::SendMessage {
+            content: content.to_string(),
+        },
+    )
+}
+
+pub fn tip_message(
+    tipper: Pubkey,
+    group: Pubkey,
+    message_id: u64,
+    recipient: Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        accounts::TipMessage {
+            tipper,
+            message: pda::message(&group, message_id),
+            group,
+            recipient,
+            system_program: system_program::ID,
+        },
+        instruction::TipMessage { message_id, amount },
+    )
+}
+
+pub fn create_escrow(
+    fee_payer: Pubkey,
+    initiator: Pubkey,
+    group: Pubkey,
+    initiator_mint: Option<Pubkey>,
+    initiator_token_account: Option<Pubkey>,
+    args: instruction::CreateEscrow,
+) -> Instruction {
+    let escrow = pda::escrow(&initiator, args.created_at);
+    build(
+        accounts::CreateEscrow {
+            fee_payer,
+            initiator,
+            escrow,
+            group,
+            initiator_member: pda::group_member(&group, &initiator),
+            counterparty_member: pda::group_member(&group, &args.counterparty),
+            initiator_mint,
+            initiator_token_account,
+            initiator_vault: initiator_mint.map(|_| pda::initiator_vault(&escrow)),
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+        },
+        args,
+    )
+}
+
+pub fn accept_escrow(
+    fee_payer: Pubkey,
+    counterparty: Pubkey,
+    initiator: Pubkey,
+    group: Pubkey,
+    created_at: i64,
+    counterparty_mint: Option<Pubkey>,
+    counterparty_token_account: Option<Pubkey>,
+) -> Instruction {
+    let escrow = pda::escrow(&initiator, created_at);
+    build(
+        accounts::AcceptEscrow {
+            fee_payer,
+            escrow,
+            counterparty,
+            counterparty_member: pda::group_member(&group, &counterparty),
+            counterparty_mint,
+            counterparty_token_account,
+            counterparty_vault: counterparty_mint.map(|_| pda::counterparty_vault(&escrow)),
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::AcceptEscrow { created_at },
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn complete_escrow(
+    fee_payer: Pubkey,
+    authority: Pubkey,
+    initiator: Pubkey,
+    counterparty: Pubkey,
+    created_at: i64,
+    rent_payer: Pubkey,
+    initiator_receive_account: Option<Pubkey>,
+    counterparty_receive_account: Option<Pubkey>,
+    initiator_refund_account: Option<Pubkey>,
+    counterparty_refund_account: Option<Pubkey>,
+) -> Instruction {
+    let escrow = pda::escrow(&initiator, created_at);
+    build(
+        accounts::CompleteEscrow {
+            fee_payer,
+            escrow,
+            authority,
+            initiator,
+            counterparty,
+            initiator_rent_payer: rent_payer,
+            counterparty_rent_payer: rent_payer,
+            initiator_vault: counterparty_receive_account.map(|_| pda::initiator_vault(&escrow)),
+            counterparty_vault: initiator_receive_account.map(|_| pda::counterparty_vault(&escrow)),
+            initiator_receive_account,
+            counterparty_receive_account,
+            initiator_refund_account,
+            counterparty_refund_account,
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::CompleteEscrow { created_at },
+    )
+}
+
+pub fn cancel_escrow(
+    fee_payer: Pubkey,
+    initiator: Pubkey,
+    escrow_initiator: Pubkey,
+    created_at: i64,
+    initiator_rent_payer: Pubkey,
+    initiator_refund_account: Option<Pubkey>,
+) -> Instruction {
+    let escrow = pda::escrow(&escrow_initiator, created_at);
+    build(
+        accounts::CancelEscrow {
+            fee_payer,
+            escrow,
+            initiator,
+            initiator_rent_payer,
+            initiator_vault: initiator_refund_account.map(|_| pda::initiator_vault(&escrow)),
+            initiator_refund_account,
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::CancelEscrow { created_at },
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn reclaim_expired_escrow(
+    fee_payer: Pubkey,
+    initiator: Pubkey,
+    counterparty: Pubkey,
+    created_at: i64,
+    initiator_rent_payer: Pubkey,
+    initiator_refund_account: Option<Pubkey>,
+    counterparty_rent_payer: Option<Pubkey>,
+    counterparty_refund_account: Option<Pubkey>,
+) -> Instruction {
+    let escrow = pda::escrow(&initiator, created_at);
+    build(
+        accounts::ReclaimExpiredEscrow {
+            fee_payer,
+            escrow,
+            initiator,
+            counterparty,
+            initiator_rent_payer,
+            initiator_vault: initiator_refund_account.map(|_| pda::initiator_vault(&escrow)),
+            initiator_refund_account,
+            counterparty_rent_payer,
+            counterparty_vault: counterparty_refund_account
+                .map(|_| pda::counterparty_vault(&escrow)),
+            counterparty_refund_account,
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::ReclaimExpiredEscrow { created_at },
+    )
+}
+
+pub fn dispute_escrow(authority: Pubkey, initiator: Pubkey, created_at: i64) -> Instruction {
+    build(
+        accounts::DisputeEscrow {
+            escrow: pda::escrow(&initiator, created_at),
+            authority,
+        },
+        instruction::DisputeEscrow { created_at },
+    )
+}
+
+/// Token legs pass their receive accounts, used when settling, and refund
+/// accounts, used when cancelling; native SOL legs pass None.
+#[allow(clippy::too_many_arguments)]
+pub fn resolve_escrow_dispute(
+    authority: Pubkey,
+    group: Pubkey,
+    authority_is_member: bool,
+    initiator: Pubkey,
+    counterparty: Pubkey,
+    created_at: i64,
+    rent_payer: Pubkey,
+    initiator_receive_account: Option<Pubkey>,
+    counterparty_receive_account: Option<Pubkey>,
+    initiator_refund_account: Option<Pubkey>,
+    counterparty_refund_account: Option<Pubkey>,
+    settle: bool,
+) -> Instruction {
+    let escrow = pda::escrow(&initiator, created_at);
+    build(
+        accounts::ResolveEscrowDispute {
+            escrow,
+            authority,
+            group,
+            authority_member: authority_is_member.then(|| pda::group_member(&group, &authority)),
+            initiator,
+            counterparty,
+            initiator_rent_payer: rent_payer,
+            counterparty_rent_payer: rent_payer,
+            initiator_vault: initiator_refund_account.map(|_| pda::initiator_vault(&escrow)),
+            counterparty_vault: counterparty_refund_account
+                .map(|_| pda::counterparty_vault(&escrow)),
+            initiator_receive_account,
+            counterparty_receive_account,
+            initiator_refund_account,
+            counterparty_refund_account,
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::ResolveEscrowDispute { created_at, settle },
+    )
+}
+
+pub fn create_meme_challenge(
+    fee_payer: Pubkey,
+    creator: Pubkey,
+    group: Option<Pubkey>,
+    reward_mint: Option<Pubkey>,
+    creator_token_account: Option<Pubkey>,
+    args: instruction::CreateMemeChallenge,
+) -> Instruction {
+    meme_challenge_with_member(
+        fee_payer,
+        creator,
+        group,
+        false,
+        reward_mint,
+        creator_token_account,
+        args,
+    )
+}
+
+/// A SOL-pool challenge attached to `group` by a group admin, who passes their membership.
+pub fn create_group_admin_meme_challenge(
+    fee_payer: Pubkey,
+    creator: Pubkey,
+    group: Pubkey,
+    args: instruction::CreateMemeChallenge,
+) -> Instruction {
+    meme_challenge_with_member(fee_payer, creator, Some(group), true, None, None, args)
+}
+
+fn meme_challenge_with_member(
+    fee_payer: Pubkey,
+    creator: Pubkey,
+    group: Option<Pubkey>,
+    is_member: bool,
+    reward_mint: Option<Pubkey>,
+    creator_token_account: Option<Pubkey>,
+    args: instruction::CreateMemeChallenge,
+) -> Instruction {
+    let challenge = pda::meme_challenge(&creator, args.start_time);
+    build(
+        accounts::CreateMemeChallenge {
+            fee_payer,
+            creator,
+            challenge,
+            group,
+            creator_member: group
+                .filter(|_| is_member)
+                .map(|group| pda::group_member(&group, &creator)),
+            reward_mint,
+            creator_token_account,
+            reward_vault: reward_mint.map(|_| pda::challenge_vault(&challenge)),
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+        },
+        args,
+    )
+}
+
+pub fn submit_meme(
+    fee_payer: Pubkey,
+    submitter: Pubkey,
+    challenge: Pubkey,
+    group: Option<Pubkey>,
+    args: instruction::SubmitMeme,
+) -> Instruction {
+    submit_meme_paying(fee_payer, submitter, challenge, group, None, args)
+}
+
+/// `token_account` pays the entry fee of a token pool.
+pub fn submit_meme_paying(
+    fee_payer: Pubkey,
+    submitter: Pubkey,
+    challenge: Pubkey,
+    group: Option<Pubkey>,
+    token_account: Option<Pubkey>,
+    args: instruction::SubmitMeme,
+) -> Instruction {
+    build(
+        accounts::SubmitMeme {
+            fee_payer,
+            submitter,
+            challenge,
+            member_record: group.map(|group| pda::group_member(&group, &submitter)),
+            submission: pda::meme_submission(&challenge, &submitter),
+            content_guard: pda::content_guard(&challenge, &args.content_hash),
+            submitter_token_account: token_account,
+            reward_vault: token_account.map(|_| pda::challenge_vault(&challenge)),
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+        },
+        args,
+    )
+}
+
+/// Accounts and vote count that weigh a vote; the default suits a one-per-wallet challenge.
+#[derive(Clone, Copy)]
+pub struct VoteWeight {
+    pub votes: u32,
+    pub with_profile: bool,
+    pub voter_token_account: Option<Pubkey>,
+    /// Set in `TokenWeighted` challenges, whose votes lock tokens of this mint
+    pub voting_mint: Option<Pubkey>,
+    pub token_pool: bool,
+}
+
+impl Default for VoteWeight {
+    fn default() -> Self {
+        Self {
+            votes: 1,
+            with_profile: false,
+            voter_token_account: None,
+            voting_mint: None,
+            token_pool: false,
+        }
+    }
+}
+
+pub fn vote_for_meme(
+    fee_payer: Pubkey,
+    voter: Pubkey,
+    challenge: Pubkey,
+    group: Option<Pubkey>,
+    submitter: Pubkey,
+) -> Instruction {
+    vote_for_meme_weighted(
+        fee_payer,
+        voter,
+        challenge,
+        group,
+        submitter,
+        VoteWeight::default(),
+    )
+}
+
+pub fn vote_for_meme_weighted(
+    fee_payer: Pubkey,
+    voter: Pubkey,
+    challenge: Pubkey,
+    group: Option<Pubkey>,
+    submitter: Pubkey,
+    weight: VoteWeight,
+) -> Instruction {
+    let submission = pda::meme_submission(&challenge, &submitter);
+    build(
+        accounts::VoteForMeme {
+            fee_payer,
+            voter,
+            submission,
+            challenge,
+            submitter,
+            member_record: group.map(|group| pda::group_member(&group, &voter)),
+            voter_record: pda::voter_record(&challenge, &voter),
+            voter_profile: weight.with_profile.then(|| pda::user_profile(&voter)),
+            voter_token_account: weight.voter_token_account,
+            voting_mint: weight.voting_mint,
+            vote_vault: weight
+                .voting_mint
+                .map(|_| pda::vote_vault(&challenge, &voter)),
+            reward_vault: weight.token_pool.then(|| pda::challenge_vault(&challenge)),
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::VoteForMeme {
+            votes: weight.votes,
+        },
+    )
+}
+
+pub fn commit_meme_vote(
+    fee_payer: Pubkey,
+    voter: Pubkey,
+    challenge: Pubkey,
+    group: Option<Pubkey>,
+    commitment: [u8; 32],
+    weight: VoteWeight,
+) -> Instruction {
+    build(
+        accounts::CommitMemeVote {
+            fee_payer,
+            voter,
+            challenge,
+            member_record: group.map(|group| pda::group_member(&group, &voter)),
+            voter_record: pda::voter_record(&challenge, &voter),
+            voter_profile: weight.with_profile.then(|| pda::user_profile(&voter)),
+            voter_token_account: weight.voter_token_account,
+            voting_mint: weight.voting_mint,
+            vote_vault: weight
+                .voting_mint
+                .map(|_| pda::vote_vault(&challenge, &voter)),
+            reward_vault: weight.token_pool.then(|| pda::challenge_vault(&challenge)),
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::CommitMemeVote {
+            commitment,
+            votes: weight.votes,
+        },
+    )
+}
+
+pub fn reveal_meme_vote(
+    voter: Pubkey,
+    challenge: Pubkey,
+    submitter: Pubkey,
+    salt: [u8; 32],
+) -> Instruction {
+    build(
+        accounts::RevealMemeVote {
+            voter,
+            challenge,
+            submission: pda::meme_submission(&challenge, &submitter),
+            voter_record: pda::voter_record(&challenge, &voter),
+        },
+        instruction::RevealMemeVote { salt },
+    )
+}
+
+/// Moves `voter`'s vote from `submitter`'s meme to `new_submitter`'s, or retracts it when None.
+/// `rent_payer` is whoever paid for the vote.
+pub fn change_meme_vote(
+    voter: Pubkey,
+    rent_payer: Pubkey,
+    challenge: Pubkey,
+    group: Option<Pubkey>,
+    submitter: Pubkey,
+    new_submitter: Option<Pubkey>,
+) -> Instruction {
+    change_meme_vote_weighted(
+        voter,
+        rent_payer,
+        challenge,
+        group,
+        submitter,
+        new_submitter,
+        None,
+    )
+}
+
+/// Like `change_meme_vote`; a retracted `TokenWeighted` vote returns its tokens to `voter_token_account`.
+pub fn change_meme_vote_weighted(
+    voter: Pubkey,
+    rent_payer: Pubkey,
+    challenge: Pubkey,
+    group: Option<Pubkey>,
+    submitter: Pubkey,
+    new_submitter: Option<Pubkey>,
+    voter_token_account: Option<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::ChangeMemeVote {
+            voter,
+            challenge,
+            voter_record: pda::voter_record(&challenge, &voter),
+            rent_payer,
+            submission: pda::meme_submission(&challenge, &submitter),
+            new_submission: new_submitter
+                .map(|new_submitter| pda::meme_submission(&challenge, &new_submitter)),
+            member_record: group.map(|group| pda::group_member(&group, &voter)),
+            vote_vault: voter_token_account.map(|_| pda::vote_vault(&challenge, &voter)),
+            voter_token_account,
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::ChangeMemeVote {},
+    )
+}
+
+pub fn withdraw_vote_tokens(
+    voter: Pubkey,
+    rent_payer: Pubkey,
+    challenge: Pubkey,
+    voter_token_account: Pubkey,
+) -> Instruction {
+    build(
+        accounts::WithdrawVoteTokens {
+            voter,
+            challenge,
+            voter_record: pda::voter_record(&challenge, &voter),
+            rent_payer,
+            vote_vault: Some(pda::vote_vault(&challenge, &voter)),
+            voter_token_account: Some(voter_token_account),
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::WithdrawVoteTokens {},
+    )
+}
+
+pub fn rank_meme_submission(challenge: Pubkey, submitter: Pubkey) -> Instruction {
+    build(
+        accounts::RankMemeSubmission {
+            challenge,
+            submission: pda::meme_submission(&challenge, &submitter),
+        },
+        instruction::RankMemeSubmission {},
+    )
+}
+
+/// `token_account` pays into, or is refunded from, a token pool; None for a SOL pool.
+pub fn contribute_to_challenge(
+    fee_payer: Pubkey,
+    contributor: Pubkey,
+    challenge: Pubkey,
+    contribution_id: u32,
+    token_account: Option<Pubkey>,
+    amount: u64,
+) -> Instruction {
+    build(
+        accounts::ContributeToChallenge {
+            fee_payer,
+            contributor,
+            challenge,
+            contribution: pda::challenge_contribution(&challenge, contribution_id),
+            contributor_token_account: token_account,
+            reward_vault: token_account.map(|_| pda::challenge_vault(&challenge)),
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::ContributeToChallenge { amount },
+    )
+}
+
+pub fn refund_contribution(
+    contributor: Pubkey,
+    challenge: Pubkey,
+    contribution_id: u32,
+    rent_payer: Pubkey,
+    token_account: Option<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::RefundContribution {
+            contributor,
+            challenge,
+            contribution: pda::challenge_contribution(&challenge, contribution_id),
+            rent_payer,
+            contributor_token_account: token_account,
+            reward_vault: token_account.map(|_| pda::challenge_vault(&challenge)),
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::RefundContribution {},
+    )
+}
+
+/// `content_hash` is the one the submission was entered with.
+pub fn refund_entry_fee(
+    submitter: Pubkey,
+    challenge: Pubkey,
+    content_hash: [u8; 32],
+    rent_payer: Pubkey,
+    token_account: Option<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::RefundEntryFee {
+            submitter,
+            challenge,
+            submission: pda::meme_submission(&challenge, &submitter),
+            content_guard: pda::content_guard(&challenge, &content_hash),
+            rent_payer,
+            submitter_token_account: token_account,
+            reward_vault: token_account.map(|_| pda::challenge_vault(&challenge)),
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::RefundEntryFee {},
+    )
+}
+
+/// `content_hash` is the one the submission was entered with.
+pub fn close_meme_submission(
+    submitter: Pubkey,
+    challenge: Pubkey,
+    content_hash: [u8; 32],
+    rent_payer: Pubkey,
+) -> Instruction {
+    build(
+        accounts::CloseMemeSubmission {
+            submitter,
+            challenge,
+            submission: pda::meme_submission(&challenge, &submitter),
+            content_guard: pda::content_guard(&challenge, &content_hash),
+            rent_payer,
+        },
+        instruction::CloseMemeSubmission {},
+    )
+}
+
+pub fn close_challenge_contribution(
+    contributor: Pubkey,
+    challenge: Pubkey,
+    contribution_id: u32,
+    rent_payer: Pubkey,
+) -> Instruction {
+    build(
+        accounts::CloseChallengeContribution {
+            contributor,
+            challenge,
+            contribution: pda::challenge_contribution(&challenge, contribution_id),
+            rent_payer,
+        },
+        instruction::CloseChallengeContribution {},
+    )
+}
+
+pub fn close_voter_record(voter: Pubkey, challenge: Pubkey, rent_payer: Pubkey) -> Instruction {
+    build(
+        accounts::CloseVoterRecord {
+            voter,
+            challenge,
+            voter_record: pda::voter_record(&challenge, &voter),
+            rent_payer,
+        },
+        instruction::CloseVoterRecord {},
+    )
+}
+
+pub fn cancel_meme_challenge(creator: Pubkey, challenge: Pubkey) -> Instruction {
+    build(
+        accounts::CancelMemeChallenge { challenge, creator },
+        instruction::CancelMemeChallenge {},
+    )
+}
+
+/// `creator_token_account` takes back the rest of a token pool.
+pub fn close_meme_challenge(
+    creator: Pubkey,
+    challenge: Pubkey,
+    rent_payer: Pubkey,
+    creator_token_account: Option<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::CloseMemeChallenge {
+            challenge,
+            creator,
+            rent_payer,
+            reward_vault: creator_token_account.map(|_| pda::challenge_vault(&challenge)),
+            creator_token_account,
+            token_program: anchor_spl::token::ID,
+        },
+        instruction::CloseMemeChallenge {},
+    )
+}
+
+/// `recipients` are the paid places in order: wallets for a SOL pool, token accounts otherwise.
+pub fn end_meme_challenge(
+    fee_payer: Pubkey,
+    challenge: Pubkey,
+    rent_payer: Pubkey,
+    token_pool: bool,
+    recipients: &[Pubkey],
+) -> Instruction {
+    let mut instruction = build(
+        accounts::EndMemeChallenge {
+            fee_payer,
+            challenge,
+            rent_payer,
+            reward_vault: token_pool.then(|| pda::challenge_vault(&challenge)),
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::EndMemeChallenge {},
+    );
+    instruction.accounts.extend(
+        recipients
+            .iter()
+            .map(|recipient| AccountMeta::new(*recipient, false)),
+    );
+    instruction
+}
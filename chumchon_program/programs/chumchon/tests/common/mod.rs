@@ -1,45 +1,701 @@
+#![allow(dead_code)]
+
+pub mod ix;
+pub mod pda;
+pub mod token;
+
+use anchor_lang::{
+    prelude::{AccountInfo, Clock, Pubkey},
+    solana_program::entrypoint::ProgramResult,
+    system_program, AccountDeserialize, AccountSerialize,
+};
+use anchor_spl::token::spl_token;
+use chumchon::{instruction, Group, GroupRole, MemeChallenge, VoterRecord, VotingMode};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
 use solana_sdk::{
-    signer::keypair::Keypair,
-    pubkey::Pubkey,
-    hash::Hash,
-    transaction::Transaction,
-    instruction::AccountMeta,
-    signature::Signer,
+    account::{Account, AccountSharedData},
+    instruction::{Instruction, InstructionError},
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
 };
 
-pub fn submit_meme_ix_setup(
-    fee_payer: &Keypair,
+pub const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+// Anchor's generated entrypoint ties the account slice and the account infos to a single
+// lifetime, which `processor!` cannot express, so the slice is leaked for the test's lifetime.
+fn process_instruction<'a, 'b, 'c, 'd>(
+    program_id: &'a Pubkey,
+    accounts: &'b [AccountInfo<'c>],
+    data: &'d [u8],
+) -> ProgramResult {
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    chumchon::entry(program_id, accounts, data)
+}
+
+pub fn program_test() -> ProgramTest {
+    ProgramTest::new("chumchon", chumchon::ID, processor!(process_instruction))
+}
+
+pub async fn start() -> ProgramTestContext {
+    program_test().start_with_context().await
+}
+
+/// Signs with the context payer plus `signers` and processes `instructions` in one transaction.
+pub async fn process(
+    context: &mut ProgramTestContext,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let blockhash = context.get_new_latest_blockhash().await.unwrap();
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+    let transaction = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        blockhash,
+    );
+    context.banks_client.process_transaction(transaction).await
+}
+
+pub fn assert_error(
+    result: Result<(), BanksClientError>,
+    expected: impl Into<u32> + std::fmt::Debug + Copy,
+) {
+    match result.expect_err("transaction should have failed").unwrap() {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => {
+            assert_eq!(code, expected.into(), "expected {expected:?}")
+        }
+        err => panic!("expected {expected:?}, got {err:?}"),
+    }
+}
+
+pub async fn fetch<T: AccountDeserialize>(context: &mut ProgramTestContext, address: Pubkey) -> T {
+    let account = context
+        .banks_client
+        .get_account(address)
+        .await
+        .unwrap()
+        .expect("account does not exist");
+    T::try_deserialize(&mut account.data.as_slice()).unwrap()
+}
+
+/// Overwrites a program account's data, for states no sequence of instructions reaches
+pub async fn store<T: AccountSerialize>(
+    context: &mut ProgramTestContext,
+    address: Pubkey,
+    value: &T,
+) {
+    let mut account = context
+        .banks_client
+        .get_account(address)
+        .await
+        .unwrap()
+        .expect("account does not exist");
+    let mut data = Vec::new();
+    value.try_serialize(&mut data).unwrap();
+    account.data[..data.len()].copy_from_slice(&data);
+    context.set_account(&address, &account.into());
+}
+
+pub async fn account_exists(context: &mut ProgramTestContext, address: Pubkey) -> bool {
+    context
+        .banks_client
+        .get_account(address)
+        .await
+        .unwrap()
+        .is_some()
+}
+
+pub async fn balance(context: &mut ProgramTestContext, address: Pubkey) -> u64 {
+    context.banks_client.get_balance(address).await.unwrap()
+}
+
+pub fn fund(context: &mut ProgramTestContext, address: &Pubkey, lamports: u64) {
+    context.set_account(
+        address,
+        &AccountSharedData::new(lamports, 0, &system_program::ID),
+    );
+}
+
+async fn set_token_program_account(
+    context: &mut ProgramTestContext,
+    address: &Pubkey,
+    data: Vec<u8>,
+) {
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let account = Account {
+        lamports: rent.minimum_balance(data.len()),
+        data,
+        owner: spl_token::ID,
+        executable: false,
+        rent_epoch: 0,
+    };
+    context.set_account(address, &account.into());
+}
+
+pub async fn create_mint(context: &mut ProgramTestContext, authority: &Pubkey) -> Pubkey {
+    let mint = Pubkey::new_unique();
+    set_token_program_account(context, &mint, token::mint_data(authority, 6)).await;
+    mint
+}
+
+pub async fn create_token_account(
+    context: &mut ProgramTestContext,
+    mint: &Pubkey,
+    owner: &Pubkey,
+) -> Pubkey {
+    let token_account = Pubkey::new_unique();
+    set_token_program_account(
+        context,
+        &token_account,
+        token::token_account_data(mint, owner),
+    )
+    .await;
+    token_account
+}
+
+pub async fn mint_to(
+    context: &mut ProgramTestContext,
+    mint: &Pubkey,
+    token_account: &Pubkey,
+    authority: &Keypair,
+    amount: u64,
+) {
+    let mint_ix = spl_token::instruction::mint_to(
+        &spl_token::ID,
+        mint,
+        token_account,
+        &authority.pubkey(),
+        &[],
+        amount,
+    )
+    .unwrap();
+    process(context, &[mint_ix], &[authority]).await.unwrap();
+}
+
+/// Credits tokens straight to an account, as if someone had transferred them in.
+pub async fn credit_tokens(context: &mut ProgramTestContext, token_account: Pubkey, extra: u64) {
+    let account = context
+        .banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .expect("account does not exist");
+    set_token_program_account(context, &token_account, token::credit(&account.data, extra)).await;
+}
+
+pub async fn token_balance(context: &mut ProgramTestContext, token_account: Pubkey) -> u64 {
+    fetch::<anchor_spl::token::TokenAccount>(context, token_account)
+        .await
+        .amount
+}
+
+pub async fn now(context: &mut ProgramTestContext) -> i64 {
+    context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp
+}
+
+pub async fn warp_to_timestamp(context: &mut ProgramTestContext, unix_timestamp: i64) {
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp = unix_timestamp;
+    context.set_sysvar(&clock);
+}
+
+pub fn group_args(name: &str) -> instruction::CreateGroup {
+    instruction::CreateGroup {
+        name: name.to_string(),
+        description: "A group for testing".to_string(),
+        is_channel: false,
+        is_whale_group: false,
+        required_token: None,
+        required_amount: 0,
+        required_nft_collection: None,
+        required_sol_balance: 0,
+        escrow_enabled: false,
+    }
+}
+
+pub async fn create_group(
+    context: &mut ProgramTestContext,
+    creator: &Keypair,
+    args: instruction::CreateGroup,
+) -> Pubkey {
+    let group = pda::group(&args.name, &creator.pubkey());
+    let create_ix = ix::create_group(context.payer.pubkey(), creator.pubkey(), args);
+    process(context, &[create_ix], &[creator]).await.unwrap();
+    group
+}
+
+pub async fn join_group(context: &mut ProgramTestContext, member: &Keypair, group: Pubkey) {
+    let join_ix = ix::join_group(context.payer.pubkey(), member.pubkey(), group, None, None);
+    process(context, &[join_ix], &[member]).await.unwrap();
+}
+
+/// Has the group's creator give `member`, who must have joined, a staff role.
+pub async fn add_group_admin(
+    context: &mut ProgramTestContext,
+    creator: &Keypair,
+    group: Pubkey,
+    member: &Pubkey,
+    role: GroupRole,
+) {
+    let add_ix = ix::add_group_admin(creator.pubkey(), group, false, *member, role);
+    process(context, &[add_ix], &[creator]).await.unwrap();
+}
+
+/// Has the group's creator permanently ban `member`, who joined through [`join_group`].
+pub async fn ban_member(
+    context: &mut ProgramTestContext,
+    creator: &Keypair,
+    group: Pubkey,
+    member: &Pubkey,
+) {
+    let payer = context.payer.pubkey();
+    let ban_ix = ix::ban_member(
+        payer,
+        creator.pubkey(),
+        group,
+        false,
+        *member,
+        Some(payer),
+        None,
+    );
+    process(context, &[ban_ix], &[creator]).await.unwrap();
+}
+
+/// Posts `content` as the group's next message and returns its id.
+pub async fn send_message(
+    context: &mut ProgramTestContext,
+    sender: &Keypair,
+    group: Pubkey,
+    content: &str,
+) -> u64 {
+    let message_id = fetch::<Group>(context, group).await.message_count;
+    let send_ix = ix::send_message(
+        context.payer.pubkey(),
+        sender.pubkey(),
+        group,
+        message_id,
+        content,
+    );
+    process(context, &[send_ix], &[sender]).await.unwrap();
+    message_id
+}
+
+pub async fn create_user_profile(context: &mut ProgramTestContext, owner: &Keypair) {
+    let create_ix = ix::create_user_profile(
+        context.payer.pubkey(),
+        owner.pubkey(),
+        instruction::CreateUserProfile {
+            username: "chum".to_string(),
+            bio: "gm".to_string(),
+            show_balance: false,
+        },
+    );
+    process(context, &[create_ix], &[owner]).await.unwrap();
+}
+
+/// Takes submissions for an hour from `start_time`, then votes for another hour.
+pub fn challenge_args(start_time: i64) -> instruction::CreateMemeChallenge {
+    instruction::CreateMemeChallenge {
+        title: "Best chum".to_string(),
+        description: "Post your best chum meme".to_string(),
+        prompt: "A fish in a suit".to_string(),
+        reward_amount: LAMPORTS_PER_SOL,
+        start_time,
+        submission_end: start_time + 3600,
+        voting_end: start_time + 7200,
+        reveal_end: None,
+        prize_split_bps: vec![10_000],
+        voting_mode: VotingMode::OnePerWallet,
+        voting_mint: None,
+        vote_price: 0,
+        entry_fee: 0,
+    }
+}
+
+/// Creates a winner-takes-all challenge with a 1 SOL prize that opens a minute from now,
+/// laid out by `challenge_args`. The creator is funded with 2 SOL to cover the prize.
+pub async fn create_meme_challenge(context: &mut ProgramTestContext, creator: &Keypair) -> Pubkey {
+    create_meme_challenge_with(context, creator, |args| args).await
+}
+
+/// Like `create_meme_challenge`, with `customize` adjusting the default arguments.
+pub async fn create_meme_challenge_with(
+    context: &mut ProgramTestContext,
+    creator: &Keypair,
+    customize: impl FnOnce(instruction::CreateMemeChallenge) -> instruction::CreateMemeChallenge,
+) -> Pubkey {
+    fund(context, &creator.pubkey(), 2 * LAMPORTS_PER_SOL);
+    let start_time = now(context).await + 60;
+    let create_ix = ix::create_meme_challenge(
+        context.payer.pubkey(),
+        creator.pubkey(),
+        None,
+        None,
+        None,
+        customize(challenge_args(start_time)),
+    );
+    process(context, &[create_ix], &[creator]).await.unwrap();
+    pda::meme_challenge(&creator.pubkey(), start_time)
+}
+
+pub async fn create_meme_challenge_with_split(
+    context: &mut ProgramTestContext,
+    creator: &Keypair,
+    prize_split_bps: Vec<u16>,
+) -> Pubkey {
+    create_meme_challenge_with(context, creator, |args| instruction::CreateMemeChallenge {
+        prize_split_bps,
+        ..args
+    })
+    .await
+}
+
+/// Creates a group owned by `creator` and a 1 SOL challenge restricted to its members.
+pub async fn create_group_meme_challenge(
+    context: &mut ProgramTestContext,
+    creator: &Keypair,
+) -> (Pubkey, Pubkey) {
+    let group = create_group(context, creator, group_args("memers")).await;
+    fund(context, &creator.pubkey(), 2 * LAMPORTS_PER_SOL);
+    let start_time = now(context).await + 60;
+    let create_ix = ix::create_meme_challenge(
+        context.payer.pubkey(),
+        creator.pubkey(),
+        Some(group),
+        None,
+        None,
+        challenge_args(start_time),
+    );
+    process(context, &[create_ix], &[creator]).await.unwrap();
+    (group, pda::meme_challenge(&creator.pubkey(), start_time))
+}
+
+/// Creates a 1 SOL commit–reveal challenge whose votes are revealed in a third hour.
+pub async fn create_commit_reveal_challenge(
+    context: &mut ProgramTestContext,
+    creator: &Keypair,
+) -> Pubkey {
+    create_meme_challenge_with(context, creator, |args| instruction::CreateMemeChallenge {
+        reveal_end: Some(args.start_time + 10_800),
+        ..args
+    })
+    .await
+}
+
+pub fn submission_args() -> instruction::SubmitMeme {
+    instruction::SubmitMeme {
+        image_url: "https://example.com/chum.png".to_string(),
+        title: "Suit fish".to_string(),
+        description: "Dressed for success".to_string(),
+        content_hash: [1; 32],
+    }
+}
+
+/// Submits an image whose hash is the submitter's key, so every submitter's entry is distinct.
+pub async fn submit_meme(context: &mut ProgramTestContext, submitter: &Keypair, challenge: Pubkey) {
+    let submit_ix = ix::submit_meme(
+        context.payer.pubkey(),
+        submitter.pubkey(),
+        challenge,
+        None,
+        instruction::SubmitMeme {
+            content_hash: submitter.pubkey().to_bytes(),
+            ..submission_args()
+        },
+    );
+    process(context, &[submit_ix], &[submitter]).await.unwrap();
+}
+
+pub async fn vote_for_meme(
+    context: &mut ProgramTestContext,
+    voter: &Keypair,
     challenge: Pubkey,
-    submission: Pubkey,
-    submitter: &Keypair,
-    system_program: Pubkey,
-    image_url: &String,
-    title: &String,
-    description: &String,
-    recent_blockhash: Hash,
-) -> Transaction {
-    let accounts = vec![
-        AccountMeta::new(fee_payer.pubkey(), true),
-        AccountMeta::new(challenge, false),
-        AccountMeta::new(submission, false),
-        AccountMeta::new(submitter.pubkey(), true),
-        AccountMeta::new_readonly(system_program, false),
-    ];
-
-    let mut data = vec![13u8]; // Instruction discriminator (simplified)
-    data.extend_from_slice(&image_url.as_bytes());
-    data.extend_from_slice(&title.as_bytes());
-    data.extend_from_slice(&description.as_bytes());
-
-    // Create a simple transaction
-    Transaction::new_with_payer(&[], Some(&fee_payer.pubkey()))
-}
-
-// Add stubs for the functions that are being imported in the test files
-pub fn get_program_test() {
-    // Placeholder implementation
-}
-
-pub fn chumchon_ix_interface() {
-    // Placeholder implementation
-}
\ No newline at end of file
+    submitter: &Pubkey,
+) {
+    let vote_ix = ix::vote_for_meme(
+        context.payer.pubkey(),
+        voter.pubkey(),
+        challenge,
+        None,
+        *submitter,
+    );
+    process(context, &[vote_ix], &[voter]).await.unwrap();
+}
+
+/// Commits `voter` to `submitter`'s entry under `salt`.
+pub async fn commit_meme_vote(
+    context: &mut ProgramTestContext,
+    voter: &Keypair,
+    challenge: Pubkey,
+    submitter: &Pubkey,
+    salt: [u8; 32],
+) {
+    let submission = pda::meme_submission(&challenge, submitter);
+    let commit_ix = ix::commit_meme_vote(
+        context.payer.pubkey(),
+        voter.pubkey(),
+        challenge,
+        None,
+        VoterRecord::commitment_for(&submission, &voter.pubkey(), &salt),
+        ix::VoteWeight::default(),
+    );
+    process(context, &[commit_ix], &[voter]).await.unwrap();
+}
+
+/// Funds `contributor` and has them add `amount` lamports to a SOL pool as the next contribution.
+pub async fn contribute_to_challenge(
+    context: &mut ProgramTestContext,
+    contributor: &Keypair,
+    challenge: Pubkey,
+    amount: u64,
+) {
+    fund(context, &contributor.pubkey(), amount + LAMPORTS_PER_SOL);
+    let challenge_account: MemeChallenge = fetch(context, challenge).await;
+    let contribute_ix = ix::contribute_to_challenge(
+        context.payer.pubkey(),
+        contributor.pubkey(),
+        challenge,
+        challenge_account.contribution_count,
+        None,
+        amount,
+    );
+    process(context, &[contribute_ix], &[contributor])
+        .await
+        .unwrap();
+}
+
+/// How one side of an escrow pays
+#[derive(Clone, Copy)]
+pub enum Leg {
+    Token,
+    Sol,
+}
+
+/// Token accounts behind an SPL token leg
+#[derive(Clone, Copy)]
+pub struct TokenLeg {
+    pub mint: Pubkey,
+    /// The depositing party's account, funded with the leg's amount
+    pub deposit_account: Pubkey,
+    /// The other party's account for this mint
+    pub receive_account: Pubkey,
+}
+
+pub struct EscrowSetup {
+    pub initiator: Keypair,
+    pub counterparty: Keypair,
+    pub created_at: i64,
+    pub escrow: Pubkey,
+    pub group: Pubkey,
+    /// Owns the group, and so may resolve disputes
+    pub group_owner: Keypair,
+    /// Pays rent for the escrow and both vaults
+    pub rent_payer: Pubkey,
+    /// None when the initiator offers native SOL
+    pub initiator_leg: Option<TokenLeg>,
+    /// None when the counterparty pays native SOL
+    pub counterparty_leg: Option<TokenLeg>,
+}
+
+impl EscrowSetup {
+    pub fn accept_ix(&self, fee_payer: Pubkey) -> Instruction {
+        ix::accept_escrow(
+            fee_payer,
+            self.counterparty.pubkey(),
+            self.initiator.pubkey(),
+            self.group,
+            self.created_at,
+            self.counterparty_leg.map(|leg| leg.mint),
+            self.counterparty_leg.map(|leg| leg.deposit_account),
+        )
+    }
+
+    pub fn complete_ix(&self, fee_payer: Pubkey, authority: Pubkey) -> Instruction {
+        ix::complete_escrow(
+            fee_payer,
+            authority,
+            self.initiator.pubkey(),
+            self.counterparty.pubkey(),
+            self.created_at,
+            self.rent_payer,
+            self.counterparty_leg.map(|leg| leg.receive_account),
+            self.initiator_leg.map(|leg| leg.receive_account),
+            self.initiator_leg.map(|leg| leg.deposit_account),
+            self.counterparty_leg.map(|leg| leg.deposit_account),
+        )
+    }
+
+    pub fn cancel_ix(&self, fee_payer: Pubkey, initiator: Pubkey) -> Instruction {
+        ix::cancel_escrow(
+            fee_payer,
+            initiator,
+            self.initiator.pubkey(),
+            self.created_at,
+            self.rent_payer,
+            self.initiator_leg.map(|leg| leg.deposit_account),
+        )
+    }
+
+    pub fn dispute_ix(&self, authority: Pubkey) -> Instruction {
+        ix::dispute_escrow(authority, self.initiator.pubkey(), self.created_at)
+    }
+
+    /// Settles the trade, or else refunds back to the accounts each side deposited from
+    pub fn resolve_ix(
+        &self,
+        authority: Pubkey,
+        authority_is_member: bool,
+        settle: bool,
+    ) -> Instruction {
+        ix::resolve_escrow_dispute(
+            authority,
+            self.group,
+            authority_is_member,
+            self.initiator.pubkey(),
+            self.counterparty.pubkey(),
+            self.created_at,
+            self.rent_payer,
+            self.counterparty_leg.map(|leg| leg.receive_account),
+            self.initiator_leg.map(|leg| leg.receive_account),
+            self.initiator_leg.map(|leg| leg.deposit_account),
+            self.counterparty_leg.map(|leg| leg.deposit_account),
+            settle,
+        )
+    }
+
+    /// Refunds back to the accounts each side deposited from
+    pub fn reclaim_ix(&self, fee_payer: Pubkey, include_counterparty: bool) -> Instruction {
+        let counterparty_leg = self.counterparty_leg.filter(|_| include_counterparty);
+        ix::reclaim_expired_escrow(
+            fee_payer,
+            self.initiator.pubkey(),
+            self.counterparty.pubkey(),
+            self.created_at,
+            self.rent_payer,
+            self.initiator_leg.map(|leg| leg.deposit_account),
+            counterparty_leg.map(|_| self.rent_payer),
+            counterparty_leg.map(|leg| leg.deposit_account),
+        )
+    }
+}
+
+pub fn escrow_args(
+    counterparty: Pubkey,
+    counterparty_token: Option<Pubkey>,
+    created_at: i64,
+    expires_at: i64,
+) -> instruction::CreateEscrow {
+    instruction::CreateEscrow {
+        counterparty,
+        initiator_amount: 100,
+        counterparty_token,
+        counterparty_amount: 250,
+        expires_at,
+        created_at,
+    }
+}
+
+async fn create_token_leg(
+    context: &mut ProgramTestContext,
+    depositor: &Pubkey,
+    receiver: &Pubkey,
+    amount: u64,
+) -> TokenLeg {
+    let mint_authority = Keypair::new();
+    let mint = create_mint(context, &mint_authority.pubkey()).await;
+    let deposit_account = create_token_account(context, &mint, depositor).await;
+    let receive_account = create_token_account(context, &mint, receiver).await;
+    mint_to(context, &mint, &deposit_account, &mint_authority, amount).await;
+    TokenLeg {
+        mint,
+        deposit_account,
+        receive_account,
+    }
+}
+
+/// Creates a whale group owned by `creator` that both parties join, ready to host their escrow.
+pub async fn create_escrow_group(
+    context: &mut ProgramTestContext,
+    creator: &Keypair,
+    initiator: &Keypair,
+    counterparty: &Keypair,
+) -> Pubkey {
+    let args = instruction::CreateGroup {
+        is_whale_group: true,
+        ..group_args("whales")
+    };
+    let group = create_group(context, creator, args).await;
+    join_group(context, initiator, group).await;
+    join_group(context, counterparty, group).await;
+    group
+}
+
+/// Opens a token-for-token escrow offering 100 tokens for 250 that expires at `expires_at`.
+pub async fn create_escrow(context: &mut ProgramTestContext, expires_at: i64) -> EscrowSetup {
+    create_escrow_with_legs(context, expires_at, Leg::Token, Leg::Token).await
+}
+
+/// Opens an escrow offering 100 units for 250, each side paying in tokens or lamports.
+/// Both parties hold 1 SOL and are funded for their leg.
+pub async fn create_escrow_with_legs(
+    context: &mut ProgramTestContext,
+    expires_at: i64,
+    initiator_pays: Leg,
+    counterparty_pays: Leg,
+) -> EscrowSetup {
+    let initiator = Keypair::new();
+    let counterparty = Keypair::new();
+    fund(context, &initiator.pubkey(), LAMPORTS_PER_SOL);
+    fund(context, &counterparty.pubkey(), LAMPORTS_PER_SOL);
+    let group_owner = Keypair::new();
+    let group = create_escrow_group(context, &group_owner, &initiator, &counterparty).await;
+    let created_at = now(context).await;
+    let escrow = pda::escrow(&initiator.pubkey(), created_at);
+
+    let initiator_leg = match initiator_pays {
+        Leg::Token => {
+            Some(create_token_leg(context, &initiator.pubkey(), &counterparty.pubkey(), 100).await)
+        }
+        Leg::Sol => None,
+    };
+    let counterparty_leg = match counterparty_pays {
+        Leg::Token => {
+            Some(create_token_leg(context, &counterparty.pubkey(), &initiator.pubkey(), 250).await)
+        }
+        Leg::Sol => None,
+    };
+
+    let create_ix = ix::create_escrow(
+        context.payer.pubkey(),
+        initiator.pubkey(),
+        group,
+        initiator_leg.map(|leg| leg.mint),
+        initiator_leg.map(|leg| leg.deposit_account),
+        escrow_args(
+            counterparty.pubkey(),
+            counterparty_leg.map(|leg| leg.mint),
+            created_at,
+            expires_at,
+        ),
+    );
+    process(context, &[create_ix], &[&initiator]).await.unwrap();
+
+    EscrowSetup {
+        initiator,
+        counterparty,
+        created_at,
+        escrow,
+        group,
+        group_owner,
+        rent_payer: context.payer.pubkey(),
+        initiator_leg,
+        counterparty_leg,
+    }
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::Pubkey;
+
+pub fn user_profile(owner: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"user", owner.as_ref()], &chumchon::ID).0
+}
+
+pub fn group(name: &str, creator: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"group", name.as_bytes(), creator.as_ref()],
+        &chumchon::ID,
+    )
+    .0
+}
+
+pub fn group_member(group: &Pubkey, member: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"member", group.as_ref(), member.as_ref()], &chumchon::ID).0
+}
+
+pub fn group_ban(group: &Pubkey, member: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"ban", group.as_ref(), member.as_ref()], &chumchon::ID).0
+}
+
+/// A code too long to seed an address maps to the default key, which the program rejects first.
+pub fn invite(group: &Pubkey, code: &str) -> Pubkey {
+    Pubkey::try_find_program_address(&[b"invite", group.as_ref(), code.as_bytes()], &chumchon::ID)
+        .map_or(Pubkey::default(), |(address, _)| address)
+}
+
+pub fn message(group: &Pubkey, message_id: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"message", group.as_ref(), &message_id.to_le_bytes()],
+        &chumchon::ID,
+    )
+    .0
+}
+
+pub fn escrow(initiator: &Pubkey, created_at: i64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"escrow", initiator.as_ref(), &created_at.to_le_bytes()],
+        &chumchon::ID,
+    )
+    .0
+}
+
+pub fn initiator_vault(escrow: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"initiator_vault", escrow.as_ref()], &chumchon::ID).0
+}
+
+pub fn counterparty_vault(escrow: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"counterparty_vault", escrow.as_ref()], &chumchon::ID).0
+}
+
+pub fn meme_challenge(creator: &Pubkey, start_time: i64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"challenge", creator.as_ref(), &start_time.to_le_bytes()],
+        &chumchon::ID,
+    )
+    .0
+}
+
+pub fn challenge_vault(challenge: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"challenge_vault", challenge.as_ref()], &chumchon::ID).0
+}
+
+pub fn challenge_contribution(challenge: &Pubkey, contribution_id: u32) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"contribution",
+            challenge.as_ref(),
+            &contribution_id.to_le_bytes(),
+        ],
+        &chumchon::ID,
+    )
+    .0
+}
+
+pub fn meme_submission(challenge: &Pubkey, submitter: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"submission", challenge.as_ref(), submitter.as_ref()],
+        &chumchon::ID,
+    )
+    .0
+}
+
+pub fn content_guard(challenge: &Pubkey, content_hash: &[u8; 32]) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"content", challenge.as_ref(), content_hash.as_ref()],
+        &chumchon::ID,
+    )
+    .0
+}
+
+pub fn voter_record(challenge: &Pubkey, voter: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"voter", challenge.as_ref(), voter.as_ref()],
+        &chumchon::ID,
+    )
+    .0
+}
+
+pub fn vote_vault(challenge: &Pubkey, voter: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"vote_vault", challenge.as_ref(), voter.as_ref()],
+        &chumchon::ID,
+    )
+    .0
+}
@@ -0,0 +1,43 @@
+use anchor_lang::{
+    prelude::Pubkey,
+    solana_program::{program_option::COption, program_pack::Pack},
+};
+use anchor_spl::token::spl_token::state::{Account, AccountState, Mint};
+
+pub fn mint_data(authority: &Pubkey, decimals: u8) -> Vec<u8> {
+    let mut data = vec![0; Mint::LEN];
+    Mint {
+        mint_authority: COption::Some(*authority),
+        supply: 0,
+        decimals,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    }
+    .pack_into_slice(&mut data);
+    data
+}
+
+pub fn token_account_data(mint: &Pubkey, owner: &Pubkey) -> Vec<u8> {
+    let mut data = vec![0; Account::LEN];
+    Account {
+        mint: *mint,
+        owner: *owner,
+        amount: 0,
+        delegate: COption::None,
+        state: AccountState::Initialized,
+        is_native: COption::None,
+        delegated_amount: 0,
+        close_authority: COption::None,
+    }
+    .pack_into_slice(&mut data);
+    data
+}
+
+/// `data` with `extra` more tokens credited to the account.
+pub fn credit(data: &[u8], extra: u64) -> Vec<u8> {
+    let mut account = Account::unpack(data).unwrap();
+    account.amount += extra;
+    let mut data = vec![0; Account::LEN];
+    account.pack_into_slice(&mut data);
+    data
+}
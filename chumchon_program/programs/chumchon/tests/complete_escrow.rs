@@ -0,0 +1,175 @@
+mod common;
+
+use chumchon::error::ErrorCode;
+use common::{
+    account_exists, assert_error, balance, create_escrow, create_escrow_with_legs, credit_tokens,
+    now, pda, process, start, token_balance, warp_to_timestamp, Leg, LAMPORTS_PER_SOL,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn complete_escrow_success() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow(&mut context, expires_at).await;
+    let accept_ix = setup.accept_ix(context.payer.pubkey());
+    process(&mut context, &[accept_ix], &[&setup.counterparty])
+        .await
+        .unwrap();
+
+    let complete_ix = setup.complete_ix(context.payer.pubkey(), setup.counterparty.pubkey());
+    process(&mut context, &[complete_ix], &[&setup.counterparty])
+        .await
+        .unwrap();
+
+    let initiator_leg = setup.initiator_leg.unwrap();
+    let counterparty_leg = setup.counterparty_leg.unwrap();
+    assert_eq!(
+        token_balance(&mut context, counterparty_leg.receive_account).await,
+        250
+    );
+    assert_eq!(
+        token_balance(&mut context, initiator_leg.receive_account).await,
+        100
+    );
+    assert!(!account_exists(&mut context, setup.escrow).await);
+    assert!(!account_exists(&mut context, pda::initiator_vault(&setup.escrow)).await);
+    assert!(!account_exists(&mut context, pda::counterparty_vault(&setup.escrow)).await);
+}
+
+#[tokio::test]
+async fn complete_escrow_returns_tokens_sent_to_a_vault_beyond_its_leg() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow(&mut context, expires_at).await;
+    let accept_ix = setup.accept_ix(context.payer.pubkey());
+    process(&mut context, &[accept_ix], &[&setup.counterparty])
+        .await
+        .unwrap();
+    credit_tokens(&mut context, pda::initiator_vault(&setup.escrow), 40).await;
+    credit_tokens(&mut context, pda::counterparty_vault(&setup.escrow), 7).await;
+
+    let complete_ix = setup.complete_ix(context.payer.pubkey(), setup.counterparty.pubkey());
+    process(&mut context, &[complete_ix], &[&setup.counterparty])
+        .await
+        .unwrap();
+
+    let initiator_leg = setup.initiator_leg.unwrap();
+    let counterparty_leg = setup.counterparty_leg.unwrap();
+    assert_eq!(
+        token_balance(&mut context, counterparty_leg.receive_account).await,
+        250
+    );
+    assert_eq!(
+        token_balance(&mut context, initiator_leg.receive_account).await,
+        100
+    );
+    assert_eq!(
+        token_balance(&mut context, initiator_leg.deposit_account).await,
+        40
+    );
+    assert_eq!(
+        token_balance(&mut context, counterparty_leg.deposit_account).await,
+        7
+    );
+    assert!(!account_exists(&mut context, pda::initiator_vault(&setup.escrow)).await);
+    assert!(!account_exists(&mut context, pda::counterparty_vault(&setup.escrow)).await);
+}
+
+#[tokio::test]
+async fn complete_escrow_swaps_tokens_for_native_sol() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow_with_legs(&mut context, expires_at, Leg::Token, Leg::Sol).await;
+    let accept_ix = setup.accept_ix(context.payer.pubkey());
+    process(&mut context, &[accept_ix], &[&setup.counterparty])
+        .await
+        .unwrap();
+
+    let complete_ix = setup.complete_ix(context.payer.pubkey(), setup.initiator.pubkey());
+    process(&mut context, &[complete_ix], &[&setup.initiator])
+        .await
+        .unwrap();
+
+    let initiator_leg = setup.initiator_leg.unwrap();
+    assert_eq!(
+        token_balance(&mut context, initiator_leg.receive_account).await,
+        100
+    );
+    assert_eq!(
+        balance(&mut context, setup.initiator.pubkey()).await,
+        LAMPORTS_PER_SOL + 250
+    );
+    assert!(!account_exists(&mut context, setup.escrow).await);
+    assert!(!account_exists(&mut context, pda::initiator_vault(&setup.escrow)).await);
+}
+
+#[tokio::test]
+async fn complete_escrow_swaps_native_sol_both_ways() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow_with_legs(&mut context, expires_at, Leg::Sol, Leg::Sol).await;
+    let accept_ix = setup.accept_ix(context.payer.pubkey());
+    process(&mut context, &[accept_ix], &[&setup.counterparty])
+        .await
+        .unwrap();
+
+    let complete_ix = setup.complete_ix(context.payer.pubkey(), setup.initiator.pubkey());
+    process(&mut context, &[complete_ix], &[&setup.initiator])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        balance(&mut context, setup.initiator.pubkey()).await,
+        LAMPORTS_PER_SOL - 100 + 250
+    );
+    assert_eq!(
+        balance(&mut context, setup.counterparty.pubkey()).await,
+        LAMPORTS_PER_SOL - 250 + 100
+    );
+    assert!(!account_exists(&mut context, setup.escrow).await);
+}
+
+#[tokio::test]
+async fn complete_escrow_requires_acceptance() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow(&mut context, expires_at).await;
+
+    let complete_ix = setup.complete_ix(context.payer.pubkey(), setup.initiator.pubkey());
+    let result = process(&mut context, &[complete_ix], &[&setup.initiator]).await;
+    assert_error(result, ErrorCode::EscrowNotAccepted);
+}
+
+#[tokio::test]
+async fn complete_escrow_requires_party() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow(&mut context, expires_at).await;
+    let accept_ix = setup.accept_ix(context.payer.pubkey());
+    process(&mut context, &[accept_ix], &[&setup.counterparty])
+        .await
+        .unwrap();
+    let stranger = Keypair::new();
+
+    let complete_ix = setup.complete_ix(context.payer.pubkey(), stranger.pubkey());
+    let result = process(&mut context, &[complete_ix], &[&stranger]).await;
+    assert_error(result, ErrorCode::Unauthorized);
+}
+
+#[tokio::test]
+async fn complete_escrow_rejects_expired_escrow() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow(&mut context, expires_at).await;
+    let accept_ix = setup.accept_ix(context.payer.pubkey());
+    process(&mut context, &[accept_ix], &[&setup.counterparty])
+        .await
+        .unwrap();
+    warp_to_timestamp(&mut context, expires_at + 1).await;
+
+    let complete_ix = setup.complete_ix(context.payer.pubkey(), setup.initiator.pubkey());
+    let result = process(&mut context, &[complete_ix], &[&setup.initiator]).await;
+    assert_error(result, ErrorCode::EscrowExpired);
+}
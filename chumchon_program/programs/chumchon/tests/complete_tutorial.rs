@@ -0,0 +1,48 @@
+mod common;
+
+use chumchon::{error::ErrorCode, UserProfile};
+use common::{assert_error, create_user_profile, fetch, ix, pda, process, start};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn complete_tutorial_success() {
+    let mut context = start().await;
+    let user = Keypair::new();
+    create_user_profile(&mut context, &user).await;
+
+    let complete_ix = ix::complete_tutorial(context.payer.pubkey(), user.pubkey(), 3);
+    process(&mut context, &[complete_ix], &[&user])
+        .await
+        .unwrap();
+
+    let profile: UserProfile = fetch(&mut context, pda::user_profile(&user.pubkey())).await;
+    assert_eq!(profile.completed_tutorials, vec![3]);
+    assert_eq!(profile.tutorial_rewards, 1000);
+}
+
+#[tokio::test]
+async fn complete_tutorial_rejects_repeat_completion() {
+    let mut context = start().await;
+    let user = Keypair::new();
+    create_user_profile(&mut context, &user).await;
+
+    let complete_ix = ix::complete_tutorial(context.payer.pubkey(), user.pubkey(), 3);
+    process(&mut context, std::slice::from_ref(&complete_ix), &[&user])
+        .await
+        .unwrap();
+
+    let result = process(&mut context, &[complete_ix], &[&user]).await;
+    assert_error(result, ErrorCode::TutorialAlreadyCompleted);
+}
+
+#[tokio::test]
+async fn complete_tutorial_rejects_unknown_tutorial() {
+    let mut context = start().await;
+    let user = Keypair::new();
+    create_user_profile(&mut context, &user).await;
+
+    let complete_ix = ix::complete_tutorial(context.payer.pubkey(), user.pubkey(), 11);
+    let result = process(&mut context, &[complete_ix], &[&user]).await;
+    assert_error(result, ErrorCode::InvalidTutorialId);
+}
@@ -0,0 +1,157 @@
+mod common;
+
+use chumchon::{error::ErrorCode, instruction, ChallengeContribution, MemeChallenge};
+use common::{
+    assert_error, balance, challenge_args, contribute_to_challenge, create_meme_challenge,
+    create_mint, create_token_account, fetch, fund, ix, mint_to, now, pda, process, start,
+    submit_meme, token_balance, warp_to_timestamp, LAMPORTS_PER_SOL,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn contribute_to_challenge_success() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let first = Keypair::new();
+    let second = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let pool_before = balance(&mut context, challenge).await;
+
+    contribute_to_challenge(&mut context, &first, challenge, LAMPORTS_PER_SOL / 2).await;
+    contribute_to_challenge(&mut context, &second, challenge, LAMPORTS_PER_SOL / 4).await;
+
+    assert_eq!(
+        balance(&mut context, challenge).await - pool_before,
+        3 * LAMPORTS_PER_SOL / 4
+    );
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(challenge_account.reward_amount, 7 * LAMPORTS_PER_SOL / 4);
+    assert_eq!(challenge_account.contribution_count, 2);
+
+    let contribution: ChallengeContribution =
+        fetch(&mut context, pda::challenge_contribution(&challenge, 1)).await;
+    assert_eq!(contribution.challenge, challenge);
+    assert_eq!(contribution.contributor, second.pubkey());
+    assert_eq!(contribution.contribution_id, 1);
+    assert_eq!(contribution.amount, LAMPORTS_PER_SOL / 4);
+}
+
+#[tokio::test]
+async fn contribute_to_challenge_token_pool() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let contributor = Keypair::new();
+    let winner = Keypair::new();
+    let mint_authority = Keypair::new();
+    let mint = create_mint(&mut context, &mint_authority.pubkey()).await;
+    let creator_token_account = create_token_account(&mut context, &mint, &creator.pubkey()).await;
+    let contributor_token_account =
+        create_token_account(&mut context, &mint, &contributor.pubkey()).await;
+    let winner_token_account = create_token_account(&mut context, &mint, &winner.pubkey()).await;
+    mint_to(
+        &mut context,
+        &mint,
+        &creator_token_account,
+        &mint_authority,
+        500,
+    )
+    .await;
+    mint_to(
+        &mut context,
+        &mint,
+        &contributor_token_account,
+        &mint_authority,
+        200,
+    )
+    .await;
+    fund(&mut context, &creator.pubkey(), LAMPORTS_PER_SOL);
+    let start_time = now(&mut context).await + 60;
+    let create_ix = ix::create_meme_challenge(
+        context.payer.pubkey(),
+        creator.pubkey(),
+        None,
+        Some(mint),
+        Some(creator_token_account),
+        instruction::CreateMemeChallenge {
+            reward_amount: 500,
+            ..challenge_args(start_time)
+        },
+    );
+    process(&mut context, &[create_ix], &[&creator])
+        .await
+        .unwrap();
+    let challenge = pda::meme_challenge(&creator.pubkey(), start_time);
+
+    let contribute_ix = ix::contribute_to_challenge(
+        context.payer.pubkey(),
+        contributor.pubkey(),
+        challenge,
+        0,
+        Some(contributor_token_account),
+        200,
+    );
+    process(&mut context, &[contribute_ix], &[&contributor])
+        .await
+        .unwrap();
+    assert_eq!(
+        token_balance(&mut context, pda::challenge_vault(&challenge)).await,
+        700
+    );
+
+    // The winner takes the contribution along with the creator's prize
+    warp_to_timestamp(&mut context, start_time + 1).await;
+    submit_meme(&mut context, &winner, challenge).await;
+    warp_to_timestamp(&mut context, start_time + 7200).await;
+    let end_ix = ix::end_meme_challenge(
+        context.payer.pubkey(),
+        challenge,
+        context.payer.pubkey(),
+        true,
+        &[winner_token_account],
+    );
+    process(&mut context, &[end_ix], &[]).await.unwrap();
+    assert_eq!(token_balance(&mut context, winner_token_account).await, 700);
+}
+
+#[tokio::test]
+async fn contribute_to_challenge_rejects_zero_amount() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let contributor = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    fund(&mut context, &contributor.pubkey(), LAMPORTS_PER_SOL);
+
+    let contribute_ix = ix::contribute_to_challenge(
+        context.payer.pubkey(),
+        contributor.pubkey(),
+        challenge,
+        0,
+        None,
+        0,
+    );
+    let result = process(&mut context, &[contribute_ix], &[&contributor]).await;
+    assert_error(result, ErrorCode::InvalidAmount);
+}
+
+#[tokio::test]
+async fn contribute_to_challenge_after_results() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let contributor = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    fund(&mut context, &contributor.pubkey(), LAMPORTS_PER_SOL);
+    warp_to_timestamp(&mut context, challenge_account.voting_end).await;
+
+    let contribute_ix = ix::contribute_to_challenge(
+        context.payer.pubkey(),
+        contributor.pubkey(),
+        challenge,
+        0,
+        None,
+        LAMPORTS_PER_SOL / 2,
+    );
+    let result = process(&mut context, &[contribute_ix], &[&contributor]).await;
+    assert_error(result, ErrorCode::ChallengeEnded);
+}
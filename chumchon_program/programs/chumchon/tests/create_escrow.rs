@@ -0,0 +1,279 @@
+mod common;
+
+use anchor_lang::error::ErrorCode as AnchorErrorCode;
+use chumchon::{error::ErrorCode, instruction, Escrow, EscrowStatus};
+use common::{
+    assert_error, balance, create_escrow_with_legs, create_group, create_mint,
+    create_token_account, escrow_args, fetch, group_args, ix, join_group, mint_to, now, pda,
+    process, start, token_balance, Leg, LAMPORTS_PER_SOL,
+};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+#[tokio::test]
+async fn create_escrow_success() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = common::create_escrow(&mut context, expires_at).await;
+    let initiator_leg = setup.initiator_leg.unwrap();
+
+    let escrow: Escrow = fetch(&mut context, setup.escrow).await;
+    assert_eq!(escrow.initiator, setup.initiator.pubkey());
+    assert_eq!(escrow.counterparty, setup.counterparty.pubkey());
+    assert_eq!(escrow.initiator_token, Some(initiator_leg.mint));
+    assert_eq!(escrow.initiator_amount, 100);
+    assert_eq!(
+        escrow.counterparty_token,
+        setup.counterparty_leg.map(|leg| leg.mint)
+    );
+    assert_eq!(escrow.counterparty_amount, 250);
+    assert_eq!(escrow.created_at, setup.created_at);
+    assert_eq!(escrow.expires_at, expires_at);
+    assert_eq!(escrow.status, EscrowStatus::Open);
+    assert_eq!(escrow.accepted_at, None);
+    assert_eq!(
+        token_balance(&mut context, initiator_leg.deposit_account).await,
+        0
+    );
+    assert_eq!(
+        token_balance(&mut context, pda::initiator_vault(&setup.escrow)).await,
+        100
+    );
+}
+
+/// A group where both parties are members, plus the initiator's funded token account.
+async fn trade_setup(
+    context: &mut ProgramTestContext,
+    group_args: instruction::CreateGroup,
+) -> (Keypair, Keypair, Pubkey, Pubkey, Pubkey) {
+    let initiator = Keypair::new();
+    let counterparty = Keypair::new();
+    let group = create_group(context, &Keypair::new(), group_args).await;
+    join_group(context, &initiator, group).await;
+    join_group(context, &counterparty, group).await;
+    let mint_authority = Keypair::new();
+    let mint = create_mint(context, &mint_authority.pubkey()).await;
+    let initiator_token_account = create_token_account(context, &mint, &initiator.pubkey()).await;
+    mint_to(
+        context,
+        &mint,
+        &initiator_token_account,
+        &mint_authority,
+        100,
+    )
+    .await;
+    (
+        initiator,
+        counterparty,
+        group,
+        mint,
+        initiator_token_account,
+    )
+}
+
+fn whale_group_args() -> instruction::CreateGroup {
+    instruction::CreateGroup {
+        is_whale_group: true,
+        ..group_args("whales")
+    }
+}
+
+#[tokio::test]
+async fn create_escrow_rejects_invalid_terms() {
+    let mut context = start().await;
+    let (initiator, counterparty, group, mint, initiator_token_account) =
+        trade_setup(&mut context, whale_group_args()).await;
+    let created_at = now(&mut context).await;
+
+    let create_ix = ix::create_escrow(
+        context.payer.pubkey(),
+        initiator.pubkey(),
+        group,
+        Some(mint),
+        Some(initiator_token_account),
+        instruction::CreateEscrow {
+            initiator_amount: 0,
+            ..escrow_args(
+                counterparty.pubkey(),
+                Some(mint),
+                created_at,
+                created_at + 3600,
+            )
+        },
+    );
+    let result = process(&mut context, &[create_ix], &[&initiator]).await;
+    assert_error(result, ErrorCode::InvalidAmount);
+
+    let create_ix = ix::create_escrow(
+        context.payer.pubkey(),
+        initiator.pubkey(),
+        group,
+        Some(mint),
+        Some(initiator_token_account),
+        escrow_args(counterparty.pubkey(), Some(mint), created_at, created_at),
+    );
+    let result = process(&mut context, &[create_ix], &[&initiator]).await;
+    assert_error(result, ErrorCode::InvalidExpiry);
+
+    // An escrow cannot be created already expired, even with a backdated creation time
+    let create_ix = ix::create_escrow(
+        context.payer.pubkey(),
+        initiator.pubkey(),
+        group,
+        Some(mint),
+        Some(initiator_token_account),
+        escrow_args(
+            counterparty.pubkey(),
+            Some(mint),
+            created_at - 30,
+            created_at - 10,
+        ),
+    );
+    let result = process(&mut context, &[create_ix], &[&initiator]).await;
+    assert_error(result, ErrorCode::InvalidExpiry);
+
+    for created_at in [created_at - 3600, created_at + 3600] {
+        let create_ix = ix::create_escrow(
+            context.payer.pubkey(),
+            initiator.pubkey(),
+            group,
+            Some(mint),
+            Some(initiator_token_account),
+            escrow_args(
+                counterparty.pubkey(),
+                Some(mint),
+                created_at,
+                created_at + 7200,
+            ),
+        );
+        let result = process(&mut context, &[create_ix], &[&initiator]).await;
+        assert_error(result, ErrorCode::InvalidCreatedAt);
+    }
+}
+
+#[tokio::test]
+async fn create_escrow_rejects_mismatched_token_account() {
+    let mut context = start().await;
+    let (initiator, counterparty, group, _, initiator_token_account) =
+        trade_setup(&mut context, whale_group_args()).await;
+    let created_at = now(&mut context).await;
+    let other_mint = create_mint(&mut context, &Keypair::new().pubkey()).await;
+
+    let create_ix = ix::create_escrow(
+        context.payer.pubkey(),
+        initiator.pubkey(),
+        group,
+        Some(other_mint),
+        Some(initiator_token_account),
+        escrow_args(
+            counterparty.pubkey(),
+            Some(other_mint),
+            created_at,
+            created_at + 3600,
+        ),
+    );
+    let result = process(&mut context, &[create_ix], &[&initiator]).await;
+    assert_error(result, ErrorCode::InvalidToken);
+}
+
+#[tokio::test]
+async fn create_escrow_requires_escrow_enabled_group() {
+    let mut context = start().await;
+    let (initiator, counterparty, group, mint, initiator_token_account) =
+        trade_setup(&mut context, group_args("chums")).await;
+    let created_at = now(&mut context).await;
+
+    let create_ix = ix::create_escrow(
+        context.payer.pubkey(),
+        initiator.pubkey(),
+        group,
+        Some(mint),
+        Some(initiator_token_account),
+        escrow_args(
+            counterparty.pubkey(),
+            Some(mint),
+            created_at,
+            created_at + 3600,
+        ),
+    );
+    let result = process(&mut context, &[create_ix], &[&initiator]).await;
+    assert_error(result, ErrorCode::EscrowNotEnabled);
+}
+
+#[tokio::test]
+async fn create_escrow_allows_opted_in_group() {
+    let mut context = start().await;
+    let args = instruction::CreateGroup {
+        escrow_enabled: true,
+        ..group_args("traders")
+    };
+    let (initiator, counterparty, group, mint, initiator_token_account) =
+        trade_setup(&mut context, args).await;
+    let created_at = now(&mut context).await;
+
+    let create_ix = ix::create_escrow(
+        context.payer.pubkey(),
+        initiator.pubkey(),
+        group,
+        Some(mint),
+        Some(initiator_token_account),
+        escrow_args(
+            counterparty.pubkey(),
+            Some(mint),
+            created_at,
+            created_at + 3600,
+        ),
+    );
+    process(&mut context, &[create_ix], &[&initiator])
+        .await
+        .unwrap();
+
+    let escrow: Escrow = fetch(&mut context, pda::escrow(&initiator.pubkey(), created_at)).await;
+    assert_eq!(escrow.group, group);
+}
+
+#[tokio::test]
+async fn create_escrow_requires_counterparty_membership() {
+    let mut context = start().await;
+    let (initiator, _, group, mint, initiator_token_account) =
+        trade_setup(&mut context, whale_group_args()).await;
+    let created_at = now(&mut context).await;
+    let outsider = Keypair::new();
+
+    let create_ix = ix::create_escrow(
+        context.payer.pubkey(),
+        initiator.pubkey(),
+        group,
+        Some(mint),
+        Some(initiator_token_account),
+        escrow_args(outsider.pubkey(), Some(mint), created_at, created_at + 3600),
+    );
+    let result = process(&mut context, &[create_ix], &[&initiator]).await;
+    assert_error(result, AnchorErrorCode::AccountNotInitialized);
+}
+
+#[tokio::test]
+async fn create_escrow_holds_native_sol() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow_with_legs(&mut context, expires_at, Leg::Sol, Leg::Token).await;
+
+    let escrow: Escrow = fetch(&mut context, setup.escrow).await;
+    assert_eq!(escrow.initiator_token, None);
+    assert_eq!(
+        escrow.counterparty_token,
+        setup.counterparty_leg.map(|leg| leg.mint)
+    );
+    assert_eq!(
+        balance(&mut context, setup.initiator.pubkey()).await,
+        LAMPORTS_PER_SOL - 100
+    );
+    let rent = context.banks_client.get_rent().await.unwrap();
+    assert_eq!(
+        balance(&mut context, setup.escrow).await,
+        rent.minimum_balance(Escrow::LEN) + 100
+    );
+}
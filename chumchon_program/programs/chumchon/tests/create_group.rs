@@ -0,0 +1,53 @@
+mod common;
+
+use chumchon::{error::ErrorCode, instruction, Group};
+use common::{assert_error, fetch, group_args, ix, pda, process, start};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn create_group_success() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+
+    let create_ix = ix::create_group(
+        context.payer.pubkey(),
+        creator.pubkey(),
+        instruction::CreateGroup {
+            is_whale_group: true,
+            required_sol_balance: 100,
+            ..group_args("whales")
+        },
+    );
+    process(&mut context, &[create_ix], &[&creator])
+        .await
+        .unwrap();
+
+    let group: Group = fetch(&mut context, pda::group("whales", &creator.pubkey())).await;
+    assert_eq!(group.name, "whales");
+    assert_eq!(group.display_name, "whales");
+    assert_eq!(group.creator, creator.pubkey());
+    assert_eq!(group.owner, creator.pubkey());
+    assert_eq!(group.pending_owner, None);
+    assert!(group.is_whale_group);
+    assert!(!group.escrow_enabled);
+    assert_eq!(group.required_sol_balance, 100);
+    assert_eq!(group.member_count, 0);
+}
+
+#[tokio::test]
+async fn create_group_rejects_long_description() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+
+    let create_ix = ix::create_group(
+        context.payer.pubkey(),
+        creator.pubkey(),
+        instruction::CreateGroup {
+            description: "a".repeat(257),
+            ..group_args("chums")
+        },
+    );
+    let result = process(&mut context, &[create_ix], &[&creator]).await;
+    assert_error(result, ErrorCode::DescriptionTooLong);
+}
@@ -0,0 +1,130 @@
+mod common;
+
+use chumchon::{error::ErrorCode, instruction, GroupRole, Invite};
+use common::{
+    add_group_admin, assert_error, create_group, fetch, group_args, ix, join_group, now, pda,
+    process, start,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn create_invite_success() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    let expires_at = now(&mut context).await + 3600;
+
+    let invite_ix = ix::create_invite(
+        context.payer.pubkey(),
+        creator.pubkey(),
+        group,
+        instruction::CreateInvite {
+            code: "gmchums".to_string(),
+            max_uses: 5,
+            expires_at,
+        },
+    );
+    process(&mut context, &[invite_ix], &[&creator])
+        .await
+        .unwrap();
+
+    let invite: Invite = fetch(&mut context, pda::invite(&group, "gmchums")).await;
+    assert_eq!(invite.group, group);
+    assert_eq!(invite.creator, creator.pubkey());
+    assert_eq!(invite.max_uses, 5);
+    assert_eq!(invite.uses, 0);
+    assert_eq!(invite.expires_at, expires_at);
+}
+
+#[tokio::test]
+async fn create_invite_requires_group_admin() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let stranger = Keypair::new();
+    let member = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    join_group(&mut context, &member, group).await;
+    let expires_at = now(&mut context).await + 3600;
+    let args = || instruction::CreateInvite {
+        code: "gmchums".to_string(),
+        max_uses: 5,
+        expires_at,
+    };
+
+    let invite_ix = ix::create_invite(context.payer.pubkey(), stranger.pubkey(), group, args());
+    let result = process(&mut context, &[invite_ix], &[&stranger]).await;
+    assert_error(result, ErrorCode::NotGroupAdmin);
+
+    let invite_ix =
+        ix::create_invite_as(context.payer.pubkey(), member.pubkey(), group, true, args());
+    let result = process(&mut context, std::slice::from_ref(&invite_ix), &[&member]).await;
+    assert_error(result, ErrorCode::NotGroupAdmin);
+
+    add_group_admin(
+        &mut context,
+        &creator,
+        group,
+        &member.pubkey(),
+        GroupRole::Admin,
+    )
+    .await;
+    process(&mut context, &[invite_ix], &[&member])
+        .await
+        .unwrap();
+    let invite: Invite = fetch(&mut context, pda::invite(&group, "gmchums")).await;
+    assert_eq!(invite.creator, member.pubkey());
+}
+
+#[tokio::test]
+async fn create_invite_rejects_invalid_parameters() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    let now = now(&mut context).await;
+
+    let invite_ix = ix::create_invite(
+        context.payer.pubkey(),
+        creator.pubkey(),
+        group,
+        instruction::CreateInvite {
+            code: "unlimited".to_string(),
+            max_uses: 0,
+            expires_at: now + 3600,
+        },
+    );
+    let result = process(&mut context, &[invite_ix], &[&creator]).await;
+    assert_error(result, ErrorCode::InvalidMaxUses);
+
+    let invite_ix = ix::create_invite(
+        context.payer.pubkey(),
+        creator.pubkey(),
+        group,
+        instruction::CreateInvite {
+            code: "stale".to_string(),
+            max_uses: 1,
+            expires_at: now - 1,
+        },
+    );
+    let result = process(&mut context, &[invite_ix], &[&creator]).await;
+    assert_error(result, ErrorCode::InvalidExpiry);
+
+    let cases = [
+        ("c".repeat(33), ErrorCode::CodeTooLong),
+        (String::new(), ErrorCode::InvalidInvite),
+    ];
+    for (code, expected) in cases {
+        let invite_ix = ix::create_invite(
+            context.payer.pubkey(),
+            creator.pubkey(),
+            group,
+            instruction::CreateInvite {
+                code,
+                max_uses: 1,
+                expires_at: now + 3600,
+            },
+        );
+        let result = process(&mut context, &[invite_ix], &[&creator]).await;
+        assert_error(result, expected);
+    }
+}
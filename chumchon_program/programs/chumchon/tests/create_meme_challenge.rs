@@ -0,0 +1,297 @@
+mod common;
+
+use chumchon::{error::ErrorCode, instruction, GroupRole, MemeChallenge, VotingMode};
+use common::{
+    add_group_admin, assert_error, balance, challenge_args, create_group,
+    create_group_meme_challenge, create_mint, create_token_account, fetch, fund, group_args, ix,
+    join_group, mint_to, now, pda, process, start, token_balance, LAMPORTS_PER_SOL,
+};
+use solana_program_test::tokio;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+#[tokio::test]
+async fn create_meme_challenge_success() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    fund(&mut context, &creator.pubkey(), 2 * LAMPORTS_PER_SOL);
+    let start_time = now(&mut context).await + 60;
+
+    let create_ix = ix::create_meme_challenge(
+        context.payer.pubkey(),
+        creator.pubkey(),
+        None,
+        None,
+        None,
+        challenge_args(start_time),
+    );
+    process(&mut context, &[create_ix], &[&creator])
+        .await
+        .unwrap();
+
+    let address = pda::meme_challenge(&creator.pubkey(), start_time);
+    let challenge: MemeChallenge = fetch(&mut context, address).await;
+    assert_eq!(challenge.creator, creator.pubkey());
+    assert_eq!(challenge.reward_amount, LAMPORTS_PER_SOL);
+    assert_eq!(challenge.reward_mint, None);
+    assert_eq!(challenge.start_time, start_time);
+    assert_eq!(challenge.submission_end, start_time + 3600);
+    assert_eq!(challenge.voting_end, start_time + 7200);
+    assert_eq!(challenge.submission_count, 0);
+    assert_eq!(challenge.prize_split_bps, vec![10_000]);
+    assert_eq!(challenge.entry_fee, 0);
+    assert!(challenge.leaderboard.is_empty());
+    assert!(challenge.winners.is_empty());
+    assert!(!challenge.completed);
+
+    // The prize pool sits in the challenge account on top of its rent
+    let rent = context.banks_client.get_rent().await.unwrap();
+    assert_eq!(
+        balance(&mut context, address).await,
+        rent.minimum_balance(MemeChallenge::LEN) + LAMPORTS_PER_SOL
+    );
+    assert_eq!(
+        balance(&mut context, creator.pubkey()).await,
+        LAMPORTS_PER_SOL
+    );
+}
+
+#[tokio::test]
+async fn create_meme_challenge_funds_token_pool() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let mint_authority = Keypair::new();
+    let mint = create_mint(&mut context, &mint_authority.pubkey()).await;
+    let creator_token_account = create_token_account(&mut context, &mint, &creator.pubkey()).await;
+    mint_to(
+        &mut context,
+        &mint,
+        &creator_token_account,
+        &mint_authority,
+        500,
+    )
+    .await;
+    let start_time = now(&mut context).await + 60;
+
+    let create_ix = ix::create_meme_challenge(
+        context.payer.pubkey(),
+        creator.pubkey(),
+        None,
+        Some(mint),
+        Some(creator_token_account),
+        instruction::CreateMemeChallenge {
+            reward_amount: 500,
+            ..challenge_args(start_time)
+        },
+    );
+    process(&mut context, &[create_ix], &[&creator])
+        .await
+        .unwrap();
+
+    let address = pda::meme_challenge(&creator.pubkey(), start_time);
+    let challenge: MemeChallenge = fetch(&mut context, address).await;
+    assert_eq!(challenge.reward_mint, Some(mint));
+    assert_eq!(
+        token_balance(&mut context, pda::challenge_vault(&address)).await,
+        500
+    );
+    assert_eq!(token_balance(&mut context, creator_token_account).await, 0);
+}
+
+#[tokio::test]
+async fn create_meme_challenge_rejects_invalid_parameters() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    fund(&mut context, &creator.pubkey(), 2 * LAMPORTS_PER_SOL);
+    let start_time = now(&mut context).await + 60;
+    let cases = [
+        (
+            instruction::CreateMemeChallenge {
+                title: "a".repeat(65),
+                ..challenge_args(start_time)
+            },
+            ErrorCode::TitleTooLong,
+        ),
+        (
+            instruction::CreateMemeChallenge {
+                description: "a".repeat(257),
+                ..challenge_args(start_time)
+            },
+            ErrorCode::DescriptionTooLong,
+        ),
+        (
+            instruction::CreateMemeChallenge {
+                prompt: "a".repeat(129),
+                ..challenge_args(start_time)
+            },
+            ErrorCode::PromptTooLong,
+        ),
+        (
+            instruction::CreateMemeChallenge {
+                reward_amount: 0,
+                ..challenge_args(start_time)
+            },
+            ErrorCode::InvalidAmount,
+        ),
+        (
+            instruction::CreateMemeChallenge {
+                submission_end: start_time,
+                ..challenge_args(start_time)
+            },
+            ErrorCode::InvalidExpiry,
+        ),
+        (
+            instruction::CreateMemeChallenge {
+                voting_end: start_time + 3600,
+                ..challenge_args(start_time)
+            },
+            ErrorCode::InvalidExpiry,
+        ),
+        (challenge_args(start_time - 120), ErrorCode::InvalidExpiry),
+        (
+            instruction::CreateMemeChallenge {
+                voting_mode: VotingMode::TokenWeighted,
+                ..challenge_args(start_time)
+            },
+            ErrorCode::InvalidVotingConfig,
+        ),
+        (
+            instruction::CreateMemeChallenge {
+                voting_mint: Some(Pubkey::new_unique()),
+                ..challenge_args(start_time)
+            },
+            ErrorCode::InvalidVotingConfig,
+        ),
+        (
+            instruction::CreateMemeChallenge {
+                voting_mode: VotingMode::Quadratic,
+                ..challenge_args(start_time)
+            },
+            ErrorCode::InvalidVotingConfig,
+        ),
+        (
+            instruction::CreateMemeChallenge {
+                vote_price: 1_000,
+                ..challenge_args(start_time)
+            },
+            ErrorCode::InvalidVotingConfig,
+        ),
+        (
+            instruction::CreateMemeChallenge {
+                reveal_end: Some(start_time + 7200),
+                ..challenge_args(start_time)
+            },
+            ErrorCode::InvalidExpiry,
+        ),
+        (
+            instruction::CreateMemeChallenge {
+                prize_split_bps: vec![],
+                ..challenge_args(start_time)
+            },
+            ErrorCode::InvalidPrizeSplit,
+        ),
+        (
+            instruction::CreateMemeChallenge {
+                prize_split_bps: vec![4_000, 3_000, 2_000, 1_000],
+                ..challenge_args(start_time)
+            },
+            ErrorCode::InvalidPrizeSplit,
+        ),
+        (
+            instruction::CreateMemeChallenge {
+                prize_split_bps: vec![6_000, 3_000],
+                ..challenge_args(start_time)
+            },
+            ErrorCode::InvalidPrizeSplit,
+        ),
+        (
+            instruction::CreateMemeChallenge {
+                prize_split_bps: vec![10_000, 0],
+                ..challenge_args(start_time)
+            },
+            ErrorCode::InvalidPrizeSplit,
+        ),
+    ];
+
+    for (args, expected) in cases {
+        let create_ix = ix::create_meme_challenge(
+            context.payer.pubkey(),
+            creator.pubkey(),
+            None,
+            None,
+            None,
+            args,
+        );
+        let result = process(&mut context, &[create_ix], &[&creator]).await;
+        assert_error(result, expected);
+    }
+}
+
+#[tokio::test]
+async fn create_meme_challenge_for_group() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let (group, challenge) = create_group_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(challenge_account.group, Some(group));
+
+    // Only group admins may attach a challenge to the group
+    let stranger = Keypair::new();
+    fund(&mut context, &stranger.pubkey(), 2 * LAMPORTS_PER_SOL);
+    let start_time = now(&mut context).await + 60;
+    let create_ix = ix::create_meme_challenge(
+        context.payer.pubkey(),
+        stranger.pubkey(),
+        Some(group),
+        None,
+        None,
+        challenge_args(start_time),
+    );
+    let result = process(&mut context, &[create_ix], &[&stranger]).await;
+    assert_error(result, ErrorCode::NotGroupAdmin);
+
+    // A membership of some other group does not stand in for one of this group
+    let other_group = create_group(&mut context, &stranger, group_args("others")).await;
+    join_group(&mut context, &stranger, other_group).await;
+    let mut create_ix = ix::create_group_admin_meme_challenge(
+        context.payer.pubkey(),
+        stranger.pubkey(),
+        group,
+        challenge_args(start_time),
+    );
+    let member_record = pda::group_member(&group, &stranger.pubkey());
+    for account in &mut create_ix.accounts {
+        if account.pubkey == member_record {
+            account.pubkey = pda::group_member(&other_group, &stranger.pubkey());
+        }
+    }
+    let result = process(&mut context, &[create_ix], &[&stranger]).await;
+    assert_error(result, ErrorCode::NotGroupAdmin);
+
+    join_group(&mut context, &stranger, group).await;
+    add_group_admin(
+        &mut context,
+        &creator,
+        group,
+        &stranger.pubkey(),
+        GroupRole::Admin,
+    )
+    .await;
+    let create_ix = ix::create_group_admin_meme_challenge(
+        context.payer.pubkey(),
+        stranger.pubkey(),
+        group,
+        challenge_args(start_time),
+    );
+    process(&mut context, &[create_ix], &[&stranger])
+        .await
+        .unwrap();
+    let challenge_account: MemeChallenge = fetch(
+        &mut context,
+        pda::meme_challenge(&stranger.pubkey(), start_time),
+    )
+    .await;
+    assert_eq!(challenge_account.group, Some(group));
+}
@@ -0,0 +1,68 @@
+mod common;
+
+use chumchon::{error::ErrorCode, instruction, UserProfile};
+use common::{assert_error, fetch, ix, pda, process, start};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn create_user_profile_success() {
+    let mut context = start().await;
+    let owner = Keypair::new();
+
+    let create_ix = ix::create_user_profile(
+        context.payer.pubkey(),
+        owner.pubkey(),
+        instruction::CreateUserProfile {
+            username: "chum".to_string(),
+            bio: "gm".to_string(),
+            show_balance: true,
+        },
+    );
+    process(&mut context, &[create_ix], &[&owner])
+        .await
+        .unwrap();
+
+    let profile: UserProfile = fetch(&mut context, pda::user_profile(&owner.pubkey())).await;
+    assert_eq!(profile.owner, owner.pubkey());
+    assert_eq!(profile.username, "chum");
+    assert_eq!(profile.bio, "gm");
+    assert!(profile.show_balance);
+    assert!(profile.completed_tutorials.is_empty());
+}
+
+#[tokio::test]
+async fn create_user_profile_rejects_long_username() {
+    let mut context = start().await;
+    let owner = Keypair::new();
+
+    let create_ix = ix::create_user_profile(
+        context.payer.pubkey(),
+        owner.pubkey(),
+        instruction::CreateUserProfile {
+            username: "a".repeat(51),
+            bio: "gm".to_string(),
+            show_balance: false,
+        },
+    );
+    let result = process(&mut context, &[create_ix], &[&owner]).await;
+    assert_error(result, ErrorCode::NameTooLong);
+}
+
+#[tokio::test]
+async fn create_user_profile_rejects_long_bio() {
+    let mut context = start().await;
+    let owner = Keypair::new();
+
+    let create_ix = ix::create_user_profile(
+        context.payer.pubkey(),
+        owner.pubkey(),
+        instruction::CreateUserProfile {
+            username: "chum".to_string(),
+            bio: "a".repeat(201),
+            show_balance: false,
+        },
+    );
+    let result = process(&mut context, &[create_ix], &[&owner]).await;
+    assert_error(result, ErrorCode::BioTooLong);
+}
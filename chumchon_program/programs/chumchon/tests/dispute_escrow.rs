@@ -0,0 +1,61 @@
+mod common;
+
+use chumchon::{error::ErrorCode, Escrow, EscrowStatus};
+use common::{assert_error, create_escrow, fetch, now, process, start};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn dispute_escrow_success() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow(&mut context, expires_at).await;
+    let accept_ix = setup.accept_ix(context.payer.pubkey());
+    process(&mut context, &[accept_ix], &[&setup.counterparty])
+        .await
+        .unwrap();
+
+    let dispute_ix = setup.dispute_ix(setup.counterparty.pubkey());
+    process(&mut context, &[dispute_ix], &[&setup.counterparty])
+        .await
+        .unwrap();
+
+    let escrow: Escrow = fetch(&mut context, setup.escrow).await;
+    assert_eq!(escrow.status, EscrowStatus::Disputed);
+    assert!(escrow.disputed_at.is_some());
+
+    // Neither party can settle or back out of a disputed trade
+    let complete_ix = setup.complete_ix(context.payer.pubkey(), setup.initiator.pubkey());
+    let result = process(&mut context, &[complete_ix], &[&setup.initiator]).await;
+    assert_error(result, ErrorCode::EscrowDisputed);
+    let cancel_ix = setup.cancel_ix(context.payer.pubkey(), setup.initiator.pubkey());
+    let result = process(&mut context, &[cancel_ix], &[&setup.initiator]).await;
+    assert_error(result, ErrorCode::EscrowDisputed);
+}
+
+#[tokio::test]
+async fn dispute_escrow_requires_acceptance() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow(&mut context, expires_at).await;
+
+    let dispute_ix = setup.dispute_ix(setup.initiator.pubkey());
+    let result = process(&mut context, &[dispute_ix], &[&setup.initiator]).await;
+    assert_error(result, ErrorCode::EscrowNotAccepted);
+}
+
+#[tokio::test]
+async fn dispute_escrow_requires_party() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow(&mut context, expires_at).await;
+    let accept_ix = setup.accept_ix(context.payer.pubkey());
+    process(&mut context, &[accept_ix], &[&setup.counterparty])
+        .await
+        .unwrap();
+
+    let stranger = Keypair::new();
+    let dispute_ix = setup.dispute_ix(stranger.pubkey());
+    let result = process(&mut context, &[dispute_ix], &[&stranger]).await;
+    assert_error(result, ErrorCode::Unauthorized);
+}
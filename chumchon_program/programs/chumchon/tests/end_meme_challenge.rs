@@ -0,0 +1,453 @@
+mod common;
+
+use chumchon::{error::ErrorCode, instruction, MemeChallenge};
+use common::{
+    account_exists, assert_error, balance, challenge_args, create_meme_challenge,
+    create_meme_challenge_with_split, create_mint, create_token_account, fetch, ix, mint_to, now,
+    pda, process, start, submit_meme, token_balance, vote_for_meme, warp_to_timestamp,
+    LAMPORTS_PER_SOL,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn end_meme_challenge_success() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+
+    let end_ix = ix::end_meme_challenge(
+        context.payer.pubkey(),
+        challenge,
+        context.payer.pubkey(),
+        false,
+        &[submitter.pubkey()],
+    );
+    let result = process(&mut context, std::slice::from_ref(&end_ix), &[]).await;
+    assert_error(result, ErrorCode::ChallengeNotEnded);
+
+    // Still too early while voting is open
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+    let result = process(&mut context, std::slice::from_ref(&end_ix), &[]).await;
+    assert_error(result, ErrorCode::ChallengeNotEnded);
+
+    warp_to_timestamp(&mut context, challenge_account.voting_end).await;
+    process(&mut context, std::slice::from_ref(&end_ix), &[])
+        .await
+        .unwrap();
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert!(challenge_account.completed);
+    assert_eq!(challenge_account.winners, vec![submitter.pubkey()]);
+    assert_eq!(
+        balance(&mut context, submitter.pubkey()).await,
+        LAMPORTS_PER_SOL
+    );
+
+    let result = process(&mut context, &[end_ix], &[]).await;
+    assert_error(result, ErrorCode::ChallengeAlreadyCompleted);
+}
+
+#[tokio::test]
+async fn end_meme_challenge_pays_most_voted_submission() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let first = Keypair::new();
+    let second = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &first, challenge).await;
+    submit_meme(&mut context, &second, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+    vote_for_meme(&mut context, &Keypair::new(), challenge, &second.pubkey()).await;
+    warp_to_timestamp(&mut context, challenge_account.voting_end).await;
+
+    let end_ix = ix::end_meme_challenge(
+        context.payer.pubkey(),
+        challenge,
+        context.payer.pubkey(),
+        false,
+        &[first.pubkey()],
+    );
+    let result = process(&mut context, &[end_ix], &[]).await;
+    assert_error(result, ErrorCode::InvalidRecipient);
+
+    let end_ix = ix::end_meme_challenge(
+        context.payer.pubkey(),
+        challenge,
+        context.payer.pubkey(),
+        false,
+        &[second.pubkey()],
+    );
+    process(&mut context, &[end_ix], &[]).await.unwrap();
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(challenge_account.winners, vec![second.pubkey()]);
+    assert_eq!(
+        balance(&mut context, second.pubkey()).await,
+        LAMPORTS_PER_SOL
+    );
+}
+
+#[tokio::test]
+async fn end_meme_challenge_breaks_ties_by_earliest_submission() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let early = Keypair::new();
+    let late = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &early, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 60).await;
+    submit_meme(&mut context, &late, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.voting_end).await;
+
+    let end_ix = ix::end_meme_challenge(
+        context.payer.pubkey(),
+        challenge,
+        context.payer.pubkey(),
+        false,
+        &[early.pubkey()],
+    );
+    process(&mut context, &[end_ix], &[]).await.unwrap();
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(challenge_account.winners, vec![early.pubkey()]);
+}
+
+#[tokio::test]
+async fn end_meme_challenge_splits_pool_across_places() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let gold = Keypair::new();
+    let silver = Keypair::new();
+    let bronze = Keypair::new();
+    let unplaced = Keypair::new();
+    let challenge =
+        create_meme_challenge_with_split(&mut context, &creator, vec![5_000, 3_000, 2_000]).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &unplaced, challenge).await;
+    submit_meme(&mut context, &bronze, challenge).await;
+    submit_meme(&mut context, &silver, challenge).await;
+    submit_meme(&mut context, &gold, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+    for _ in 0..3 {
+        vote_for_meme(&mut context, &Keypair::new(), challenge, &gold.pubkey()).await;
+    }
+    for _ in 0..2 {
+        vote_for_meme(&mut context, &Keypair::new(), challenge, &silver.pubkey()).await;
+    }
+    vote_for_meme(&mut context, &Keypair::new(), challenge, &bronze.pubkey()).await;
+    warp_to_timestamp(&mut context, challenge_account.voting_end).await;
+
+    // Every paid place needs its recipient
+    let end_ix = ix::end_meme_challenge(
+        context.payer.pubkey(),
+        challenge,
+        context.payer.pubkey(),
+        false,
+        &[gold.pubkey(), silver.pubkey()],
+    );
+    let result = process(&mut context, &[end_ix], &[]).await;
+    assert_error(result, ErrorCode::InvalidRecipient);
+
+    let end_ix = ix::end_meme_challenge(
+        context.payer.pubkey(),
+        challenge,
+        context.payer.pubkey(),
+        false,
+        &[gold.pubkey(), silver.pubkey(), bronze.pubkey()],
+    );
+    process(&mut context, &[end_ix], &[]).await.unwrap();
+
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(
+        challenge_account.winners,
+        vec![gold.pubkey(), silver.pubkey(), bronze.pubkey()]
+    );
+    assert_eq!(
+        balance(&mut context, gold.pubkey()).await,
+        LAMPORTS_PER_SOL / 2
+    );
+    assert_eq!(
+        balance(&mut context, silver.pubkey()).await,
+        LAMPORTS_PER_SOL * 3 / 10
+    );
+    assert_eq!(
+        balance(&mut context, bronze.pubkey()).await,
+        LAMPORTS_PER_SOL / 5
+    );
+    assert_eq!(balance(&mut context, unplaced.pubkey()).await, 0);
+}
+
+#[tokio::test]
+async fn end_meme_challenge_gives_unreached_places_to_first() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let challenge =
+        create_meme_challenge_with_split(&mut context, &creator, vec![6_000, 4_000]).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.voting_end).await;
+
+    let end_ix = ix::end_meme_challenge(
+        context.payer.pubkey(),
+        challenge,
+        context.payer.pubkey(),
+        false,
+        &[submitter.pubkey()],
+    );
+    process(&mut context, &[end_ix], &[]).await.unwrap();
+    assert_eq!(
+        balance(&mut context, submitter.pubkey()).await,
+        LAMPORTS_PER_SOL
+    );
+}
+
+#[tokio::test]
+async fn end_meme_challenge_pays_token_pool() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let first = Keypair::new();
+    let second = Keypair::new();
+    let mint_authority = Keypair::new();
+    let mint = create_mint(&mut context, &mint_authority.pubkey()).await;
+    let creator_token_account = create_token_account(&mut context, &mint, &creator.pubkey()).await;
+    let first_token_account = create_token_account(&mut context, &mint, &first.pubkey()).await;
+    let second_token_account = create_token_account(&mut context, &mint, &second.pubkey()).await;
+    mint_to(
+        &mut context,
+        &mint,
+        &creator_token_account,
+        &mint_authority,
+        500,
+    )
+    .await;
+    let start_time = now(&mut context).await + 60;
+    let create_ix = ix::create_meme_challenge(
+        context.payer.pubkey(),
+        creator.pubkey(),
+        None,
+        Some(mint),
+        Some(creator_token_account),
+        instruction::CreateMemeChallenge {
+            reward_amount: 500,
+            prize_split_bps: vec![7_000, 3_000],
+            ..challenge_args(start_time)
+        },
+    );
+    process(&mut context, &[create_ix], &[&creator])
+        .await
+        .unwrap();
+    let challenge = pda::meme_challenge(&creator.pubkey(), start_time);
+    warp_to_timestamp(&mut context, start_time + 1).await;
+    submit_meme(&mut context, &first, challenge).await;
+    submit_meme(&mut context, &second, challenge).await;
+    warp_to_timestamp(&mut context, start_time + 7200).await;
+
+    let end_ix = ix::end_meme_challenge(
+        context.payer.pubkey(),
+        challenge,
+        context.payer.pubkey(),
+        true,
+        &[second_token_account, first_token_account],
+    );
+    let result = process(&mut context, &[end_ix], &[]).await;
+    assert_error(result, ErrorCode::InvalidRecipient);
+
+    let end_ix = ix::end_meme_challenge(
+        context.payer.pubkey(),
+        challenge,
+        context.payer.pubkey(),
+        true,
+        &[first_token_account, second_token_account],
+    );
+    process(&mut context, &[end_ix], &[]).await.unwrap();
+
+    assert_eq!(token_balance(&mut context, first_token_account).await, 350);
+    assert_eq!(token_balance(&mut context, second_token_account).await, 150);
+    assert!(!account_exists(&mut context, pda::challenge_vault(&challenge)).await);
+}
+
+#[tokio::test]
+async fn end_meme_challenge_gives_tokens_sent_to_the_vault_to_first() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let first = Keypair::new();
+    let mint_authority = Keypair::new();
+    let mint = create_mint(&mut context, &mint_authority.pubkey()).await;
+    let creator_token_account = create_token_account(&mut context, &mint, &creator.pubkey()).await;
+    let first_token_account = create_token_account(&mut context, &mint, &first.pubkey()).await;
+    mint_to(
+        &mut context,
+        &mint,
+        &creator_token_account,
+        &mint_authority,
+        500,
+    )
+    .await;
+    let start_time = now(&mut context).await + 60;
+    let create_ix = ix::create_meme_challenge(
+        context.payer.pubkey(),
+        creator.pubkey(),
+        None,
+        Some(mint),
+        Some(creator_token_account),
+        instruction::CreateMemeChallenge {
+            reward_amount: 500,
+            ..challenge_args(start_time)
+        },
+    );
+    process(&mut context, &[create_ix], &[&creator])
+        .await
+        .unwrap();
+    let challenge = pda::meme_challenge(&creator.pubkey(), start_time);
+    let vault = pda::challenge_vault(&challenge);
+    mint_to(&mut context, &mint, &vault, &mint_authority, 25).await;
+    warp_to_timestamp(&mut context, start_time + 1).await;
+    submit_meme(&mut context, &first, challenge).await;
+    warp_to_timestamp(&mut context, start_time + 7200).await;
+
+    let end_ix = ix::end_meme_challenge(
+        context.payer.pubkey(),
+        challenge,
+        context.payer.pubkey(),
+        true,
+        &[first_token_account],
+    );
+    process(&mut context, &[end_ix], &[]).await.unwrap();
+
+    assert_eq!(token_balance(&mut context, first_token_account).await, 525);
+    assert!(!account_exists(&mut context, vault).await);
+}
+
+#[tokio::test]
+async fn end_meme_challenge_ranks_every_submission_after_a_drop() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let first = Keypair::new();
+    let second = Keypair::new();
+    let third = Keypair::new();
+    let retracting_voter = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &first, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 60).await;
+    submit_meme(&mut context, &second, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 120).await;
+    submit_meme(&mut context, &third, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+    vote_for_meme(&mut context, &Keypair::new(), challenge, &first.pubkey()).await;
+    for _ in 0..2 {
+        vote_for_meme(&mut context, &Keypair::new(), challenge, &second.pubkey()).await;
+        vote_for_meme(&mut context, &Keypair::new(), challenge, &third.pubkey()).await;
+    }
+    vote_for_meme(&mut context, &retracting_voter, challenge, &third.pubkey()).await;
+    let change_ix = ix::change_meme_vote(
+        retracting_voter.pubkey(),
+        context.payer.pubkey(),
+        challenge,
+        None,
+        third.pubkey(),
+        None,
+    );
+    process(&mut context, &[change_ix], &[&retracting_voter])
+        .await
+        .unwrap();
+    // Ranking the weakest submission while votes can still move fills the open place,
+    // but does not count towards rebuilding the board
+    let rank_ix = ix::rank_meme_submission(challenge, first.pubkey());
+    process(&mut context, &[rank_ix], &[]).await.unwrap();
+    warp_to_timestamp(&mut context, challenge_account.voting_end).await;
+
+    let end_ix = ix::end_meme_challenge(
+        context.payer.pubkey(),
+        challenge,
+        context.payer.pubkey(),
+        false,
+        &[first.pubkey()],
+    );
+    let result = process(&mut context, &[end_ix], &[]).await;
+    assert_error(result, ErrorCode::LeaderboardIncomplete);
+
+    // Ranking the same submission twice counts it once
+    for submitter in [&first, &third, &third] {
+        let rank_ix = ix::rank_meme_submission(challenge, submitter.pubkey());
+        process(&mut context, &[rank_ix], &[]).await.unwrap();
+    }
+    let end_ix = ix::end_meme_challenge(
+        context.payer.pubkey(),
+        challenge,
+        context.payer.pubkey(),
+        false,
+        &[third.pubkey()],
+    );
+    let result = process(&mut context, &[end_ix], &[]).await;
+    assert_error(result, ErrorCode::LeaderboardIncomplete);
+
+    // Once every submission is ranked, the earlier of the two level on votes wins
+    let rank_ix = ix::rank_meme_submission(challenge, second.pubkey());
+    process(&mut context, &[rank_ix], &[]).await.unwrap();
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert!(!challenge_account.leaderboard_stale);
+
+    let end_ix = ix::end_meme_challenge(
+        context.payer.pubkey(),
+        challenge,
+        context.payer.pubkey(),
+        false,
+        &[second.pubkey()],
+    );
+    process(&mut context, &[end_ix], &[]).await.unwrap();
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(challenge_account.winners, vec![second.pubkey()]);
+}
+
+#[tokio::test]
+async fn end_meme_challenge_requires_submissions() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.voting_end).await;
+
+    let end_ix = ix::end_meme_challenge(
+        context.payer.pubkey(),
+        challenge,
+        context.payer.pubkey(),
+        false,
+        &[],
+    );
+    let result = process(&mut context, &[end_ix], &[]).await;
+    assert_error(result, ErrorCode::NoSubmissions);
+}
+
+#[tokio::test]
+async fn end_meme_challenge_requires_rent_payer() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let stranger = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.voting_end).await;
+
+    let end_ix = ix::end_meme_challenge(
+        context.payer.pubkey(),
+        challenge,
+        stranger.pubkey(),
+        false,
+        &[submitter.pubkey()],
+    );
+    let result = process(&mut context, &[end_ix], &[]).await;
+    assert_error(result, ErrorCode::NotOwner);
+}
@@ -0,0 +1,187 @@
+mod common;
+
+use chumchon::{error::ErrorCode, instruction, Group, GroupMember, GroupRole};
+use common::{
+    assert_error, create_group, create_mint, create_token_account, fetch, fund, group_args, ix,
+    mint_to, pda, process, start, LAMPORTS_PER_SOL,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn join_group_success() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let member = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+
+    let join_ix = ix::join_group(context.payer.pubkey(), member.pubkey(), group, None, None);
+    process(&mut context, &[join_ix], &[&member]).await.unwrap();
+
+    let record: GroupMember =
+        fetch(&mut context, pda::group_member(&group, &member.pubkey())).await;
+    assert_eq!(record.group, group);
+    assert_eq!(record.member, member.pubkey());
+    assert_eq!(record.role, GroupRole::Member);
+    let group: Group = fetch(&mut context, group).await;
+    assert_eq!(group.member_count, 1);
+}
+
+#[tokio::test]
+async fn join_group_as_owner() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+
+    let join_ix = ix::join_group(context.payer.pubkey(), creator.pubkey(), group, None, None);
+    process(&mut context, &[join_ix], &[&creator])
+        .await
+        .unwrap();
+
+    // The owner's role comes from the group, so it leaves with ownership
+    let record: GroupMember =
+        fetch(&mut context, pda::group_member(&group, &creator.pubkey())).await;
+    assert_eq!(record.role, GroupRole::Member);
+    let group_account: Group = fetch(&mut context, group).await;
+    assert_eq!(group_account.owner, creator.pubkey());
+}
+
+#[tokio::test]
+async fn join_whale_group_requires_sol_balance() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let whale = Keypair::new();
+    let minnow = Keypair::new();
+    let group = create_group(
+        &mut context,
+        &creator,
+        instruction::CreateGroup {
+            is_whale_group: true,
+            required_sol_balance: 10 * LAMPORTS_PER_SOL,
+            ..group_args("whales")
+        },
+    )
+    .await;
+    fund(&mut context, &whale.pubkey(), 10 * LAMPORTS_PER_SOL);
+    fund(&mut context, &minnow.pubkey(), LAMPORTS_PER_SOL);
+
+    let join_ix = ix::join_group(context.payer.pubkey(), whale.pubkey(), group, None, None);
+    process(&mut context, &[join_ix], &[&whale]).await.unwrap();
+
+    let join_ix = ix::join_group(context.payer.pubkey(), minnow.pubkey(), group, None, None);
+    let result = process(&mut context, &[join_ix], &[&minnow]).await;
+    assert_error(result, ErrorCode::InsufficientSolBalance);
+}
+
+#[tokio::test]
+async fn join_token_gated_group() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let member = Keypair::new();
+    let mint_authority = Keypair::new();
+    let mint = create_mint(&mut context, &mint_authority.pubkey()).await;
+    let other_mint = create_mint(&mut context, &mint_authority.pubkey()).await;
+    let group = create_group(
+        &mut context,
+        &creator,
+        instruction::CreateGroup {
+            required_token: Some(mint),
+            required_amount: 500,
+            ..group_args("holders")
+        },
+    )
+    .await;
+    let token_account = create_token_account(&mut context, &mint, &member.pubkey()).await;
+    let other_account = create_token_account(&mut context, &other_mint, &member.pubkey()).await;
+    mint_to(&mut context, &mint, &token_account, &mint_authority, 499).await;
+    mint_to(
+        &mut context,
+        &other_mint,
+        &other_account,
+        &mint_authority,
+        500,
+    )
+    .await;
+
+    let join_ix = ix::join_group(
+        context.payer.pubkey(),
+        member.pubkey(),
+        group,
+        Some(other_account),
+        None,
+    );
+    let result = process(&mut context, &[join_ix], &[&member]).await;
+    assert_error(result, ErrorCode::InvalidToken);
+
+    let join_ix = ix::join_group(
+        context.payer.pubkey(),
+        member.pubkey(),
+        group,
+        Some(token_account),
+        None,
+    );
+    let result = process(&mut context, &[join_ix], &[&member]).await;
+    assert_error(result, ErrorCode::InsufficientTokenBalance);
+
+    mint_to(&mut context, &mint, &token_account, &mint_authority, 1).await;
+    let join_ix = ix::join_group(
+        context.payer.pubkey(),
+        member.pubkey(),
+        group,
+        Some(token_account),
+        None,
+    );
+    process(&mut context, &[join_ix], &[&member]).await.unwrap();
+}
+
+#[tokio::test]
+async fn join_nft_gated_group() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let member = Keypair::new();
+    let mint_authority = Keypair::new();
+    let collection = create_mint(&mut context, &mint_authority.pubkey()).await;
+    let other_nft = create_mint(&mut context, &mint_authority.pubkey()).await;
+    let group = create_group(
+        &mut context,
+        &creator,
+        instruction::CreateGroup {
+            required_nft_collection: Some(collection),
+            ..group_args("collectors")
+        },
+    )
+    .await;
+    let nft_account = create_token_account(&mut context, &collection, &member.pubkey()).await;
+    let other_account = create_token_account(&mut context, &other_nft, &member.pubkey()).await;
+    mint_to(&mut context, &other_nft, &other_account, &mint_authority, 1).await;
+
+    let join_ix = ix::join_group(
+        context.payer.pubkey(),
+        member.pubkey(),
+        group,
+        None,
+        Some(other_account),
+    );
+    let result = process(&mut context, &[join_ix], &[&member]).await;
+    assert_error(result, ErrorCode::InvalidNFT);
+
+    let join_ix = ix::join_group(
+        context.payer.pubkey(),
+        member.pubkey(),
+        group,
+        None,
+        Some(nft_account),
+    );
+    let result = process(&mut context, &[join_ix], &[&member]).await;
+    assert_error(result, ErrorCode::NoNFT);
+
+    mint_to(&mut context, &collection, &nft_account, &mint_authority, 1).await;
+    let join_ix = ix::join_group(
+        context.payer.pubkey(),
+        member.pubkey(),
+        group,
+        None,
+        Some(nft_account),
+    );
+    process(&mut context, &[join_ix], &[&member]).await.unwrap();
+}
@@ -0,0 +1,103 @@
+mod common;
+
+use chumchon::{error::ErrorCode, Group, GroupRole};
+use common::{
+    account_exists, add_group_admin, assert_error, create_group, fetch, group_args, ix, join_group,
+    pda, process, start,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn kick_member_success() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let moderator = Keypair::new();
+    let member = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    join_group(&mut context, &moderator, group).await;
+    join_group(&mut context, &member, group).await;
+    add_group_admin(
+        &mut context,
+        &creator,
+        group,
+        &moderator.pubkey(),
+        GroupRole::Moderator,
+    )
+    .await;
+
+    let payer = context.payer.pubkey();
+    let kick_ix = ix::kick_member(moderator.pubkey(), group, true, member.pubkey(), payer);
+    process(&mut context, &[kick_ix], &[&moderator])
+        .await
+        .unwrap();
+
+    assert!(!account_exists(&mut context, pda::group_member(&group, &member.pubkey())).await);
+    let group_account: Group = fetch(&mut context, group).await;
+    assert_eq!(group_account.member_count, 1);
+
+    // A kicked member is free to come back
+    join_group(&mut context, &member, group).await;
+}
+
+#[tokio::test]
+async fn kick_member_requires_higher_role() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let moderator = Keypair::new();
+    let other_moderator = Keypair::new();
+    let member = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    join_group(&mut context, &moderator, group).await;
+    join_group(&mut context, &other_moderator, group).await;
+    join_group(&mut context, &member, group).await;
+    add_group_admin(
+        &mut context,
+        &creator,
+        group,
+        &moderator.pubkey(),
+        GroupRole::Moderator,
+    )
+    .await;
+    add_group_admin(
+        &mut context,
+        &creator,
+        group,
+        &other_moderator.pubkey(),
+        GroupRole::Moderator,
+    )
+    .await;
+    let payer = context.payer.pubkey();
+
+    // Plain members cannot kick
+    let kick_ix = ix::kick_member(
+        member.pubkey(),
+        group,
+        true,
+        other_moderator.pubkey(),
+        payer,
+    );
+    let result = process(&mut context, &[kick_ix], &[&member]).await;
+    assert_error(result, ErrorCode::NotGroupAdmin);
+
+    let kick_ix = ix::kick_member(
+        moderator.pubkey(),
+        group,
+        true,
+        other_moderator.pubkey(),
+        payer,
+    );
+    let result = process(&mut context, &[kick_ix], &[&moderator]).await;
+    assert_error(result, ErrorCode::NotGroupAdmin);
+
+    let kick_ix = ix::kick_member(
+        creator.pubkey(),
+        group,
+        false,
+        other_moderator.pubkey(),
+        payer,
+    );
+    process(&mut context, &[kick_ix], &[&creator])
+        .await
+        .unwrap();
+}
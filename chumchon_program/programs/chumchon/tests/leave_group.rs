@@ -0,0 +1,57 @@
+mod common;
+
+use chumchon::{error::ErrorCode, Group};
+use common::{
+    account_exists, assert_error, balance, create_group, fetch, fund, group_args, ix, join_group,
+    pda, process, start, LAMPORTS_PER_SOL,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn leave_group_success() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let sponsor = Keypair::new();
+    let member = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    fund(&mut context, &sponsor.pubkey(), LAMPORTS_PER_SOL);
+    let join_ix = ix::join_group(sponsor.pubkey(), member.pubkey(), group, None, None);
+    process(&mut context, &[join_ix], &[&sponsor, &member])
+        .await
+        .unwrap();
+
+    let member_record = pda::group_member(&group, &member.pubkey());
+    let record_rent = balance(&mut context, member_record).await;
+    let sponsor_before = balance(&mut context, sponsor.pubkey()).await;
+    let leave_ix = ix::leave_group(member.pubkey(), group, sponsor.pubkey());
+    process(&mut context, &[leave_ix], &[&member])
+        .await
+        .unwrap();
+
+    assert!(!account_exists(&mut context, member_record).await);
+    assert_eq!(
+        balance(&mut context, sponsor.pubkey()).await - sponsor_before,
+        record_rent
+    );
+    let group_account: Group = fetch(&mut context, group).await;
+    assert_eq!(group_account.member_count, 0);
+
+    // Leaving does not stop the member coming back
+    join_group(&mut context, &member, group).await;
+    let group_account: Group = fetch(&mut context, group).await;
+    assert_eq!(group_account.member_count, 1);
+}
+
+#[tokio::test]
+async fn leave_group_requires_rent_payer() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let member = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    join_group(&mut context, &member, group).await;
+
+    let leave_ix = ix::leave_group(member.pubkey(), group, member.pubkey());
+    let result = process(&mut context, &[leave_ix], &[&member]).await;
+    assert_error(result, ErrorCode::NotOwner);
+}
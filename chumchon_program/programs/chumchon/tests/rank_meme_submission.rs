@@ -0,0 +1,163 @@
+mod common;
+
+use chumchon::{error::ErrorCode, MemeChallenge};
+use common::{
+    assert_error, create_meme_challenge, fetch, ix, pda, process, start, submit_meme,
+    vote_for_meme, warp_to_timestamp,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn rank_meme_submission_after_retracted_vote() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let early = Keypair::new();
+    let late = Keypair::new();
+    let retracting_voter = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &early, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 60).await;
+    submit_meme(&mut context, &late, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+    vote_for_meme(&mut context, &Keypair::new(), challenge, &early.pubkey()).await;
+    vote_for_meme(&mut context, &Keypair::new(), challenge, &late.pubkey()).await;
+    vote_for_meme(&mut context, &retracting_voter, challenge, &late.pubkey()).await;
+
+    let change_ix = ix::change_meme_vote(
+        retracting_voter.pubkey(),
+        context.payer.pubkey(),
+        challenge,
+        None,
+        late.pubkey(),
+        None,
+    );
+    process(&mut context, &[change_ix], &[&retracting_voter])
+        .await
+        .unwrap();
+
+    // Level on votes, the early submission may now lead, so the late one gives up its place
+    let early_submission = pda::meme_submission(&challenge, &early.pubkey());
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert!(challenge_account.leaderboard.is_empty());
+
+    let rank_ix = ix::rank_meme_submission(challenge, early.pubkey());
+    process(&mut context, &[rank_ix], &[]).await.unwrap();
+
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(challenge_account.leaderboard.len(), 1);
+    assert_eq!(
+        challenge_account.leaderboard[0].submission,
+        early_submission
+    );
+    assert_eq!(challenge_account.leaderboard[0].votes, 1);
+}
+
+#[tokio::test]
+async fn rank_meme_submission_after_completion() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.voting_end).await;
+    let end_ix = ix::end_meme_challenge(
+        context.payer.pubkey(),
+        challenge,
+        context.payer.pubkey(),
+        false,
+        &[submitter.pubkey()],
+    );
+    process(&mut context, &[end_ix], &[]).await.unwrap();
+
+    let rank_ix = ix::rank_meme_submission(challenge, submitter.pubkey());
+    let result = process(&mut context, &[rank_ix], &[]).await;
+    assert_error(result, ErrorCode::ChallengeAlreadyCompleted);
+}
+
+#[tokio::test]
+async fn rank_meme_submission_rebuilds_board_across_transactions() {
+    // More submissions than one transaction can lock, so the board must be rebuilt in parts
+    const SUBMISSIONS: usize = 70;
+    const PER_TRANSACTION: usize = 10;
+
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let retracting_voter = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    let submitters: Vec<Keypair> = (0..SUBMISSIONS).map(|_| Keypair::new()).collect();
+    for submitter in &submitters[..SUBMISSIONS - 1] {
+        submit_meme(&mut context, submitter, challenge).await;
+    }
+    warp_to_timestamp(&mut context, challenge_account.start_time + 60).await;
+    let latest = &submitters[SUBMISSIONS - 1];
+    submit_meme(&mut context, latest, challenge).await;
+
+    // The leader loses a vote and drops off, leaving the latest submission ahead unseen
+    let leader = fetch::<MemeChallenge>(&mut context, challenge)
+        .await
+        .leaderboard[0]
+        .submitter;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+    vote_for_meme(&mut context, &Keypair::new(), challenge, &leader).await;
+    vote_for_meme(&mut context, &retracting_voter, challenge, &leader).await;
+    for _ in 0..2 {
+        vote_for_meme(&mut context, &Keypair::new(), challenge, &latest.pubkey()).await;
+    }
+    let change_ix = ix::change_meme_vote(
+        retracting_voter.pubkey(),
+        context.payer.pubkey(),
+        challenge,
+        None,
+        leader,
+        None,
+    );
+    process(&mut context, &[change_ix], &[&retracting_voter])
+        .await
+        .unwrap();
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert!(challenge_account.leaderboard_stale);
+
+    warp_to_timestamp(&mut context, challenge_account.voting_end).await;
+    for (i, chunk) in submitters.chunks(PER_TRANSACTION).enumerate() {
+        let end_ix = ix::end_meme_challenge(
+            context.payer.pubkey(),
+            challenge,
+            context.payer.pubkey(),
+            false,
+            &[latest.pubkey()],
+        );
+        let result = process(&mut context, &[end_ix], &[]).await;
+        assert_error(result, ErrorCode::LeaderboardIncomplete);
+
+        let rank_ixs: Vec<_> = chunk
+            .iter()
+            .map(|submitter| ix::rank_meme_submission(challenge, submitter.pubkey()))
+            .collect();
+        process(&mut context, &rank_ixs, &[]).await.unwrap();
+        let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+        assert_eq!(
+            challenge_account.ranked_count as usize,
+            i * PER_TRANSACTION + chunk.len()
+        );
+    }
+
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert!(!challenge_account.leaderboard_stale);
+    let end_ix = ix::end_meme_challenge(
+        context.payer.pubkey(),
+        challenge,
+        context.payer.pubkey(),
+        false,
+        &[latest.pubkey()],
+    );
+    process(&mut context, &[end_ix], &[]).await.unwrap();
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(challenge_account.winners, vec![latest.pubkey()]);
+}
@@ -0,0 +1,143 @@
+mod common;
+
+use chumchon::error::ErrorCode;
+use common::{
+    account_exists, assert_error, balance, create_escrow, create_escrow_with_legs, now, pda,
+    process, start, token_balance, warp_to_timestamp, Leg, LAMPORTS_PER_SOL,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::Signer;
+
+#[tokio::test]
+async fn reclaim_expired_escrow_refunds_initiator() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow(&mut context, expires_at).await;
+    warp_to_timestamp(&mut context, expires_at + 1).await;
+
+    let reclaim_ix = setup.reclaim_ix(context.payer.pubkey(), false);
+    process(&mut context, &[reclaim_ix], &[]).await.unwrap();
+
+    assert_eq!(
+        token_balance(&mut context, setup.initiator_leg.unwrap().deposit_account).await,
+        100
+    );
+    assert!(!account_exists(&mut context, setup.escrow).await);
+    assert!(!account_exists(&mut context, pda::initiator_vault(&setup.escrow)).await);
+}
+
+#[tokio::test]
+async fn reclaim_expired_escrow_refunds_both_sides() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow(&mut context, expires_at).await;
+    let accept_ix = setup.accept_ix(context.payer.pubkey());
+    process(&mut context, &[accept_ix], &[&setup.counterparty])
+        .await
+        .unwrap();
+    warp_to_timestamp(&mut context, expires_at + 1).await;
+
+    let reclaim_ix = setup.reclaim_ix(context.payer.pubkey(), true);
+    process(&mut context, &[reclaim_ix], &[]).await.unwrap();
+
+    assert_eq!(
+        token_balance(&mut context, setup.initiator_leg.unwrap().deposit_account).await,
+        100
+    );
+    assert_eq!(
+        token_balance(
+            &mut context,
+            setup.counterparty_leg.unwrap().deposit_account
+        )
+        .await,
+        250
+    );
+    assert!(!account_exists(&mut context, setup.escrow).await);
+    assert!(!account_exists(&mut context, pda::counterparty_vault(&setup.escrow)).await);
+}
+
+#[tokio::test]
+async fn reclaim_expired_escrow_refunds_undecided_dispute() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow(&mut context, expires_at).await;
+    let accept_ix = setup.accept_ix(context.payer.pubkey());
+    process(&mut context, &[accept_ix], &[&setup.counterparty])
+        .await
+        .unwrap();
+    let dispute_ix = setup.dispute_ix(setup.counterparty.pubkey());
+    process(&mut context, &[dispute_ix], &[&setup.counterparty])
+        .await
+        .unwrap();
+    warp_to_timestamp(&mut context, expires_at + 1).await;
+
+    let reclaim_ix = setup.reclaim_ix(context.payer.pubkey(), true);
+    process(&mut context, &[reclaim_ix], &[]).await.unwrap();
+
+    assert_eq!(
+        token_balance(&mut context, setup.initiator_leg.unwrap().deposit_account).await,
+        100
+    );
+    assert_eq!(
+        token_balance(
+            &mut context,
+            setup.counterparty_leg.unwrap().deposit_account
+        )
+        .await,
+        250
+    );
+    assert!(!account_exists(&mut context, setup.escrow).await);
+    assert!(!account_exists(&mut context, pda::counterparty_vault(&setup.escrow)).await);
+}
+
+#[tokio::test]
+async fn reclaim_expired_escrow_refunds_native_sol() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow_with_legs(&mut context, expires_at, Leg::Sol, Leg::Sol).await;
+    let accept_ix = setup.accept_ix(context.payer.pubkey());
+    process(&mut context, &[accept_ix], &[&setup.counterparty])
+        .await
+        .unwrap();
+    warp_to_timestamp(&mut context, expires_at + 1).await;
+
+    let reclaim_ix = setup.reclaim_ix(context.payer.pubkey(), true);
+    process(&mut context, &[reclaim_ix], &[]).await.unwrap();
+
+    assert_eq!(
+        balance(&mut context, setup.initiator.pubkey()).await,
+        LAMPORTS_PER_SOL
+    );
+    assert_eq!(
+        balance(&mut context, setup.counterparty.pubkey()).await,
+        LAMPORTS_PER_SOL
+    );
+    assert!(!account_exists(&mut context, setup.escrow).await);
+}
+
+#[tokio::test]
+async fn reclaim_expired_escrow_requires_counterparty_accounts() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow(&mut context, expires_at).await;
+    let accept_ix = setup.accept_ix(context.payer.pubkey());
+    process(&mut context, &[accept_ix], &[&setup.counterparty])
+        .await
+        .unwrap();
+    warp_to_timestamp(&mut context, expires_at + 1).await;
+
+    let reclaim_ix = setup.reclaim_ix(context.payer.pubkey(), false);
+    let result = process(&mut context, &[reclaim_ix], &[]).await;
+    assert_error(result, ErrorCode::MissingTokenAccount);
+}
+
+#[tokio::test]
+async fn reclaim_expired_escrow_rejects_live_escrow() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow(&mut context, expires_at).await;
+
+    let reclaim_ix = setup.reclaim_ix(context.payer.pubkey(), false);
+    let result = process(&mut context, &[reclaim_ix], &[]).await;
+    assert_error(result, ErrorCode::EscrowNotExpired);
+}
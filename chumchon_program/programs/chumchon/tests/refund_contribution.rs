@@ -0,0 +1,163 @@
+mod common;
+
+use chumchon::{error::ErrorCode, instruction, MemeChallenge};
+use common::{
+    account_exists, assert_error, balance, challenge_args, contribute_to_challenge,
+    create_meme_challenge, create_mint, create_token_account, fetch, fund, ix, mint_to, now, pda,
+    process, start, submit_meme, token_balance, warp_to_timestamp, LAMPORTS_PER_SOL,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn refund_contribution_without_submissions() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let contributor = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    contribute_to_challenge(&mut context, &contributor, challenge, LAMPORTS_PER_SOL / 2).await;
+    let payer = context.payer.pubkey();
+
+    // Someone could still submit
+    let refund_ix = ix::refund_contribution(contributor.pubkey(), challenge, 0, payer, None);
+    let result = process(
+        &mut context,
+        std::slice::from_ref(&refund_ix),
+        &[&contributor],
+    )
+    .await;
+    assert_error(result, ErrorCode::RefundUnavailable);
+
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+    let contribution = pda::challenge_contribution(&challenge, 0);
+    let record_rent = balance(&mut context, contribution).await;
+    let contributor_before = balance(&mut context, contributor.pubkey()).await;
+    let payer_before = balance(&mut context, payer).await;
+    process(&mut context, &[refund_ix], &[&contributor])
+        .await
+        .unwrap();
+
+    // The record's rent goes back to whoever paid for it
+    assert_eq!(
+        balance(&mut context, contributor.pubkey()).await - contributor_before,
+        LAMPORTS_PER_SOL / 2
+    );
+    assert!(balance(&mut context, payer).await > payer_before + record_rent / 2);
+    assert!(!account_exists(&mut context, contribution).await);
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(challenge_account.reward_amount, LAMPORTS_PER_SOL);
+}
+
+#[tokio::test]
+async fn refund_contribution_token_pool() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let contributor = Keypair::new();
+    let mint_authority = Keypair::new();
+    let mint = create_mint(&mut context, &mint_authority.pubkey()).await;
+    let creator_token_account = create_token_account(&mut context, &mint, &creator.pubkey()).await;
+    let contributor_token_account =
+        create_token_account(&mut context, &mint, &contributor.pubkey()).await;
+    mint_to(
+        &mut context,
+        &mint,
+        &creator_token_account,
+        &mint_authority,
+        500,
+    )
+    .await;
+    mint_to(
+        &mut context,
+        &mint,
+        &contributor_token_account,
+        &mint_authority,
+        200,
+    )
+    .await;
+    fund(&mut context, &creator.pubkey(), LAMPORTS_PER_SOL);
+    let start_time = now(&mut context).await + 60;
+    let create_ix = ix::create_meme_challenge(
+        context.payer.pubkey(),
+        creator.pubkey(),
+        None,
+        Some(mint),
+        Some(creator_token_account),
+        instruction::CreateMemeChallenge {
+            reward_amount: 500,
+            ..challenge_args(start_time)
+        },
+    );
+    process(&mut context, &[create_ix], &[&creator])
+        .await
+        .unwrap();
+    let challenge = pda::meme_challenge(&creator.pubkey(), start_time);
+    let contribute_ix = ix::contribute_to_challenge(
+        context.payer.pubkey(),
+        contributor.pubkey(),
+        challenge,
+        0,
+        Some(contributor_token_account),
+        200,
+    );
+    process(&mut context, &[contribute_ix], &[&contributor])
+        .await
+        .unwrap();
+    warp_to_timestamp(&mut context, start_time + 3600).await;
+
+    let refund_ix = ix::refund_contribution(
+        contributor.pubkey(),
+        challenge,
+        0,
+        context.payer.pubkey(),
+        Some(contributor_token_account),
+    );
+    process(&mut context, &[refund_ix], &[&contributor])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        token_balance(&mut context, contributor_token_account).await,
+        200
+    );
+    assert_eq!(
+        token_balance(&mut context, pda::challenge_vault(&challenge)).await,
+        500
+    );
+}
+
+#[tokio::test]
+async fn refund_contribution_with_submissions() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let contributor = Keypair::new();
+    let submitter = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    contribute_to_challenge(&mut context, &contributor, challenge, LAMPORTS_PER_SOL / 2).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.voting_end).await;
+
+    let payer = context.payer.pubkey();
+    let refund_ix = ix::refund_contribution(contributor.pubkey(), challenge, 0, payer, None);
+    let result = process(&mut context, &[refund_ix], &[&contributor]).await;
+    assert_error(result, ErrorCode::RefundUnavailable);
+}
+
+#[tokio::test]
+async fn refund_contribution_requires_contributor() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let contributor = Keypair::new();
+    let stranger = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    contribute_to_challenge(&mut context, &contributor, challenge, LAMPORTS_PER_SOL / 2).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+
+    let payer = context.payer.pubkey();
+    let refund_ix = ix::refund_contribution(stranger.pubkey(), challenge, 0, payer, None);
+    let result = process(&mut context, &[refund_ix], &[&stranger]).await;
+    assert_error(result, ErrorCode::NotOwner);
+}
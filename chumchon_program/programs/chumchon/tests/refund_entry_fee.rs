@@ -0,0 +1,192 @@
+mod common;
+
+use chumchon::{error::ErrorCode, instruction, MemeChallenge};
+use common::{
+    account_exists, assert_error, balance, challenge_args, create_meme_challenge_with, create_mint,
+    create_token_account, fetch, fund, ix, mint_to, now, pda, process, start, submission_args,
+    submit_meme, token_balance, warp_to_timestamp, LAMPORTS_PER_SOL,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn refund_entry_fee_success() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let challenge = create_meme_challenge_with(&mut context, &creator, |args| {
+        instruction::CreateMemeChallenge {
+            entry_fee: LAMPORTS_PER_SOL / 10,
+            ..args
+        }
+    })
+    .await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    fund(&mut context, &submitter.pubkey(), LAMPORTS_PER_SOL);
+    submit_meme(&mut context, &submitter, challenge).await;
+    let submission = pda::meme_submission(&challenge, &submitter.pubkey());
+    let content_guard = pda::content_guard(&challenge, &submitter.pubkey().to_bytes());
+
+    let payer = context.payer.pubkey();
+    let refund_ix = ix::refund_entry_fee(
+        submitter.pubkey(),
+        challenge,
+        submitter.pubkey().to_bytes(),
+        payer,
+        None,
+    );
+    let result = process(
+        &mut context,
+        std::slice::from_ref(&refund_ix),
+        &[&submitter],
+    )
+    .await;
+    assert_error(result, ErrorCode::ChallengeNotCancelled);
+
+    let cancel_ix = ix::cancel_meme_challenge(creator.pubkey(), challenge);
+    process(&mut context, &[cancel_ix], &[&creator])
+        .await
+        .unwrap();
+    let submission_rent =
+        balance(&mut context, submission).await + balance(&mut context, content_guard).await;
+    let submitter_before = balance(&mut context, submitter.pubkey()).await;
+    let payer_before = balance(&mut context, payer).await;
+    process(
+        &mut context,
+        std::slice::from_ref(&refund_ix),
+        &[&submitter],
+    )
+    .await
+    .unwrap();
+
+    // The fee goes to the submitter, and the rent of the submission and guard to its payer
+    assert_eq!(
+        balance(&mut context, submitter.pubkey()).await - submitter_before,
+        LAMPORTS_PER_SOL / 10
+    );
+    assert!(balance(&mut context, payer).await > payer_before + submission_rent / 2);
+    assert!(!account_exists(&mut context, submission).await);
+    assert!(!account_exists(&mut context, content_guard).await);
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(challenge_account.reward_amount, LAMPORTS_PER_SOL);
+    assert_eq!(challenge_account.submission_count, 0);
+
+    let result = process(&mut context, &[refund_ix], &[&submitter]).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn refund_entry_fee_token_pool() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let mint_authority = Keypair::new();
+    let mint = create_mint(&mut context, &mint_authority.pubkey()).await;
+    let creator_token_account = create_token_account(&mut context, &mint, &creator.pubkey()).await;
+    let submitter_token_account =
+        create_token_account(&mut context, &mint, &submitter.pubkey()).await;
+    mint_to(
+        &mut context,
+        &mint,
+        &creator_token_account,
+        &mint_authority,
+        500,
+    )
+    .await;
+    mint_to(
+        &mut context,
+        &mint,
+        &submitter_token_account,
+        &mint_authority,
+        50,
+    )
+    .await;
+    fund(&mut context, &creator.pubkey(), LAMPORTS_PER_SOL);
+    let start_time = now(&mut context).await + 60;
+    let create_ix = ix::create_meme_challenge(
+        context.payer.pubkey(),
+        creator.pubkey(),
+        None,
+        Some(mint),
+        Some(creator_token_account),
+        instruction::CreateMemeChallenge {
+            reward_amount: 500,
+            entry_fee: 50,
+            ..challenge_args(start_time)
+        },
+    );
+    process(&mut context, &[create_ix], &[&creator])
+        .await
+        .unwrap();
+    let challenge = pda::meme_challenge(&creator.pubkey(), start_time);
+    warp_to_timestamp(&mut context, start_time + 1).await;
+    let submit_ix = ix::submit_meme_paying(
+        context.payer.pubkey(),
+        submitter.pubkey(),
+        challenge,
+        None,
+        Some(submitter_token_account),
+        submission_args(),
+    );
+    process(&mut context, &[submit_ix], &[&submitter])
+        .await
+        .unwrap();
+    let cancel_ix = ix::cancel_meme_challenge(creator.pubkey(), challenge);
+    process(&mut context, &[cancel_ix], &[&creator])
+        .await
+        .unwrap();
+
+    let refund_ix = ix::refund_entry_fee(
+        submitter.pubkey(),
+        challenge,
+        submission_args().content_hash,
+        context.payer.pubkey(),
+        Some(submitter_token_account),
+    );
+    process(&mut context, &[refund_ix], &[&submitter])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        token_balance(&mut context, submitter_token_account).await,
+        50
+    );
+    assert_eq!(
+        token_balance(&mut context, pda::challenge_vault(&challenge)).await,
+        500
+    );
+}
+
+#[tokio::test]
+async fn refund_entry_fee_requires_rent_payer() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let stranger = Keypair::new();
+    let challenge = create_meme_challenge_with(&mut context, &creator, |args| {
+        instruction::CreateMemeChallenge {
+            entry_fee: LAMPORTS_PER_SOL / 10,
+            ..args
+        }
+    })
+    .await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    fund(&mut context, &submitter.pubkey(), LAMPORTS_PER_SOL);
+    submit_meme(&mut context, &submitter, challenge).await;
+    let cancel_ix = ix::cancel_meme_challenge(creator.pubkey(), challenge);
+    process(&mut context, &[cancel_ix], &[&creator])
+        .await
+        .unwrap();
+
+    let refund_ix = ix::refund_entry_fee(
+        submitter.pubkey(),
+        challenge,
+        submitter.pubkey().to_bytes(),
+        stranger.pubkey(),
+        None,
+    );
+    let result = process(&mut context, &[refund_ix], &[&submitter]).await;
+    assert_error(result, ErrorCode::NotOwner);
+}
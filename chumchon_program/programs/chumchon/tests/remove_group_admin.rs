@@ -0,0 +1,96 @@
+mod common;
+
+use chumchon::{error::ErrorCode, GroupMember, GroupRole};
+use common::{
+    add_group_admin, assert_error, create_group, fetch, group_args, ix, join_group, pda, process,
+    start,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn remove_group_admin_success() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let admin = Keypair::new();
+    let moderator = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    join_group(&mut context, &admin, group).await;
+    join_group(&mut context, &moderator, group).await;
+    add_group_admin(
+        &mut context,
+        &creator,
+        group,
+        &admin.pubkey(),
+        GroupRole::Admin,
+    )
+    .await;
+    add_group_admin(
+        &mut context,
+        &creator,
+        group,
+        &moderator.pubkey(),
+        GroupRole::Moderator,
+    )
+    .await;
+
+    // Admins may remove moderators
+    let remove_ix = ix::remove_group_admin(admin.pubkey(), group, true, moderator.pubkey());
+    process(&mut context, &[remove_ix], &[&admin])
+        .await
+        .unwrap();
+    let record: GroupMember =
+        fetch(&mut context, pda::group_member(&group, &moderator.pubkey())).await;
+    assert_eq!(record.role, GroupRole::Member);
+
+    let remove_ix = ix::remove_group_admin(creator.pubkey(), group, false, admin.pubkey());
+    process(&mut context, &[remove_ix], &[&creator])
+        .await
+        .unwrap();
+    let record: GroupMember = fetch(&mut context, pda::group_member(&group, &admin.pubkey())).await;
+    assert_eq!(record.role, GroupRole::Member);
+}
+
+#[tokio::test]
+async fn remove_group_admin_requires_higher_role() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let admin = Keypair::new();
+    let other_admin = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    join_group(&mut context, &admin, group).await;
+    join_group(&mut context, &other_admin, group).await;
+    add_group_admin(
+        &mut context,
+        &creator,
+        group,
+        &admin.pubkey(),
+        GroupRole::Admin,
+    )
+    .await;
+    add_group_admin(
+        &mut context,
+        &creator,
+        group,
+        &other_admin.pubkey(),
+        GroupRole::Admin,
+    )
+    .await;
+
+    let remove_ix = ix::remove_group_admin(admin.pubkey(), group, true, other_admin.pubkey());
+    let result = process(&mut context, &[remove_ix], &[&admin]).await;
+    assert_error(result, ErrorCode::NotGroupAdmin);
+}
+
+#[tokio::test]
+async fn remove_group_admin_requires_staff_member() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let member = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    join_group(&mut context, &member, group).await;
+
+    let remove_ix = ix::remove_group_admin(creator.pubkey(), group, false, member.pubkey());
+    let result = process(&mut context, &[remove_ix], &[&creator]).await;
+    assert_error(result, ErrorCode::InvalidRole);
+}
@@ -0,0 +1,102 @@
+mod common;
+
+use chumchon::error::ErrorCode;
+use common::{
+    account_exists, assert_error, create_escrow, now, pda, process, start, token_balance,
+    EscrowSetup,
+};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::signature::Signer;
+
+async fn create_disputed_escrow(context: &mut ProgramTestContext) -> EscrowSetup {
+    let expires_at = now(context).await + 3600;
+    let setup = create_escrow(context, expires_at).await;
+    let accept_ix = setup.accept_ix(context.payer.pubkey());
+    let dispute_ix = setup.dispute_ix(setup.initiator.pubkey());
+    process(
+        context,
+        &[accept_ix, dispute_ix],
+        &[&setup.counterparty, &setup.initiator],
+    )
+    .await
+    .unwrap();
+    setup
+}
+
+#[tokio::test]
+async fn resolve_escrow_dispute_settles_trade() {
+    let mut context = start().await;
+    let setup = create_disputed_escrow(&mut context).await;
+
+    let resolve_ix = setup.resolve_ix(setup.group_owner.pubkey(), false, true);
+    process(&mut context, &[resolve_ix], &[&setup.group_owner])
+        .await
+        .unwrap();
+
+    let initiator_leg = setup.initiator_leg.unwrap();
+    let counterparty_leg = setup.counterparty_leg.unwrap();
+    assert_eq!(
+        token_balance(&mut context, counterparty_leg.receive_account).await,
+        250
+    );
+    assert_eq!(
+        token_balance(&mut context, initiator_leg.receive_account).await,
+        100
+    );
+    assert!(!account_exists(&mut context, setup.escrow).await);
+    assert!(!account_exists(&mut context, pda::initiator_vault(&setup.escrow)).await);
+    assert!(!account_exists(&mut context, pda::counterparty_vault(&setup.escrow)).await);
+}
+
+#[tokio::test]
+async fn resolve_escrow_dispute_cancels_and_refunds_both_sides() {
+    let mut context = start().await;
+    let setup = create_disputed_escrow(&mut context).await;
+
+    let resolve_ix = setup.resolve_ix(setup.group_owner.pubkey(), false, false);
+    process(&mut context, &[resolve_ix], &[&setup.group_owner])
+        .await
+        .unwrap();
+
+    let initiator_leg = setup.initiator_leg.unwrap();
+    let counterparty_leg = setup.counterparty_leg.unwrap();
+    assert_eq!(
+        token_balance(&mut context, initiator_leg.deposit_account).await,
+        100
+    );
+    assert_eq!(
+        token_balance(&mut context, counterparty_leg.deposit_account).await,
+        250
+    );
+    assert_eq!(
+        token_balance(&mut context, initiator_leg.receive_account).await,
+        0
+    );
+    assert!(!account_exists(&mut context, setup.escrow).await);
+    assert!(!account_exists(&mut context, pda::counterparty_vault(&setup.escrow)).await);
+}
+
+#[tokio::test]
+async fn resolve_escrow_dispute_requires_moderator() {
+    let mut context = start().await;
+    let setup = create_disputed_escrow(&mut context).await;
+
+    let resolve_ix = setup.resolve_ix(setup.counterparty.pubkey(), true, true);
+    let result = process(&mut context, &[resolve_ix], &[&setup.counterparty]).await;
+    assert_error(result, ErrorCode::NotGroupAdmin);
+}
+
+#[tokio::test]
+async fn resolve_escrow_dispute_requires_dispute() {
+    let mut context = start().await;
+    let expires_at = now(&mut context).await + 3600;
+    let setup = create_escrow(&mut context, expires_at).await;
+    let accept_ix = setup.accept_ix(context.payer.pubkey());
+    process(&mut context, &[accept_ix], &[&setup.counterparty])
+        .await
+        .unwrap();
+
+    let resolve_ix = setup.resolve_ix(setup.group_owner.pubkey(), false, true);
+    let result = process(&mut context, &[resolve_ix], &[&setup.group_owner]).await;
+    assert_error(result, ErrorCode::EscrowNotDisputed);
+}
@@ -0,0 +1,122 @@
+mod common;
+
+use chumchon::{error::ErrorCode, MemeChallenge, MemeSubmission, VoterRecord};
+use common::{
+    assert_error, commit_meme_vote, create_commit_reveal_challenge, fetch, ix, pda, process, start,
+    submit_meme, warp_to_timestamp,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn reveal_meme_vote_success() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let voter = Keypair::new();
+    let challenge = create_commit_reveal_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+    commit_meme_vote(
+        &mut context,
+        &voter,
+        challenge,
+        &submitter.pubkey(),
+        [7; 32],
+    )
+    .await;
+
+    let reveal_ix = ix::reveal_meme_vote(voter.pubkey(), challenge, submitter.pubkey(), [7; 32]);
+    let result = process(&mut context, std::slice::from_ref(&reveal_ix), &[&voter]).await;
+    assert_error(result, ErrorCode::RevealNotOpen);
+
+    warp_to_timestamp(&mut context, challenge_account.voting_end).await;
+    process(&mut context, std::slice::from_ref(&reveal_ix), &[&voter])
+        .await
+        .unwrap();
+
+    let submission = pda::meme_submission(&challenge, &submitter.pubkey());
+    let submission_account: MemeSubmission = fetch(&mut context, submission).await;
+    assert_eq!(submission_account.votes, 1);
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(challenge_account.total_votes, 1);
+    assert_eq!(challenge_account.leaderboard[0].votes, 1);
+    let record: VoterRecord =
+        fetch(&mut context, pda::voter_record(&challenge, &voter.pubkey())).await;
+    assert_eq!(record.submission, submission);
+    assert!(record.revealed);
+
+    let result = process(&mut context, &[reveal_ix], &[&voter]).await;
+    assert_error(result, ErrorCode::VoteAlreadyRevealed);
+}
+
+#[tokio::test]
+async fn reveal_meme_vote_must_match_commitment() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let first = Keypair::new();
+    let second = Keypair::new();
+    let voter = Keypair::new();
+    let challenge = create_commit_reveal_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &first, challenge).await;
+    submit_meme(&mut context, &second, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+    commit_meme_vote(&mut context, &voter, challenge, &first.pubkey(), [7; 32]).await;
+    warp_to_timestamp(&mut context, challenge_account.voting_end).await;
+
+    let cases = [(second.pubkey(), [7; 32]), (first.pubkey(), [8; 32])];
+    for (submitter, salt) in cases {
+        let reveal_ix = ix::reveal_meme_vote(voter.pubkey(), challenge, submitter, salt);
+        let result = process(&mut context, &[reveal_ix], &[&voter]).await;
+        assert_error(result, ErrorCode::InvalidReveal);
+    }
+}
+
+#[tokio::test]
+async fn reveal_meme_vote_closes_with_reveal_window() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let voter = Keypair::new();
+    let challenge = create_commit_reveal_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+    commit_meme_vote(
+        &mut context,
+        &voter,
+        challenge,
+        &submitter.pubkey(),
+        [7; 32],
+    )
+    .await;
+
+    // The challenge cannot end while votes may still be revealed
+    warp_to_timestamp(&mut context, challenge_account.voting_end).await;
+    let end_ix = ix::end_meme_challenge(
+        context.payer.pubkey(),
+        challenge,
+        context.payer.pubkey(),
+        false,
+        &[submitter.pubkey()],
+    );
+    let result = process(&mut context, std::slice::from_ref(&end_ix), &[]).await;
+    assert_error(result, ErrorCode::ChallengeNotEnded);
+
+    // A commitment left unrevealed is simply never counted
+    let reveal_end = challenge_account.reveal_end.unwrap();
+    warp_to_timestamp(&mut context, reveal_end).await;
+    let reveal_ix = ix::reveal_meme_vote(voter.pubkey(), challenge, submitter.pubkey(), [7; 32]);
+    let result = process(&mut context, &[reveal_ix], &[&voter]).await;
+    assert_error(result, ErrorCode::RevealNotOpen);
+
+    process(&mut context, &[end_ix], &[]).await.unwrap();
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(challenge_account.total_votes, 0);
+    assert_eq!(challenge_account.winners, vec![submitter.pubkey()]);
+}
@@ -0,0 +1,195 @@
+mod common;
+
+use chumchon::{error::ErrorCode, instruction, Group, GroupRole, Message};
+use common::{
+    add_group_admin, assert_error, create_group, fetch, group_args, ix, join_group, pda, process,
+    send_message, start,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn send_message_success() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let sender = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    join_group(&mut context, &sender, group).await;
+
+    let send_ix = ix::send_message(
+        context.payer.pubkey(),
+        sender.pubkey(),
+        group,
+        0,
+        "gm chums",
+    );
+    process(&mut context, &[send_ix], &[&sender]).await.unwrap();
+
+    let message: Message = fetch(&mut context, pda::message(&group, 0)).await;
+    assert_eq!(message.group, group);
+    assert_eq!(message.sender, sender.pubkey());
+    assert_eq!(message.message_id, 0);
+    assert_eq!(message.content, "gm chums");
+    assert_eq!(message.tips_received, 0);
+    let group_account: Group = fetch(&mut context, group).await;
+    assert_eq!(group_account.message_count, 1);
+    assert_eq!(group_account.last_message_at, message.timestamp);
+}
+
+#[tokio::test]
+async fn send_message_numbers_messages_sequentially() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let alice = Keypair::new();
+    let bob = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    join_group(&mut context, &alice, group).await;
+    join_group(&mut context, &bob, group).await;
+
+    assert_eq!(send_message(&mut context, &alice, group, "gm").await, 0);
+    assert_eq!(send_message(&mut context, &bob, group, "gm gm").await, 1);
+    assert_eq!(send_message(&mut context, &alice, group, "wagmi").await, 2);
+
+    let message: Message = fetch(&mut context, pda::message(&group, 2)).await;
+    assert_eq!(message.sender, alice.pubkey());
+    assert_eq!(message.message_id, 2);
+    assert_eq!(message.content, "wagmi");
+    let group_account: Group = fetch(&mut context, group).await;
+    assert_eq!(group_account.message_count, 3);
+}
+
+#[tokio::test]
+async fn send_message_rejects_stale_message_id() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let sender = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    join_group(&mut context, &sender, group).await;
+    send_message(&mut context, &sender, group, "gm").await;
+
+    let send_ix = ix::send_message(
+        context.payer.pubkey(),
+        sender.pubkey(),
+        group,
+        0,
+        "gm again",
+    );
+    let result = process(&mut context, &[send_ix], &[&sender]).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn send_message_rejects_long_content() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let sender = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    join_group(&mut context, &sender, group).await;
+
+    let send_ix = ix::send_message(
+        context.payer.pubkey(),
+        sender.pubkey(),
+        group,
+        0,
+        &"a".repeat(501),
+    );
+    let result = process(&mut context, &[send_ix], &[&sender]).await;
+    assert_error(result, ErrorCode::ContentTooLong);
+}
+
+#[tokio::test]
+async fn send_message_in_channel_is_admin_only() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let member = Keypair::new();
+    let group = create_group(
+        &mut context,
+        &creator,
+        instruction::CreateGroup {
+            is_channel: true,
+            ..group_args("announcements")
+        },
+    )
+    .await;
+    join_group(&mut context, &creator, group).await;
+    join_group(&mut context, &member, group).await;
+
+    let send_ix = ix::send_message(context.payer.pubkey(), member.pubkey(), group, 0, "gm");
+    let result = process(&mut context, &[send_ix], &[&member]).await;
+    assert_error(result, ErrorCode::ChannelPostingRestricted);
+
+    send_message(&mut context, &creator, group, "gm").await;
+    add_group_admin(
+        &mut context,
+        &creator,
+        group,
+        &member.pubkey(),
+        GroupRole::Admin,
+    )
+    .await;
+    send_message(&mut context, &member, group, "gm").await;
+}
+
+#[tokio::test]
+async fn send_message_by_owner_without_membership() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let successor = Keypair::new();
+    let outsider = Keypair::new();
+    let group = create_group(
+        &mut context,
+        &creator,
+        instruction::CreateGroup {
+            is_channel: true,
+            ..group_args("announcements")
+        },
+    )
+    .await;
+
+    // Neither the creator nor the owner they hand the group to has joined it
+    let send_ix = ix::send_message_as(
+        context.payer.pubkey(),
+        creator.pubkey(),
+        group,
+        false,
+        0,
+        "gm",
+    );
+    process(&mut context, &[send_ix], &[&creator])
+        .await
+        .unwrap();
+    let transfer_ix =
+        ix::transfer_group_ownership(creator.pubkey(), group, Some(successor.pubkey()));
+    process(&mut context, &[transfer_ix], &[&creator])
+        .await
+        .unwrap();
+    let accept_ix = ix::accept_group_ownership(successor.pubkey(), group);
+    process(&mut context, &[accept_ix], &[&successor])
+        .await
+        .unwrap();
+
+    let send_ix = ix::send_message_as(
+        context.payer.pubkey(),
+        successor.pubkey(),
+        group,
+        false,
+        1,
+        "gm",
+    );
+    process(&mut context, &[send_ix], &[&successor])
+        .await
+        .unwrap();
+    let message: Message = fetch(&mut context, pda::message(&group, 1)).await;
+    assert_eq!(message.sender, successor.pubkey());
+
+    let send_ix = ix::send_message_as(
+        context.payer.pubkey(),
+        outsider.pubkey(),
+        group,
+        false,
+        2,
+        "gm",
+    );
+    let result = process(&mut context, &[send_ix], &[&outsider]).await;
+    assert_error(result, ErrorCode::NotGroupMember);
+}
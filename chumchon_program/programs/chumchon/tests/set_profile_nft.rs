@@ -0,0 +1,92 @@
+mod common;
+
+use chumchon::{error::ErrorCode, UserProfile};
+use common::{
+    assert_error, create_mint, create_token_account, create_user_profile, fetch, ix, mint_to, pda,
+    process, start,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn set_profile_nft_success() {
+    let mut context = start().await;
+    let owner = Keypair::new();
+    let mint_authority = Keypair::new();
+    create_user_profile(&mut context, &owner).await;
+    let nft_mint = create_mint(&mut context, &mint_authority.pubkey()).await;
+    let nft_account = create_token_account(&mut context, &nft_mint, &owner.pubkey()).await;
+    mint_to(&mut context, &nft_mint, &nft_account, &mint_authority, 1).await;
+
+    let set_ix = ix::set_profile_nft(
+        context.payer.pubkey(),
+        owner.pubkey(),
+        nft_account,
+        nft_mint,
+    );
+    process(&mut context, &[set_ix], &[&owner]).await.unwrap();
+
+    let profile: UserProfile = fetch(&mut context, pda::user_profile(&owner.pubkey())).await;
+    assert_eq!(profile.nft_profile_picture, Some(nft_mint));
+}
+
+#[tokio::test]
+async fn set_profile_nft_rejects_mismatched_mint() {
+    let mut context = start().await;
+    let owner = Keypair::new();
+    let mint_authority = Keypair::new();
+    create_user_profile(&mut context, &owner).await;
+    let nft_mint = create_mint(&mut context, &mint_authority.pubkey()).await;
+    let other_mint = create_mint(&mut context, &mint_authority.pubkey()).await;
+    let nft_account = create_token_account(&mut context, &nft_mint, &owner.pubkey()).await;
+    mint_to(&mut context, &nft_mint, &nft_account, &mint_authority, 1).await;
+
+    let set_ix = ix::set_profile_nft(
+        context.payer.pubkey(),
+        owner.pubkey(),
+        nft_account,
+        other_mint,
+    );
+    let result = process(&mut context, &[set_ix], &[&owner]).await;
+    assert_error(result, ErrorCode::InvalidMint);
+}
+
+#[tokio::test]
+async fn set_profile_nft_requires_holding_the_nft() {
+    let mut context = start().await;
+    let owner = Keypair::new();
+    let mint_authority = Keypair::new();
+    create_user_profile(&mut context, &owner).await;
+    let nft_mint = create_mint(&mut context, &mint_authority.pubkey()).await;
+    let nft_account = create_token_account(&mut context, &nft_mint, &owner.pubkey()).await;
+
+    let set_ix = ix::set_profile_nft(
+        context.payer.pubkey(),
+        owner.pubkey(),
+        nft_account,
+        nft_mint,
+    );
+    let result = process(&mut context, &[set_ix], &[&owner]).await;
+    assert_error(result, ErrorCode::NoNFT);
+}
+
+#[tokio::test]
+async fn set_profile_nft_rejects_foreign_token_account() {
+    let mut context = start().await;
+    let owner = Keypair::new();
+    let holder = Keypair::new();
+    let mint_authority = Keypair::new();
+    create_user_profile(&mut context, &owner).await;
+    let nft_mint = create_mint(&mut context, &mint_authority.pubkey()).await;
+    let nft_account = create_token_account(&mut context, &nft_mint, &holder.pubkey()).await;
+    mint_to(&mut context, &nft_mint, &nft_account, &mint_authority, 1).await;
+
+    let set_ix = ix::set_profile_nft(
+        context.payer.pubkey(),
+        owner.pubkey(),
+        nft_account,
+        nft_mint,
+    );
+    let result = process(&mut context, &[set_ix], &[&owner]).await;
+    assert_error(result, ErrorCode::NotOwner);
+}
@@ -0,0 +1,346 @@
+mod common;
+
+use chumchon::{error::ErrorCode, instruction, ContentGuard, MemeChallenge, MemeSubmission};
+use common::{
+    assert_error, balance, challenge_args, create_group_meme_challenge, create_meme_challenge,
+    create_meme_challenge_with, create_mint, create_token_account, fetch, fund, ix, join_group,
+    mint_to, now, pda, process, start, submission_args, token_balance, warp_to_timestamp,
+    LAMPORTS_PER_SOL,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn submit_meme_success() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+
+    let submit_ix = ix::submit_meme(
+        context.payer.pubkey(),
+        submitter.pubkey(),
+        challenge,
+        None,
+        submission_args(),
+    );
+    process(&mut context, &[submit_ix], &[&submitter])
+        .await
+        .unwrap();
+
+    let submission: MemeSubmission = fetch(
+        &mut context,
+        pda::meme_submission(&challenge, &submitter.pubkey()),
+    )
+    .await;
+    assert_eq!(submission.challenge, challenge);
+    assert_eq!(submission.submitter, submitter.pubkey());
+    assert_eq!(submission.image_url, submission_args().image_url);
+    assert_eq!(submission.content_hash, submission_args().content_hash);
+    assert_eq!(submission.votes, 0);
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(challenge_account.submission_count, 1);
+    let guard: ContentGuard = fetch(
+        &mut context,
+        pda::content_guard(&challenge, &submission_args().content_hash),
+    )
+    .await;
+    assert_eq!(
+        guard.submission,
+        pda::meme_submission(&challenge, &submitter.pubkey())
+    );
+}
+
+#[tokio::test]
+async fn submit_meme_rejects_duplicate_content() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let first = Keypair::new();
+    let second = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let other_challenge = create_meme_challenge(&mut context, &Keypair::new()).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    let submit_ix = ix::submit_meme(
+        context.payer.pubkey(),
+        first.pubkey(),
+        challenge,
+        None,
+        submission_args(),
+    );
+    process(&mut context, &[submit_ix], &[&first])
+        .await
+        .unwrap();
+
+    let submit_ix = ix::submit_meme(
+        context.payer.pubkey(),
+        second.pubkey(),
+        challenge,
+        None,
+        submission_args(),
+    );
+    let result = process(&mut context, &[submit_ix], &[&second]).await;
+    assert_error(result, ErrorCode::DuplicateSubmission);
+
+    // The same image is still welcome in another challenge, even at a pre-funded address
+    fund(
+        &mut context,
+        &pda::content_guard(&other_challenge, &submission_args().content_hash),
+        1,
+    );
+    let submit_ix = ix::submit_meme(
+        context.payer.pubkey(),
+        second.pubkey(),
+        other_challenge,
+        None,
+        submission_args(),
+    );
+    process(&mut context, &[submit_ix], &[&second])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn submit_meme_collects_entry_fee() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let challenge = create_meme_challenge_with(&mut context, &creator, |args| {
+        instruction::CreateMemeChallenge {
+            entry_fee: LAMPORTS_PER_SOL / 10,
+            ..args
+        }
+    })
+    .await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    fund(&mut context, &submitter.pubkey(), LAMPORTS_PER_SOL);
+    let pool_before = balance(&mut context, challenge).await;
+
+    let submit_ix = ix::submit_meme(
+        context.payer.pubkey(),
+        submitter.pubkey(),
+        challenge,
+        None,
+        submission_args(),
+    );
+    process(&mut context, &[submit_ix], &[&submitter])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        balance(&mut context, challenge).await - pool_before,
+        LAMPORTS_PER_SOL / 10
+    );
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(
+        challenge_account.reward_amount,
+        LAMPORTS_PER_SOL + LAMPORTS_PER_SOL / 10
+    );
+}
+
+#[tokio::test]
+async fn submit_meme_collects_token_entry_fee() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let mint_authority = Keypair::new();
+    let mint = create_mint(&mut context, &mint_authority.pubkey()).await;
+    let creator_token_account = create_token_account(&mut context, &mint, &creator.pubkey()).await;
+    let submitter_token_account =
+        create_token_account(&mut context, &mint, &submitter.pubkey()).await;
+    mint_to(
+        &mut context,
+        &mint,
+        &creator_token_account,
+        &mint_authority,
+        500,
+    )
+    .await;
+    mint_to(
+        &mut context,
+        &mint,
+        &submitter_token_account,
+        &mint_authority,
+        50,
+    )
+    .await;
+    fund(&mut context, &creator.pubkey(), LAMPORTS_PER_SOL);
+    let start_time = now(&mut context).await + 60;
+    let create_ix = ix::create_meme_challenge(
+        context.payer.pubkey(),
+        creator.pubkey(),
+        None,
+        Some(mint),
+        Some(creator_token_account),
+        instruction::CreateMemeChallenge {
+            reward_amount: 500,
+            entry_fee: 50,
+            ..challenge_args(start_time)
+        },
+    );
+    process(&mut context, &[create_ix], &[&creator])
+        .await
+        .unwrap();
+    let challenge = pda::meme_challenge(&creator.pubkey(), start_time);
+    warp_to_timestamp(&mut context, start_time + 1).await;
+
+    let submit_ix = ix::submit_meme(
+        context.payer.pubkey(),
+        submitter.pubkey(),
+        challenge,
+        None,
+        submission_args(),
+    );
+    let result = process(&mut context, &[submit_ix], &[&submitter]).await;
+    assert_error(result, ErrorCode::MissingTokenAccount);
+
+    let submit_ix = ix::submit_meme_paying(
+        context.payer.pubkey(),
+        submitter.pubkey(),
+        challenge,
+        None,
+        Some(submitter_token_account),
+        submission_args(),
+    );
+    process(&mut context, &[submit_ix], &[&submitter])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        token_balance(&mut context, submitter_token_account).await,
+        0
+    );
+    assert_eq!(
+        token_balance(&mut context, pda::challenge_vault(&challenge)).await,
+        550
+    );
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(challenge_account.reward_amount, 550);
+}
+
+#[tokio::test]
+async fn submit_meme_outside_challenge_window() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+
+    let submit_ix = ix::submit_meme(
+        context.payer.pubkey(),
+        submitter.pubkey(),
+        challenge,
+        None,
+        submission_args(),
+    );
+    let result = process(
+        &mut context,
+        std::slice::from_ref(&submit_ix),
+        &[&submitter],
+    )
+    .await;
+    assert_error(result, ErrorCode::ChallengeNotStarted);
+
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+    let result = process(&mut context, &[submit_ix], &[&submitter]).await;
+    assert_error(result, ErrorCode::SubmissionsClosed);
+}
+
+#[tokio::test]
+async fn submit_meme_rejects_long_fields() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    let cases = [
+        (
+            instruction::SubmitMeme {
+                image_url: "a".repeat(201),
+                ..submission_args()
+            },
+            ErrorCode::UrlTooLong,
+        ),
+        (
+            instruction::SubmitMeme {
+                title: "a".repeat(65),
+                ..submission_args()
+            },
+            ErrorCode::TitleTooLong,
+        ),
+        (
+            instruction::SubmitMeme {
+                description: "a".repeat(257),
+                ..submission_args()
+            },
+            ErrorCode::DescriptionTooLong,
+        ),
+    ];
+
+    for (args, expected) in cases {
+        let submit_ix = ix::submit_meme(
+            context.payer.pubkey(),
+            submitter.pubkey(),
+            challenge,
+            None,
+            args,
+        );
+        let result = process(&mut context, &[submit_ix], &[&submitter]).await;
+        assert_error(result, expected);
+    }
+}
+
+#[tokio::test]
+async fn submit_meme_requires_group_membership() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let (group, challenge) = create_group_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+
+    let submit_ix = ix::submit_meme(
+        context.payer.pubkey(),
+        submitter.pubkey(),
+        challenge,
+        None,
+        submission_args(),
+    );
+    let result = process(&mut context, &[submit_ix], &[&submitter]).await;
+    assert_error(result, ErrorCode::NotGroupMember);
+
+    // Another member's record does not stand in for the submitter's own
+    join_group(&mut context, &creator, group).await;
+    let mut submit_ix = ix::submit_meme(
+        context.payer.pubkey(),
+        submitter.pubkey(),
+        challenge,
+        Some(group),
+        submission_args(),
+    );
+    let own_record = pda::group_member(&group, &submitter.pubkey());
+    let member_record = submit_ix
+        .accounts
+        .iter_mut()
+        .find(|meta| meta.pubkey == own_record)
+        .unwrap();
+    member_record.pubkey = pda::group_member(&group, &creator.pubkey());
+    let result = process(&mut context, &[submit_ix], &[&submitter]).await;
+    assert_error(result, ErrorCode::NotGroupMember);
+
+    join_group(&mut context, &submitter, group).await;
+    let submit_ix = ix::submit_meme(
+        context.payer.pubkey(),
+        submitter.pubkey(),
+        challenge,
+        Some(group),
+        submission_args(),
+    );
+    process(&mut context, &[submit_ix], &[&submitter])
+        .await
+        .unwrap();
+}
@@ -0,0 +1,69 @@
+mod common;
+
+use chumchon::{error::ErrorCode, Message};
+use common::{
+    assert_error, balance, create_group, fetch, fund, group_args, ix, join_group, pda, process,
+    send_message, start, LAMPORTS_PER_SOL,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn tip_message_success() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let sender = Keypair::new();
+    let tipper = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    join_group(&mut context, &sender, group).await;
+    send_message(&mut context, &sender, group, "gm").await;
+    let message_id = send_message(&mut context, &sender, group, "tip me").await;
+    fund(&mut context, &sender.pubkey(), LAMPORTS_PER_SOL);
+    fund(&mut context, &tipper.pubkey(), LAMPORTS_PER_SOL);
+
+    let tip_ix = ix::tip_message(tipper.pubkey(), group, message_id, sender.pubkey(), 5_000);
+    process(&mut context, &[tip_ix], &[&tipper]).await.unwrap();
+
+    let message: Message = fetch(&mut context, pda::message(&group, message_id)).await;
+    assert_eq!(message.tips_received, 5_000);
+    assert_eq!(
+        balance(&mut context, sender.pubkey()).await,
+        LAMPORTS_PER_SOL + 5_000
+    );
+    assert_eq!(
+        balance(&mut context, tipper.pubkey()).await,
+        LAMPORTS_PER_SOL - 5_000
+    );
+}
+
+#[tokio::test]
+async fn tip_message_rejects_zero_amount() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let sender = Keypair::new();
+    let tipper = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    join_group(&mut context, &sender, group).await;
+    let message_id = send_message(&mut context, &sender, group, "gm").await;
+    fund(&mut context, &tipper.pubkey(), LAMPORTS_PER_SOL);
+
+    let tip_ix = ix::tip_message(tipper.pubkey(), group, message_id, sender.pubkey(), 0);
+    let result = process(&mut context, &[tip_ix], &[&tipper]).await;
+    assert_error(result, ErrorCode::InvalidAmount);
+}
+
+#[tokio::test]
+async fn tip_message_pays_only_the_sender() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let sender = Keypair::new();
+    let tipper = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    join_group(&mut context, &sender, group).await;
+    let message_id = send_message(&mut context, &sender, group, "gm").await;
+    fund(&mut context, &tipper.pubkey(), LAMPORTS_PER_SOL);
+
+    let tip_ix = ix::tip_message(tipper.pubkey(), group, message_id, creator.pubkey(), 5_000);
+    let result = process(&mut context, &[tip_ix], &[&tipper]).await;
+    assert_error(result, ErrorCode::InvalidRecipient);
+}
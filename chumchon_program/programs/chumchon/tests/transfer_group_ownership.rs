@@ -0,0 +1,45 @@
+mod common;
+
+use chumchon::{error::ErrorCode, Group};
+use common::{assert_error, create_group, fetch, group_args, ix, process, start};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn transfer_group_ownership_success() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let successor = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+
+    let transfer_ix =
+        ix::transfer_group_ownership(creator.pubkey(), group, Some(successor.pubkey()));
+    process(&mut context, &[transfer_ix], &[&creator])
+        .await
+        .unwrap();
+
+    // Ownership only moves once the successor accepts
+    let group_account: Group = fetch(&mut context, group).await;
+    assert_eq!(group_account.owner, creator.pubkey());
+    assert_eq!(group_account.pending_owner, Some(successor.pubkey()));
+
+    let transfer_ix = ix::transfer_group_ownership(creator.pubkey(), group, None);
+    process(&mut context, &[transfer_ix], &[&creator])
+        .await
+        .unwrap();
+    let group_account: Group = fetch(&mut context, group).await;
+    assert_eq!(group_account.pending_owner, None);
+}
+
+#[tokio::test]
+async fn transfer_group_ownership_requires_owner() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let stranger = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+
+    let transfer_ix =
+        ix::transfer_group_ownership(stranger.pubkey(), group, Some(stranger.pubkey()));
+    let result = process(&mut context, &[transfer_ix], &[&stranger]).await;
+    assert_error(result, ErrorCode::NotGroupOwner);
+}
@@ -0,0 +1,123 @@
+mod common;
+
+use chumchon::{error::ErrorCode, GroupRole};
+use common::{
+    account_exists, add_group_admin, assert_error, ban_member, create_group, group_args, ix,
+    join_group, pda, process, start,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn unban_member_success() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let moderator = Keypair::new();
+    let member = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    join_group(&mut context, &moderator, group).await;
+    join_group(&mut context, &member, group).await;
+    add_group_admin(
+        &mut context,
+        &creator,
+        group,
+        &moderator.pubkey(),
+        GroupRole::Moderator,
+    )
+    .await;
+    let payer = context.payer.pubkey();
+    let ban_ix = ix::ban_member(
+        payer,
+        moderator.pubkey(),
+        group,
+        true,
+        member.pubkey(),
+        Some(payer),
+        None,
+    );
+    process(&mut context, &[ban_ix], &[&moderator])
+        .await
+        .unwrap();
+
+    let ban_record = pda::group_ban(&group, &member.pubkey());
+    let unban_ix = ix::unban_member(moderator.pubkey(), group, true, member.pubkey(), payer);
+    process(&mut context, &[unban_ix], &[&moderator])
+        .await
+        .unwrap();
+
+    assert!(!account_exists(&mut context, ban_record).await);
+    join_group(&mut context, &member, group).await;
+}
+
+#[tokio::test]
+async fn unban_member_requires_moderator() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let member = Keypair::new();
+    let friend = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    join_group(&mut context, &member, group).await;
+    join_group(&mut context, &friend, group).await;
+    ban_member(&mut context, &creator, group, &member.pubkey()).await;
+
+    let payer = context.payer.pubkey();
+    let unban_ix = ix::unban_member(friend.pubkey(), group, true, member.pubkey(), payer);
+    let result = process(&mut context, &[unban_ix], &[&friend]).await;
+    assert_error(result, ErrorCode::NotGroupAdmin);
+}
+
+#[tokio::test]
+async fn unban_member_requires_banner_rank() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let moderator = Keypair::new();
+    let member = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    join_group(&mut context, &moderator, group).await;
+    join_group(&mut context, &member, group).await;
+    add_group_admin(
+        &mut context,
+        &creator,
+        group,
+        &moderator.pubkey(),
+        GroupRole::Moderator,
+    )
+    .await;
+    ban_member(&mut context, &creator, group, &member.pubkey()).await;
+
+    let payer = context.payer.pubkey();
+    let unban_ix = ix::unban_member(moderator.pubkey(), group, true, member.pubkey(), payer);
+    let result = process(&mut context, &[unban_ix], &[&moderator]).await;
+    assert_error(result, ErrorCode::NotGroupAdmin);
+}
+
+#[tokio::test]
+async fn unban_member_by_later_owner() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let successor = Keypair::new();
+    let member = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    join_group(&mut context, &successor, group).await;
+    join_group(&mut context, &member, group).await;
+    ban_member(&mut context, &creator, group, &member.pubkey()).await;
+    let transfer_ix =
+        ix::transfer_group_ownership(creator.pubkey(), group, Some(successor.pubkey()));
+    let accept_ix = ix::accept_group_ownership(successor.pubkey(), group);
+    process(
+        &mut context,
+        &[transfer_ix, accept_ix],
+        &[&creator, &successor],
+    )
+    .await
+    .unwrap();
+
+    // Nobody outranks the owner who placed the ban, but whoever owns the group now may lift it
+    let payer = context.payer.pubkey();
+    let unban_ix = ix::unban_member(successor.pubkey(), group, true, member.pubkey(), payer);
+    process(&mut context, &[unban_ix], &[&successor])
+        .await
+        .unwrap();
+
+    assert!(!account_exists(&mut context, pda::group_ban(&group, &member.pubkey())).await);
+}
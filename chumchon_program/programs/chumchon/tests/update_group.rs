@@ -0,0 +1,144 @@
+mod common;
+
+use chumchon::{error::ErrorCode, instruction, Group, GroupRole};
+use common::{
+    add_group_admin, assert_error, create_group, fetch, group_args, ix, join_group, pda, process,
+    start, LAMPORTS_PER_SOL,
+};
+use solana_program_test::tokio;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+fn update_args(display_name: &str) -> instruction::UpdateGroup {
+    instruction::UpdateGroup {
+        display_name: display_name.to_string(),
+        description: "gm".to_string(),
+        is_channel: false,
+        is_whale_group: false,
+        required_token: None,
+        required_amount: 0,
+        required_nft_collection: None,
+        required_sol_balance: 0,
+        escrow_enabled: false,
+    }
+}
+
+#[tokio::test]
+async fn update_group_success() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let member = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+
+    let update_ix = ix::update_group(
+        creator.pubkey(),
+        group,
+        false,
+        instruction::UpdateGroup {
+            is_whale_group: true,
+            required_sol_balance: 10 * LAMPORTS_PER_SOL,
+            escrow_enabled: true,
+            ..update_args("Chums HQ")
+        },
+    );
+    process(&mut context, &[update_ix], &[&creator])
+        .await
+        .unwrap();
+
+    let group_account: Group = fetch(&mut context, group).await;
+    assert_eq!(group_account.name, "chums");
+    assert_eq!(group_account.display_name, "Chums HQ");
+    assert_eq!(group_account.description, "gm");
+    assert!(group_account.is_whale_group);
+    assert!(group_account.escrow_enabled);
+    assert_eq!(pda::group("chums", &creator.pubkey()), group);
+
+    // New gating applies to anyone joining from now on
+    let join_ix = ix::join_group(context.payer.pubkey(), member.pubkey(), group, None, None);
+    let result = process(&mut context, &[join_ix], &[&member]).await;
+    assert_error(result, ErrorCode::InsufficientSolBalance);
+}
+
+#[tokio::test]
+async fn update_group_requires_admin() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let member = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+    join_group(&mut context, &member, group).await;
+
+    let update_ix = ix::update_group(member.pubkey(), group, true, update_args("Chums HQ"));
+    let result = process(&mut context, std::slice::from_ref(&update_ix), &[&member]).await;
+    assert_error(result, ErrorCode::NotGroupAdmin);
+
+    add_group_admin(
+        &mut context,
+        &creator,
+        group,
+        &member.pubkey(),
+        GroupRole::Admin,
+    )
+    .await;
+    process(&mut context, &[update_ix], &[&member])
+        .await
+        .unwrap();
+    let group_account: Group = fetch(&mut context, group).await;
+    assert_eq!(group_account.display_name, "Chums HQ");
+}
+
+#[tokio::test]
+async fn update_group_rejects_long_fields() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let group = create_group(&mut context, &creator, group_args("chums")).await;
+
+    let cases = [
+        (update_args(&"a".repeat(33)), ErrorCode::NameTooLong),
+        (
+            instruction::UpdateGroup {
+                description: "a".repeat(257),
+                ..update_args("chums")
+            },
+            ErrorCode::DescriptionTooLong,
+        ),
+    ];
+    for (args, expected) in cases {
+        let update_ix = ix::update_group(creator.pubkey(), group, false, args);
+        let result = process(&mut context, &[update_ix], &[&creator]).await;
+        assert_error(result, expected);
+    }
+}
+
+#[tokio::test]
+async fn update_group_fills_every_field() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let successor = Keypair::new();
+    let name = "c".repeat(32);
+    let group = create_group(&mut context, &creator, group_args(&name)).await;
+
+    let update_ix = ix::update_group(
+        creator.pubkey(),
+        group,
+        false,
+        instruction::UpdateGroup {
+            description: "d".repeat(256),
+            required_token: Some(Pubkey::new_unique()),
+            required_nft_collection: Some(Pubkey::new_unique()),
+            ..update_args(&"n".repeat(32))
+        },
+    );
+    let transfer_ix =
+        ix::transfer_group_ownership(creator.pubkey(), group, Some(successor.pubkey()));
+    process(&mut context, &[update_ix, transfer_ix], &[&creator])
+        .await
+        .unwrap();
+
+    let group_account: Group = fetch(&mut context, group).await;
+    assert_eq!(group_account.display_name, "n".repeat(32));
+    assert_eq!(group_account.description.len(), 256);
+    assert_eq!(group_account.pending_owner, Some(successor.pubkey()));
+    assert_eq!(Group::LEN, 551);
+}
@@ -0,0 +1,50 @@
+mod common;
+
+use chumchon::{error::ErrorCode, instruction, UserProfile};
+use common::{assert_error, create_user_profile, fetch, ix, pda, process, start};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn update_user_profile_success() {
+    let mut context = start().await;
+    let owner = Keypair::new();
+    create_user_profile(&mut context, &owner).await;
+
+    let update_ix = ix::update_user_profile(
+        context.payer.pubkey(),
+        owner.pubkey(),
+        instruction::UpdateUserProfile {
+            username: "chumchon".to_string(),
+            bio: "wagmi".to_string(),
+            show_balance: true,
+        },
+    );
+    process(&mut context, &[update_ix], &[&owner])
+        .await
+        .unwrap();
+
+    let profile: UserProfile = fetch(&mut context, pda::user_profile(&owner.pubkey())).await;
+    assert_eq!(profile.username, "chumchon");
+    assert_eq!(profile.bio, "wagmi");
+    assert!(profile.show_balance);
+}
+
+#[tokio::test]
+async fn update_user_profile_rejects_long_bio() {
+    let mut context = start().await;
+    let owner = Keypair::new();
+    create_user_profile(&mut context, &owner).await;
+
+    let update_ix = ix::update_user_profile(
+        context.payer.pubkey(),
+        owner.pubkey(),
+        instruction::UpdateUserProfile {
+            username: "chumchon".to_string(),
+            bio: "a".repeat(201),
+            show_balance: true,
+        },
+    );
+    let result = process(&mut context, &[update_ix], &[&owner]).await;
+    assert_error(result, ErrorCode::BioTooLong);
+}
@@ -0,0 +1,97 @@
+mod common;
+
+use chumchon::{error::ErrorCode, instruction, Group, Invite};
+use common::{
+    account_exists, assert_error, ban_member, create_group, fetch, group_args, ix, join_group, now,
+    pda, process, start, warp_to_timestamp,
+};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+async fn create_invite(
+    context: &mut ProgramTestContext,
+    creator: &Keypair,
+    max_uses: u32,
+    expires_at: i64,
+) -> Pubkey {
+    let group = create_group(context, creator, group_args("chums")).await;
+    let invite_ix = ix::create_invite(
+        context.payer.pubkey(),
+        creator.pubkey(),
+        group,
+        instruction::CreateInvite {
+            code: "gmchums".to_string(),
+            max_uses,
+            expires_at,
+        },
+    );
+    process(context, &[invite_ix], &[creator]).await.unwrap();
+    group
+}
+
+#[tokio::test]
+async fn use_invite_success() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let member = Keypair::new();
+    let expires_at = now(&mut context).await + 3600;
+    let group = create_invite(&mut context, &creator, 2, expires_at).await;
+
+    let use_ix = ix::use_invite(context.payer.pubkey(), member.pubkey(), group, "gmchums");
+    process(&mut context, &[use_ix], &[&member]).await.unwrap();
+
+    let invite: Invite = fetch(&mut context, pda::invite(&group, "gmchums")).await;
+    assert_eq!(invite.uses, 1);
+    let group_account: Group = fetch(&mut context, group).await;
+    assert_eq!(group_account.member_count, 1);
+    assert!(account_exists(&mut context, pda::group_member(&group, &member.pubkey())).await);
+}
+
+#[tokio::test]
+async fn use_invite_rejects_expired_invite() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let member = Keypair::new();
+    let expires_at = now(&mut context).await + 3600;
+    let group = create_invite(&mut context, &creator, 2, expires_at).await;
+    warp_to_timestamp(&mut context, expires_at).await;
+
+    let use_ix = ix::use_invite(context.payer.pubkey(), member.pubkey(), group, "gmchums");
+    let result = process(&mut context, &[use_ix], &[&member]).await;
+    assert_error(result, ErrorCode::InviteExpired);
+}
+
+#[tokio::test]
+async fn use_invite_rejects_exhausted_invite() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let first = Keypair::new();
+    let second = Keypair::new();
+    let expires_at = now(&mut context).await + 3600;
+    let group = create_invite(&mut context, &creator, 1, expires_at).await;
+
+    let use_ix = ix::use_invite(context.payer.pubkey(), first.pubkey(), group, "gmchums");
+    process(&mut context, &[use_ix], &[&first]).await.unwrap();
+
+    let use_ix = ix::use_invite(context.payer.pubkey(), second.pubkey(), group, "gmchums");
+    let result = process(&mut context, &[use_ix], &[&second]).await;
+    assert_error(result, ErrorCode::InviteUsed);
+}
+
+#[tokio::test]
+async fn use_invite_rejects_banned_member() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let member = Keypair::new();
+    let expires_at = now(&mut context).await + 3600;
+    let group = create_invite(&mut context, &creator, 2, expires_at).await;
+    join_group(&mut context, &member, group).await;
+    ban_member(&mut context, &creator, group, &member.pubkey()).await;
+
+    let use_ix = ix::use_invite(context.payer.pubkey(), member.pubkey(), group, "gmchums");
+    let result = process(&mut context, &[use_ix], &[&member]).await;
+    assert_error(result, ErrorCode::BannedFromGroup);
+}
@@ -0,0 +1,494 @@
+mod common;
+
+use chumchon::{
+    error::ErrorCode, instruction, MemeChallenge, MemeSubmission, VoterRecord, VotingMode,
+};
+use common::{
+    assert_error, balance, create_group_meme_challenge, create_meme_challenge,
+    create_meme_challenge_with, create_meme_challenge_with_split, create_mint,
+    create_token_account, create_user_profile, fetch, fund, ix, join_group, mint_to, pda, process,
+    start, submission_args, submit_meme, token_balance, vote_for_meme, warp_to_timestamp,
+    LAMPORTS_PER_SOL,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn vote_for_meme_success() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let voter = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+
+    let vote_ix = ix::vote_for_meme(
+        context.payer.pubkey(),
+        voter.pubkey(),
+        challenge,
+        None,
+        submitter.pubkey(),
+    );
+    process(&mut context, &[vote_ix], &[&voter]).await.unwrap();
+
+    let submission_key = pda::meme_submission(&challenge, &submitter.pubkey());
+    let submission: MemeSubmission = fetch(&mut context, submission_key).await;
+    assert_eq!(submission.votes, 1);
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(challenge_account.total_votes, 1);
+    assert_eq!(challenge_account.leaderboard[0].submission, submission_key);
+    assert_eq!(challenge_account.leaderboard[0].votes, 1);
+
+    let record: VoterRecord =
+        fetch(&mut context, pda::voter_record(&challenge, &voter.pubkey())).await;
+    assert_eq!(record.submission, submission_key);
+    assert_eq!(record.voter, voter.pubkey());
+    assert_eq!(record.voted_at, challenge_account.submission_end);
+}
+
+#[tokio::test]
+async fn vote_for_meme_tracks_leader() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let early = Keypair::new();
+    let late = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &early, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 60).await;
+    submit_meme(&mut context, &late, challenge).await;
+    let early_submission = pda::meme_submission(&challenge, &early.pubkey());
+    let late_submission = pda::meme_submission(&challenge, &late.pubkey());
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(
+        challenge_account.leaderboard[0].submission,
+        early_submission
+    );
+
+    vote_for_meme(&mut context, &Keypair::new(), challenge, &late.pubkey()).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(challenge_account.leaderboard[0].submission, late_submission);
+
+    // Drawing level hands the lead back to the earlier submission
+    vote_for_meme(&mut context, &Keypair::new(), challenge, &early.pubkey()).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(
+        challenge_account.leaderboard[0].submission,
+        early_submission
+    );
+    assert_eq!(challenge_account.leaderboard[0].votes, 1);
+
+    vote_for_meme(&mut context, &Keypair::new(), challenge, &late.pubkey()).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(challenge_account.leaderboard[0].submission, late_submission);
+    assert_eq!(challenge_account.leaderboard[0].votes, 2);
+}
+
+#[tokio::test]
+async fn vote_for_meme_ranks_paid_places() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let first = Keypair::new();
+    let second = Keypair::new();
+    let third = Keypair::new();
+    let challenge =
+        create_meme_challenge_with_split(&mut context, &creator, vec![7_000, 3_000]).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &first, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 2).await;
+    submit_meme(&mut context, &second, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 3).await;
+    submit_meme(&mut context, &third, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+    let leaders = |challenge_account: &MemeChallenge| {
+        challenge_account
+            .leaderboard
+            .iter()
+            .map(|entry| entry.submitter)
+            .collect::<Vec<_>>()
+    };
+
+    // Only as many entries as paid places are tracked, earliest first while level
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(
+        leaders(&challenge_account),
+        vec![first.pubkey(), second.pubkey()]
+    );
+
+    vote_for_meme(&mut context, &Keypair::new(), challenge, &third.pubkey()).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(
+        leaders(&challenge_account),
+        vec![third.pubkey(), first.pubkey()]
+    );
+
+    vote_for_meme(&mut context, &Keypair::new(), challenge, &second.pubkey()).await;
+    vote_for_meme(&mut context, &Keypair::new(), challenge, &second.pubkey()).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(
+        leaders(&challenge_account),
+        vec![second.pubkey(), third.pubkey()]
+    );
+    assert_eq!(challenge_account.leaderboard[0].votes, 2);
+    assert_eq!(challenge_account.leaderboard[1].votes, 1);
+}
+
+#[tokio::test]
+async fn vote_for_meme_rejects_own_submission() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+
+    let vote_ix = ix::vote_for_meme(
+        context.payer.pubkey(),
+        submitter.pubkey(),
+        challenge,
+        None,
+        submitter.pubkey(),
+    );
+    let result = process(&mut context, &[vote_ix], &[&submitter]).await;
+    assert_error(result, ErrorCode::CannotVoteOwnSubmission);
+}
+
+#[tokio::test]
+async fn vote_for_meme_once_per_challenge() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let first = Keypair::new();
+    let second = Keypair::new();
+    let voter = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &first, challenge).await;
+    submit_meme(&mut context, &second, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+    vote_for_meme(&mut context, &voter, challenge, &first.pubkey()).await;
+
+    // The voter record already exists, so a vote for another submission cannot be cast
+    let vote_ix = ix::vote_for_meme(
+        context.payer.pubkey(),
+        voter.pubkey(),
+        challenge,
+        None,
+        second.pubkey(),
+    );
+    let result = process(&mut context, &[vote_ix], &[&voter]).await;
+    assert!(result.is_err());
+    let submission: MemeSubmission = fetch(
+        &mut context,
+        pda::meme_submission(&challenge, &second.pubkey()),
+    )
+    .await;
+    assert_eq!(submission.votes, 0);
+}
+
+#[tokio::test]
+async fn vote_for_meme_outside_voting_window() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let voter = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+
+    // No votes while submissions are still coming in
+    let vote_ix = ix::vote_for_meme(
+        context.payer.pubkey(),
+        voter.pubkey(),
+        challenge,
+        None,
+        submitter.pubkey(),
+    );
+    let result = process(&mut context, std::slice::from_ref(&vote_ix), &[&voter]).await;
+    assert_error(result, ErrorCode::VotingNotOpen);
+
+    warp_to_timestamp(&mut context, challenge_account.voting_end).await;
+    let result = process(&mut context, &[vote_ix], &[&voter]).await;
+    assert_error(result, ErrorCode::VotingNotOpen);
+}
+
+#[tokio::test]
+async fn vote_for_meme_requires_group_membership() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let voter = Keypair::new();
+    let (group, challenge) = create_group_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    join_group(&mut context, &submitter, group).await;
+    let submit_ix = ix::submit_meme(
+        context.payer.pubkey(),
+        submitter.pubkey(),
+        challenge,
+        Some(group),
+        submission_args(),
+    );
+    process(&mut context, &[submit_ix], &[&submitter])
+        .await
+        .unwrap();
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+
+    let vote_ix = ix::vote_for_meme(
+        context.payer.pubkey(),
+        voter.pubkey(),
+        challenge,
+        None,
+        submitter.pubkey(),
+    );
+    let result = process(&mut context, &[vote_ix], &[&voter]).await;
+    assert_error(result, ErrorCode::NotGroupMember);
+
+    join_group(&mut context, &voter, group).await;
+    let vote_ix = ix::vote_for_meme(
+        context.payer.pubkey(),
+        voter.pubkey(),
+        challenge,
+        Some(group),
+        submitter.pubkey(),
+    );
+    process(&mut context, &[vote_ix], &[&voter]).await.unwrap();
+}
+
+#[tokio::test]
+async fn vote_for_meme_one_per_profile() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let voter = Keypair::new();
+    let challenge = create_meme_challenge_with(&mut context, &creator, |args| {
+        instruction::CreateMemeChallenge {
+            voting_mode: VotingMode::OnePerProfile,
+            ..args
+        }
+    })
+    .await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+
+    let vote_ix = ix::vote_for_meme(
+        context.payer.pubkey(),
+        voter.pubkey(),
+        challenge,
+        None,
+        submitter.pubkey(),
+    );
+    let result = process(&mut context, &[vote_ix], &[&voter]).await;
+    assert_error(result, ErrorCode::ProfileRequired);
+
+    create_user_profile(&mut context, &voter).await;
+    let vote_ix = ix::vote_for_meme_weighted(
+        context.payer.pubkey(),
+        voter.pubkey(),
+        challenge,
+        None,
+        submitter.pubkey(),
+        ix::VoteWeight {
+            with_profile: true,
+            ..Default::default()
+        },
+    );
+    process(&mut context, &[vote_ix], &[&voter]).await.unwrap();
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(challenge_account.total_votes, 1);
+}
+
+#[tokio::test]
+async fn vote_for_meme_token_weighted() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let voter = Keypair::new();
+    let mint_authority = Keypair::new();
+    let mint = create_mint(&mut context, &mint_authority.pubkey()).await;
+    let other_mint = create_mint(&mut context, &mint_authority.pubkey()).await;
+    let voter_token_account = create_token_account(&mut context, &mint, &voter.pubkey()).await;
+    let other_token_account =
+        create_token_account(&mut context, &other_mint, &voter.pubkey()).await;
+    let challenge = create_meme_challenge_with(&mut context, &creator, |args| {
+        instruction::CreateMemeChallenge {
+            voting_mode: VotingMode::TokenWeighted,
+            voting_mint: Some(mint),
+            ..args
+        }
+    })
+    .await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+    mint_to(
+        &mut context,
+        &other_mint,
+        &other_token_account,
+        &mint_authority,
+        40,
+    )
+    .await;
+
+    let fee_payer = context.payer.pubkey();
+    let vote_with = |voter_token_account| {
+        ix::vote_for_meme_weighted(
+            fee_payer,
+            voter.pubkey(),
+            challenge,
+            None,
+            submitter.pubkey(),
+            ix::VoteWeight {
+                voter_token_account: Some(voter_token_account),
+                voting_mint: Some(mint),
+                ..Default::default()
+            },
+        )
+    };
+    let cases = [
+        (vote_with(other_token_account), ErrorCode::InvalidToken),
+        (vote_with(voter_token_account), ErrorCode::NoVotingPower),
+    ];
+    for (vote_ix, expected) in cases {
+        let result = process(&mut context, &[vote_ix], &[&voter]).await;
+        assert_error(result, expected);
+    }
+
+    mint_to(
+        &mut context,
+        &mint,
+        &voter_token_account,
+        &mint_authority,
+        40,
+    )
+    .await;
+    let vote_ix = vote_with(voter_token_account);
+    process(&mut context, &[vote_ix], &[&voter]).await.unwrap();
+
+    let submission_key = pda::meme_submission(&challenge, &submitter.pubkey());
+    let submission: MemeSubmission = fetch(&mut context, submission_key).await;
+    assert_eq!(submission.votes, 40);
+    let record: VoterRecord =
+        fetch(&mut context, pda::voter_record(&challenge, &voter.pubkey())).await;
+    assert_eq!(record.weight, 40);
+
+    // The tokens stay locked until voting is over, so they cannot vote again from another wallet
+    assert_eq!(token_balance(&mut context, voter_token_account).await, 0);
+    let vote_vault = pda::vote_vault(&challenge, &voter.pubkey());
+    assert_eq!(token_balance(&mut context, vote_vault).await, 40);
+}
+
+#[tokio::test]
+async fn vote_for_meme_quadratic() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let voter = Keypair::new();
+    let challenge = create_meme_challenge_with(&mut context, &creator, |args| {
+        instruction::CreateMemeChallenge {
+            voting_mode: VotingMode::Quadratic,
+            vote_price: 1_000,
+            ..args
+        }
+    })
+    .await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+    fund(&mut context, &voter.pubkey(), LAMPORTS_PER_SOL);
+    let pool_before = balance(&mut context, challenge).await;
+
+    let vote_ix = ix::vote_for_meme_weighted(
+        context.payer.pubkey(),
+        voter.pubkey(),
+        challenge,
+        None,
+        submitter.pubkey(),
+        ix::VoteWeight {
+            votes: 0,
+            ..Default::default()
+        },
+    );
+    let result = process(&mut context, &[vote_ix], &[&voter]).await;
+    assert_error(result, ErrorCode::InvalidAmount);
+
+    // The cost of this many votes does not fit in a u64
+    let vote_ix = ix::vote_for_meme_weighted(
+        context.payer.pubkey(),
+        voter.pubkey(),
+        challenge,
+        None,
+        submitter.pubkey(),
+        ix::VoteWeight {
+            votes: u32::MAX,
+            ..Default::default()
+        },
+    );
+    let result = process(&mut context, &[vote_ix], &[&voter]).await;
+    assert_error(result, ErrorCode::Overflow);
+
+    // Three votes cost 3² times the price, all of it added to the prize
+    let vote_ix = ix::vote_for_meme_weighted(
+        context.payer.pubkey(),
+        voter.pubkey(),
+        challenge,
+        None,
+        submitter.pubkey(),
+        ix::VoteWeight {
+            votes: 3,
+            ..Default::default()
+        },
+    );
+    process(&mut context, &[vote_ix], &[&voter]).await.unwrap();
+
+    let submission: MemeSubmission = fetch(
+        &mut context,
+        pda::meme_submission(&challenge, &submitter.pubkey()),
+    )
+    .await;
+    assert_eq!(submission.votes, 3);
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(challenge_account.reward_amount, LAMPORTS_PER_SOL + 9_000);
+    assert_eq!(balance(&mut context, challenge).await, pool_before + 9_000);
+}
+
+#[tokio::test]
+async fn vote_for_meme_counts_single_votes_outside_quadratic_mode() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let voter = Keypair::new();
+    let challenge = create_meme_challenge(&mut context, &creator).await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+
+    let vote_ix = ix::vote_for_meme_weighted(
+        context.payer.pubkey(),
+        voter.pubkey(),
+        challenge,
+        None,
+        submitter.pubkey(),
+        ix::VoteWeight {
+            votes: 2,
+            ..Default::default()
+        },
+    );
+    let result = process(&mut context, &[vote_ix], &[&voter]).await;
+    assert_error(result, ErrorCode::InvalidAmount);
+}
@@ -0,0 +1,75 @@
+mod common;
+
+use chumchon::{error::ErrorCode, instruction, MemeChallenge, VotingMode};
+use common::{
+    account_exists, assert_error, create_meme_challenge_with, create_mint, create_token_account,
+    fetch, ix, mint_to, pda, process, start, submit_meme, token_balance, warp_to_timestamp,
+};
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn withdraw_vote_tokens_after_voting() {
+    let mut context = start().await;
+    let creator = Keypair::new();
+    let submitter = Keypair::new();
+    let voter = Keypair::new();
+    let mint_authority = Keypair::new();
+    let mint = create_mint(&mut context, &mint_authority.pubkey()).await;
+    let voter_token_account = create_token_account(&mut context, &mint, &voter.pubkey()).await;
+    mint_to(
+        &mut context,
+        &mint,
+        &voter_token_account,
+        &mint_authority,
+        40,
+    )
+    .await;
+    let challenge = create_meme_challenge_with(&mut context, &creator, |args| {
+        instruction::CreateMemeChallenge {
+            voting_mode: VotingMode::TokenWeighted,
+            voting_mint: Some(mint),
+            ..args
+        }
+    })
+    .await;
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.start_time + 1).await;
+    submit_meme(&mut context, &submitter, challenge).await;
+    warp_to_timestamp(&mut context, challenge_account.submission_end).await;
+    let vote_ix = ix::vote_for_meme_weighted(
+        context.payer.pubkey(),
+        voter.pubkey(),
+        challenge,
+        None,
+        submitter.pubkey(),
+        ix::VoteWeight {
+            voter_token_account: Some(voter_token_account),
+            voting_mint: Some(mint),
+            ..Default::default()
+        },
+    );
+    process(&mut context, &[vote_ix], &[&voter]).await.unwrap();
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(challenge_account.open_vote_vaults, 1);
+
+    let withdraw_ix = ix::withdraw_vote_tokens(
+        voter.pubkey(),
+        context.payer.pubkey(),
+        challenge,
+        voter_token_account,
+    );
+    let result = process(&mut context, std::slice::from_ref(&withdraw_ix), &[&voter]).await;
+    assert_error(result, ErrorCode::ChallengeNotEnded);
+
+    warp_to_timestamp(&mut context, challenge_account.voting_end).await;
+    process(&mut context, &[withdraw_ix], &[&voter])
+        .await
+        .unwrap();
+
+    assert_eq!(token_balance(&mut context, voter_token_account).await, 40);
+    assert!(!account_exists(&mut context, pda::vote_vault(&challenge, &voter.pubkey())).await);
+    assert!(!account_exists(&mut context, pda::voter_record(&challenge, &voter.pubkey())).await);
+    let challenge_account: MemeChallenge = fetch(&mut context, challenge).await;
+    assert_eq!(challenge_account.open_vote_vaults, 0);
+}